@@ -0,0 +1,361 @@
+//! Async counterparts of the Interface trait and the Controller
+//!
+//! The AsyncController implements the same command table as the blocking Controller. It waits
+//! after reset and sleep in/out commands with an async [`DelayNs`] source (see
+//! [`timing`](crate::timing)) and reads the extended registers directly or through the index read
+//! command (see [`extended`](crate::extended)). It has no reset pin and no shadow registers.
+
+#![allow(async_fn_in_trait)]
+
+use embedded_hal_async::delay::DelayNs;
+
+use crate::timing::{NoDelay, COMMAND_WAIT_MS, SLEEP_GUARD_MS};
+use crate::{bytes_to_words16, bytes_to_words18, word18_to_bytes, word_chunks, WORD_READ_CHUNK};
+// The command table refers to the register modules relative to the crate root
+use crate::*;
+
+/// Async version of the [`Interface`](crate::Interface) trait.
+/// Intended for executors like embassy, where long frame memory transfers must not block.
+pub trait AsyncInterface {
+    /// An enumeration of Interface errors
+    type Error;
+
+    /// Write parameters as a slice
+    async fn command(&mut self, command: u8) -> Result<(), Self::Error>;
+    async fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error>;
+    /// Read parameters into a slice
+    /// Note: the implementation needs to add a dummy read between command send and data receive
+    async fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error>;
+
+    /// Write parameters as an iterator
+    async fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error>;
+    /// Read parameters into an iterator
+    /// Note: the implementation needs to add a dummy read between command send and data receive
+    async fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error>;
+
+    /// Write 16-bit words (one bus cycle each on a 16-bit bus)
+    /// The default implementation sends every word as two bytes, MSB first.
+    async fn send_parameters_u16(&mut self, command: u8, data: &[u16]) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, data.iter().flat_map(|w| w.to_be_bytes())).await
+    }
+    /// Write 18-bit words D[17:0] (one bus cycle each on an 18-bit bus)
+    /// The default implementation sends every word as three bytes with 6 bits each, left aligned.
+    async fn send_parameters_u32(&mut self, command: u8, data: &[u32]) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, data.iter().flat_map(|&w| word18_to_bytes(w))).await
+    }
+    /// Read 16-bit words (one bus cycle each on a 16-bit bus)
+    /// The default implementation reads every word as two bytes, MSB first, through a stack buffer.
+    /// Frame memory reads longer than the buffer are resumed with Read Memory Continue (3Eh).
+    /// Other reads are clamped to the buffer.
+    async fn read_parameters_u16(&mut self, command: u8, data: &mut [u16]) -> Result<(), Self::Error> {
+        let mut buf = [0u8; WORD_READ_CHUNK];
+        for (command, words) in word_chunks(command, data, 2) {
+            let bytes = &mut buf[..words.len() * 2];
+            self.read_parameters(command, bytes).await?;
+            bytes_to_words16(bytes, words);
+        }
+        Ok(())
+    }
+    /// Read 18-bit words D[17:0] (one bus cycle each on an 18-bit bus)
+    /// The default implementation reads every word as three bytes with 6 bits each, left aligned,
+    /// through a stack buffer like `read_parameters_u16`.
+    async fn read_parameters_u32(&mut self, command: u8, data: &mut [u32]) -> Result<(), Self::Error> {
+        let mut buf = [0u8; WORD_READ_CHUNK];
+        for (command, words) in word_chunks(command, data, 3) {
+            let bytes = &mut buf[..words.len() * 3];
+            self.read_parameters(command, bytes).await?;
+            bytes_to_words18(bytes, words);
+        }
+        Ok(())
+    }
+
+    /// Read the single parameter selected with the index read command (D9h)
+    /// See [`Interface::read_indexed_parameter`](crate::Interface::read_indexed_parameter).
+    async fn read_indexed_parameter(&mut self, command: u8) -> Result<u8, Self::Error> {
+        let mut data = [0];
+        self.read_parameters(command, &mut data).await?;
+        Ok(data[0])
+    }
+}
+
+impl DelayNs for NoDelay {
+    async fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Generates the async AsyncController methods from the command table
+macro_rules! async_commands {
+    (@fn $(#[$attr:meta])* command $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self) -> Result<(), Iface::Error> {
+            self.command($cmd).await
+        }
+    };
+    (@fn $(#[$attr:meta])* timed $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self) -> Result<(), Iface::Error> {
+            self.timed_command($cmd).await
+        }
+    };
+    (@fn $(#[$attr:meta])* read $name:ident $cmd:literal $reg:ty) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self) -> Result<$reg, Iface::Error> {
+            let mut r = <$reg>::default();
+            self.read_parameters($cmd, &mut r.data).await?;
+            Ok(r)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_ext $name:ident $cmd:literal $reg:ty) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self) -> Result<$reg, Iface::Error> {
+            let mut r = <$reg>::default();
+            self.read_extended($cmd, &mut r.data).await?;
+            Ok(r)
+        }
+    };
+    (@fn $(#[$attr:meta])* write $name:ident $cmd:literal $reg:ty, $w:ty) => {
+        $(#[$attr])*
+        pub async fn $name<F>(&mut self, f: F) -> Result<(), Iface::Error>
+        where
+            F: FnOnce($w) -> $w
+        {
+            let mut r = <$reg>::default();
+            r.write(f);
+            self.send_parameters($cmd, &r.data).await
+        }
+    };
+    (@fn $(#[$attr:meta])* write_slice $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, d: &[u8]) -> Result<(), Iface::Error> {
+            self.send_parameters($cmd, d).await
+        }
+    };
+    (@fn $(#[$attr:meta])* write_iter $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name<T: IntoIterator<Item = u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
+            self.send_parameters_iter($cmd, d).await
+        }
+    };
+    (@fn $(#[$attr:meta])* read_slice $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name<'l>(&mut self, d: &'l mut [u8]) -> Result<&'l mut [u8], Iface::Error> {
+            self.read_parameters($cmd, d).await?;
+            Ok(d)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_iter $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
+            self.read_parameters_iter($cmd, d).await
+        }
+    };
+    (@fn $(#[$attr:meta])* write_u16 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, d: &[u16]) -> Result<(), Iface::Error> {
+            self.send_parameters_u16($cmd, d).await
+        }
+    };
+    (@fn $(#[$attr:meta])* write_u32 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name(&mut self, d: &[u32]) -> Result<(), Iface::Error> {
+            self.send_parameters_u32($cmd, d).await
+        }
+    };
+    (@fn $(#[$attr:meta])* read_u16 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name<'l>(&mut self, d: &'l mut [u16]) -> Result<&'l mut [u16], Iface::Error> {
+            self.read_parameters_u16($cmd, d).await?;
+            Ok(d)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_u32 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub async fn $name<'l>(&mut self, d: &'l mut [u32]) -> Result<&'l mut [u32], Iface::Error> {
+            self.read_parameters_u32($cmd, d).await?;
+            Ok(d)
+        }
+    };
+    ($($(#[$attr:meta])* $kind:ident $name:ident = $cmd:literal $(=> $($ty:ty),+)?;)*) => {
+        $(async_commands! { @fn $(#[$attr])* $kind $name $cmd $($($ty),+)? })*
+    };
+}
+
+/// AsyncController implements the LCD command set and calls on the AsyncInterface trait
+/// to communicate with the LCD panel.
+///
+/// The optional delay source is added with `with_delay`, see the [module documentation](self).
+#[derive(Copy, Clone)]
+pub struct AsyncController<Iface, Delay = NoDelay>
+where
+    Iface: AsyncInterface,
+{
+    /// Custom interface
+    iface: Iface,
+    delay: Delay,
+    /// A reset or sleep in/out command was sent, the next one has to wait 120 ms
+    sleep_guard: bool,
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    extended_read: extended::ExtendedRead,
+}
+
+impl<Iface: AsyncInterface> AsyncController<Iface>
+where
+    Iface: AsyncInterface,
+{
+    pub fn new(iface: Iface) -> AsyncController<Iface> {
+        AsyncController {
+            iface,
+            delay: NoDelay,
+            sleep_guard: true,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended_read: extended::ExtendedRead::Direct,
+        }
+    }
+}
+
+impl<Iface, Delay> AsyncController<Iface, Delay>
+where
+    Iface: AsyncInterface,
+    Delay: DelayNs,
+{
+    /// Use `delay` for the mandated waits
+    pub fn with_delay<D: DelayNs>(self, delay: D) -> AsyncController<Iface, D> {
+        AsyncController {
+            iface: self.iface,
+            delay,
+            sleep_guard: self.sleep_guard,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended_read: self.extended_read,
+        }
+    }
+    /// Read the extended registers with `mode`, the default is `ExtendedRead::Direct`
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    pub fn with_extended_read(mut self, mode: extended::ExtendedRead) -> Self {
+        self.extended_read = mode;
+        self
+    }
+
+    /// Send a reset or a sleep in/out command and wait as required
+    async fn timed_command(&mut self, command: u8) -> Result<(), Iface::Error> {
+        if self.sleep_guard {
+            self.delay.delay_ms(SLEEP_GUARD_MS - COMMAND_WAIT_MS).await;
+            self.sleep_guard = false;
+        }
+        self.command(command).await?;
+        self.delay.delay_ms(COMMAND_WAIT_MS).await;
+        self.sleep_guard = true;
+        Ok(())
+    }
+
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    async fn read_extended(&mut self, command: u8, data: &mut [u8]) -> Result<(), Iface::Error> {
+        match self.extended_read {
+            extended::ExtendedRead::Direct => self.read_parameters(command, data).await,
+            extended::ExtendedRead::Indexed => {
+                for (i, d) in data.iter_mut().enumerate() {
+                    self.send_parameters(0xD9, &[0x10 | (i as u8 + 1)]).await?;
+                    *d = self.iface.read_indexed_parameter(command).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    #[inline(always)]
+    async fn command(&mut self, command: u8) -> Result<(), Iface::Error> {
+        self.iface.command(command).await
+    }
+    #[inline(always)]
+    async fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Iface::Error> {
+        self.iface.send_parameters(command, data).await
+    }
+    #[inline(always)]
+    async fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Iface::Error> {
+        self.iface.read_parameters(command, data).await
+    }
+    #[inline(always)]
+    async fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Iface::Error> {
+        self.iface.send_parameters_iter(command, data).await
+    }
+    #[inline(always)]
+    async fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Iface::Error> {
+        self.iface.read_parameters_iter(command, data).await
+    }
+    #[inline(always)]
+    async fn send_parameters_u16(&mut self, command: u8, data: &[u16]) -> Result<(), Iface::Error> {
+        self.iface.send_parameters_u16(command, data).await
+    }
+    #[inline(always)]
+    async fn send_parameters_u32(&mut self, command: u8, data: &[u32]) -> Result<(), Iface::Error> {
+        self.iface.send_parameters_u32(command, data).await
+    }
+    #[inline(always)]
+    async fn read_parameters_u16(&mut self, command: u8, data: &mut [u16]) -> Result<(), Iface::Error> {
+        self.iface.read_parameters_u16(command, data).await
+    }
+    #[inline(always)]
+    async fn read_parameters_u32(&mut self, command: u8, data: &mut [u32]) -> Result<(), Iface::Error> {
+        self.iface.read_parameters_u32(command, data).await
+    }
+
+    command_table!(async_commands);
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::mock::MockInterface;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    /// Delay source recording the waits in ms
+    #[derive(Default)]
+    struct Waits(Vec<u32>);
+
+    impl DelayNs for Waits {
+        async fn delay_ns(&mut self, ns: u32) {
+            self.0.push(ns / 1_000_000);
+        }
+    }
+
+    #[test]
+    fn timed_commands_wait() {
+        let mock = MockInterface::new();
+        let mut lcd = AsyncController::new(mock.clone()).with_delay(Waits::default());
+        block_on(async {
+            lcd.software_reset().await.unwrap();
+            lcd.sleep_out().await.unwrap();
+            lcd.display_on().await.unwrap();
+        });
+        assert_eq!(lcd.delay.0, [115, 5, 115, 5]);
+        mock.expect_command(0x01).expect_command(0x11).expect_command(0x29).done();
+    }
+
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    #[test]
+    fn indexed_extended_read() {
+        let mock = MockInterface::new();
+        mock.push_response(0xD3, &[0x00]).push_response(0xD3, &[0x93]).push_response(0xD3, &[0x41]);
+        let mut lcd = AsyncController::new(mock.clone()).with_extended_read(extended::ExtendedRead::Indexed);
+        let id4 = block_on(lcd.read_id4()).unwrap();
+        assert_eq!(id4.into_bytes(), [0x00, 0x93, 0x41]);
+        mock.expect_parameters(0xD9, &[0x11]).expect_read(0xD3, 1)
+            .expect_parameters(0xD9, &[0x12]).expect_read(0xD3, 1)
+            .expect_parameters(0xD9, &[0x13]).expect_read(0xD3, 1)
+            .done();
+    }
+}
@@ -0,0 +1,174 @@
+//! Checked versions of the address setting commands
+//!
+//! The plain register writes send any value, addresses out of the frame memory lead to undefined
+//! panel behaviour. The `_checked` methods validate their parameters first and return an
+//! [`Error`] without touching the bus if they are out of range.
+//!
+//! The column and page limits depend on the row/column exchange (MV), the Controller keeps track
+//! of the last MADCTL written through it. After a reset MV is cleared.
+
+use embedded_hal::delay::DelayNs;
+
+use crate::shadow::RegisterCache;
+use crate::{Controller, Interface};
+
+/// Number of columns of the frame memory without row/column exchange
+const COLUMNS: u16 = 240;
+/// Number of rows (lines) of the frame memory without row/column exchange
+const ROWS: u16 = 320;
+
+/// An enumeration of Controller errors
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error<IfaceError> {
+    /// Error of the Interface
+    Interface(IfaceError),
+    /// The start address is after the end address
+    StartAfterEnd { start: u16, end: u16 },
+    /// The address is beyond the last one of the frame memory in the current orientation
+    OutOfBounds { address: u16, last: u16 },
+    /// The scroll areas (TFA + VSA + BFA) don't add up to the 320 lines
+    ScrollAreas { lines: u32 },
+}
+
+fn check_range<E>(start: u16, end: u16, count: u16) -> Result<(), Error<E>> {
+    if start > end {
+        Err(Error::StartAfterEnd { start, end })
+    } else {
+        check_address(end, count)
+    }
+}
+
+fn check_address<E>(address: u16, count: u16) -> Result<(), Error<E>> {
+    if address >= count {
+        Err(Error::OutOfBounds { address, last: count - 1 })
+    } else {
+        Ok(())
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Logical width and height of the frame memory for the current MV
+    fn logical_size(&self) -> (u16, u16) {
+        if self.row_column_exchange {
+            (ROWS, COLUMNS)
+        } else {
+            (COLUMNS, ROWS)
+        }
+    }
+
+    /// Column Address Set (2Ah), SC <= EC < width
+    pub fn column_address_set_checked(&mut self, sc: u16, ec: u16) -> Result<(), Error<Iface::Error>> {
+        check_range(sc, ec, self.logical_size().0)?;
+        self.column_address_set(|w| w.sc(sc).ec(ec)).map_err(Error::Interface)
+    }
+    /// Page Address Set (2Bh), SP <= EP < height
+    pub fn page_address_set_checked(&mut self, sp: u16, ep: u16) -> Result<(), Error<Iface::Error>> {
+        check_range(sp, ep, self.logical_size().1)?;
+        self.page_address_set(|w| w.sp(sp).ep(ep)).map_err(Error::Interface)
+    }
+    /// Partial Area (30h), SR and ER < 320
+    /// SR may be after ER, the partial area then wraps around the last line.
+    pub fn partial_area_checked(&mut self, sr: u16, er: u16) -> Result<(), Error<Iface::Error>> {
+        check_address(sr, ROWS)?;
+        check_address(er, ROWS)?;
+        self.partial_area(|w| w.sr(sr).er(er)).map_err(Error::Interface)
+    }
+    /// Vertical Scrolling Definition (33h), TFA + VSA + BFA = 320
+    pub fn vertical_scrolling_definition_checked(&mut self, tfa: u16, vsa: u16, bfa: u16) -> Result<(), Error<Iface::Error>> {
+        let lines = tfa as u32 + vsa as u32 + bfa as u32;
+        if lines != ROWS as u32 {
+            return Err(Error::ScrollAreas { lines });
+        }
+        self.vertical_scrolling_definition(|w| w.tfa(tfa).vsa(vsa).bfa(bfa))
+            .map_err(Error::Interface)
+    }
+    /// Set Tear Scanline (44h), STS < 320
+    pub fn set_tear_scanline_checked(&mut self, sts: u16) -> Result<(), Error<Iface::Error>> {
+        check_address(sts, ROWS)?;
+        self.set_tear_scanline(|w| w.sts(sts)).map_err(Error::Interface)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::madctl::Madctl;
+    use crate::mock::MockInterface;
+    use crate::orientation::Orientation;
+
+    #[test]
+    fn portrait_limits() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        lcd.column_address_set_checked(0, 239).unwrap();
+        assert_eq!(lcd.column_address_set_checked(0, 240), Err(Error::OutOfBounds { address: 240, last: 239 }));
+        lcd.page_address_set_checked(319, 319).unwrap();
+        assert_eq!(lcd.page_address_set_checked(0, 320), Err(Error::OutOfBounds { address: 320, last: 319 }));
+        assert_eq!(lcd.column_address_set_checked(10, 9), Err(Error::StartAfterEnd { start: 10, end: 9 }));
+        assert_eq!(lcd.page_address_set_checked(300, 299), Err(Error::StartAfterEnd { start: 300, end: 299 }));
+
+        // The rejected ones never reach the bus
+        mock.expect_parameters(0x2A, &[0x00, 0x00, 0x00, 0xEF])
+            .expect_parameters(0x2B, &[0x01, 0x3F, 0x01, 0x3F])
+            .done();
+    }
+
+    #[test]
+    fn row_column_exchange_swaps_the_limits() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        assert_eq!(lcd.set_orientation(Orientation::Landscape, false).unwrap(), (320, 240));
+        lcd.column_address_set_checked(0, 319).unwrap();
+        assert_eq!(lcd.page_address_set_checked(0, 240), Err(Error::OutOfBounds { address: 240, last: 239 }));
+        lcd.page_address_set_checked(0, 239).unwrap();
+
+        // MV off again through a raw MADCTL
+        lcd.write_madctl(Madctl::from(0x48)).unwrap();
+        assert_eq!(lcd.column_address_set_checked(0, 319), Err(Error::OutOfBounds { address: 319, last: 239 }));
+
+        // MV on, then cleared by the software reset
+        lcd.memory_access_control(|w| w.row_column_exchange(true)).unwrap();
+        lcd.column_address_set_checked(0, 319).unwrap();
+        lcd.software_reset().unwrap();
+        assert_eq!(lcd.column_address_set_checked(0, 319), Err(Error::OutOfBounds { address: 319, last: 239 }));
+
+        mock.expect_parameters(0x36, &[0x20])
+            .expect_parameters(0x2A, &[0x00, 0x00, 0x01, 0x3F])
+            .expect_parameters(0x2B, &[0x00, 0x00, 0x00, 0xEF])
+            .expect_parameters(0x36, &[0x48])
+            .expect_parameters(0x36, &[0x20])
+            .expect_parameters(0x2A, &[0x00, 0x00, 0x01, 0x3F])
+            .expect_command(0x01)
+            .done();
+    }
+
+    #[test]
+    fn partial_scroll_and_tear_limits() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        // The partial area may wrap around, but both lines have to exist
+        lcd.partial_area_checked(300, 20).unwrap();
+        assert_eq!(lcd.partial_area_checked(320, 20), Err(Error::OutOfBounds { address: 320, last: 319 }));
+        assert_eq!(lcd.partial_area_checked(0, 320), Err(Error::OutOfBounds { address: 320, last: 319 }));
+        lcd.vertical_scrolling_definition_checked(10, 300, 10).unwrap();
+        assert_eq!(lcd.vertical_scrolling_definition_checked(10, 300, 11), Err(Error::ScrollAreas { lines: 321 }));
+        assert_eq!(lcd.vertical_scrolling_definition_checked(u16::MAX, u16::MAX, 2), Err(Error::ScrollAreas { lines: 131072 }));
+        lcd.set_tear_scanline_checked(319).unwrap();
+        assert_eq!(lcd.set_tear_scanline_checked(320), Err(Error::OutOfBounds { address: 320, last: 319 }));
+        // The partial area and the scanline don't depend on MV
+        lcd.set_orientation(Orientation::Landscape, false).unwrap();
+        lcd.set_tear_scanline_checked(319).unwrap();
+
+        mock.expect_parameters(0x30, &[0x01, 0x2C, 0x00, 0x14])
+            .expect_parameters(0x33, &[0x00, 0x0A, 0x01, 0x2C, 0x00, 0x0A])
+            .expect_parameters(0x44, &[0x01, 0x3F])
+            .expect_parameters(0x36, &[0x20])
+            .expect_parameters(0x44, &[0x01, 0x3F])
+            .done();
+    }
+}
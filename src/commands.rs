@@ -0,0 +1,994 @@
+//! The command table of the ili9341
+//!
+//! Every command is listed once in `command_table!`, which hands the table to a generator
+//! macro. The generators turn the entries into the methods of `Controller` and
+//! `AsyncController`, so both always implement the same command set, and into the fields of
+//! `ShadowRegisters`.
+//!
+//! Entry kinds:
+//! - `command`: command without parameters
+//! - `timed`: reset or sleep in/out command, followed by the mandated waits (see `timing`)
+//! - `read`: read a register into its type
+//! - `read_ext`: read an extended register into its type (see `extended` for the index read)
+//! - `write`: write a register through its write closure
+//! - `write_slice`/`write_iter`/`read_slice`/`read_iter`: raw frame memory transfers
+//! - `write_u16`/`write_u32`/`read_u16`/`read_u32`: frame memory transfers with bus words
+
+macro_rules! command_table {
+    ($generator:ident) => {
+        $generator! {
+            // autogenerated commands start
+
+            /**
+                This command is an empty command; it does not have any effect on the display module. However it can be used to terminate
+                Frame Memory Write or Read as described in RAMWR (Memory Write) and RAMRD (Memory Read) Commands.
+            */
+            command no_operation = 0x00;
+            /**
+                When the Software Reset command is written, it causes a software reset. It resets the commands and parameters to their
+                S/W Reset default values. (See default tables in each command description.)
+                Note: The Frame Memory contents are unaffected by this command
+
+                The Controller and the AsyncController wait as required by the datasheet if they have a delay source
+                (see `timing`).
+            */
+            timed software_reset = 0x01;
+            /**
+                This read byte returns 24 bits display identification information.
+                The 1st parameter is dummy data.
+                The 2nd parameter (ID1 [7:0]): LCD module’s manufacturer ID.
+                The 3rd parameter (ID2 [7:0]): LCD module/driver version ID.
+                The 4th parameter (ID3 [7:0]): LCD module/driver ID.
+            */
+            read read_display_identification_information = 0x04 => read_display_identification_information::DisplayIdentificationInformation;
+            /// Read Display Status
+            read read_display_status = 0x09 => read_display_status::DisplayStatus;
+            /// Read Display Power Mode
+            read read_display_power_mode = 0x0A => read_display_power_mode::DisplayPowerMode;
+            /// Read Display MADCTL
+            read read_display_madctl = 0x0B => read_display_madctl::DisplayMadctl;
+            /// Read Display Pixel Format
+            read read_display_pixel_format = 0x0C => read_display_pixel_format::DisplayPixelFormat;
+            /// Read Display Image Format
+            read read_display_image_format = 0x0D => read_display_image_format::DisplayImageFormat;
+            /// Read Display Signal Mode
+            read read_display_signal_mode = 0x0E => read_display_signal_mode::DisplaySignalMode;
+            /// Read Display Self-Diagnostic Result
+            read read_display_self_diagnostic_result = 0x0F => read_display_self_diagnostic_result::DisplaySelfDiagnosticResult;
+            /**
+                This command causes the LCD module to enter the minimum power consumption mode. In this mode e.g. the DC/DC
+                converter is stopped, Internal oscillator is stopped, and panel scanning is stopped.
+        
+                MCU interface and memory are still working and the memory keeps its contents.
+
+                The Controller and the AsyncController wait as required by the datasheet if they have a delay source
+                (see `timing`).
+            */
+            timed enter_sleep_mode = 0x10;
+            /**
+                This command turns off sleep mode.
+                In this mode e.g. the DC/DC converter is enabled, Internal oscillator is started, and panel scanning is started.
+
+                The Controller and the AsyncController wait as required by the datasheet if they have a delay source
+                (see `timing`).
+            */
+            timed sleep_out = 0x11;
+            /**
+                This command turns on partial mode The partial mode window is described by the Partial Area command (30H). To leave
+                Partial mode, the Normal Display Mode On command (13H) should be written.
+            */
+            command partial_mode_on = 0x12;
+            /**
+                This command returns the display to normal mode.
+                Normal display mode on means Partial mode off.
+                Exit from NORON by the Partial mode On command (12h)
+            */
+            command normal_display_mode_on = 0x13;
+            /**
+                This command is used to recover from display inversion mode.
+                This command makes no change of the content of frame memory.
+                This command doesn’t change any other status.
+            */
+            command display_inversion_off = 0x20;
+            /**
+                This command is used to enter into display inversion mode.
+                This command makes no change of the content of frame memory. Every bit is inverted from the frame memory to the display.
+                This command doesn’t change any other status.
+                To exit Display inversion mode, the Display inversion OFF command (20h) should be written.
+            */
+            command display_inversion_on = 0x21;
+            /**
+                This command is used to select the desired Gamma curve for the current display. A maximum of 4 fixed gamma curves can
+                be selected. The curve is selected by setting the appropriate bit in the parameter.
+                Note: All other values are undefined.
+            */
+            write gamma_set = 0x26 => gamma::GammaSet, gamma::GammaSetWrite;
+            /**
+                This command is used to enter into DISPLAY OFF mode. In this mode, the output from Frame Memory is disabled and blank
+                page inserted.
+                This command makes no change of contents of frame memory.
+                This command does not change any other status.
+                There will be no abnormal visible effect on the display.
+            */
+            command display_off = 0x28;
+            /**
+                This command is used to recover from DISPLAY OFF mode. Output from the Frame Memory is enabled.
+                This command makes no change of contents of frame memory.
+                This command does not change any other status
+            */
+            command display_on = 0x29;
+            /**
+                This command is used to define area of frame memory where MCU can access. This command makes no change on the
+                other driver status. The values of SC [15:0] and EC [15:0] are referred when RAMWR command comes. Each value
+                represents one column line in the Frame Memory.
+            */
+            write column_address_set = 0x2A => column_address::ColumnAddressSet, column_address::ColumnAddressSetWrite;
+            /**
+                This command is used to define area of frame memory where MCU can access. This command makes no change on the
+                other driver status. The values of SP [15:0] and EP [15:0] are referred when RAMWR command comes. Each value
+                represents one Page line in the Frame Memory.
+            */
+            write page_address_set = 0x2B => page_address::PageAddressSet, page_address::PageAddressSetWrite;
+            /**
+                This command is used to transfer data from MCU to frame memory. This command makes no change to the other driver
+                status. When this command is accepted, the column register and the page register are reset to the Start Column/Start
+                Page positions. The Start Column/Start Page positions are different in accordance with MADCTL setting.) Then D [17:0] is
+                stored in frame memory and the column register and the page register incremented. Sending any other command can stop
+                frame Write.
+            */
+            write_slice memory_write = 0x2C;
+            /**
+                This command is used to transfer data from MCU to frame memory. This command makes no change to the other driver
+                status. When this command is accepted, the column register and the page register are reset to the Start Column/Start
+                Page positions. The Start Column/Start Page positions are different in accordance with MADCTL setting.) Then D [17:0] is
+                stored in frame memory and the column register and the page register incremented. Sending any other command can stop
+                frame Write.
+            */
+            write_iter memory_write_iter = 0x2C;
+            /**
+                This command is used to define the LUT for 16-bit to 18-bit color depth conversion.
+                128 bytes must be written to the LUT regardless of the color mode. Only the values in Section 7.4 are referred.
+                This command has no effect on other commands, parameter and contents of frame memory. Visible change takes effect
+                next time the frame memory is written to.
+            */
+            write color_set = 0x2D => color::ColorSet, color::ColorSetWrite;
+            /**
+                This command transfers image data from ILI9341’s frame memory to the host processor starting at the pixel location
+                specified by preceding set_column_address and set_page_address commands.
+        
+                If Memory Access control B5 = 0:
+                The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
+                frame memory at (SC, SP). The column register is then incremented and pixels read from the frame memory until the
+                column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
+                incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value or the host
+                processor sends another command.
+        
+                If Memory Access Control B5 = 1:
+                The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
+                frame memory at (SC, SP). The page register is then incremented and pixels read from the frame memory until the page
+                register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
+                Pixels are read from the frame memory until the column register equals the End Column (EC) value or the host processor
+                sends another command.
+            */
+            read_slice memory_read = 0x2E;
+            /**
+                This command transfers image data from ILI9341’s frame memory to the host processor starting at the pixel location
+                specified by preceding set_column_address and set_page_address commands.
+        
+                If Memory Access control B5 = 0:
+                The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
+                frame memory at (SC, SP). The column register is then incremented and pixels read from the frame memory until the
+                column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
+                incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value or the host
+                processor sends another command.
+        
+                If Memory Access Control B5 = 1:
+                The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
+                frame memory at (SC, SP). The page register is then incremented and pixels read from the frame memory until the page
+                register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
+                Pixels are read from the frame memory until the column register equals the End Column (EC) value or the host processor
+                sends another command.
+            */
+            read_iter memory_read_iter = 0x2E;
+            /**
+                This command defines the partial mode’s display area. There are 2 parameters associated with this command, the first
+                defines the Start Row (SR) and the second the End Row (ER), as illustrated in the figures below. SR and ER refer to the
+                Frame Memory Line Pointer.
+            */
+            write partial_area = 0x30 => partial_area::PartialArea, partial_area::PartialAreaWrite;
+            /// Vertical Scrolling Definition
+            write vertical_scrolling_definition = 0x33 => vertical_scrolling::VerticalScrollingDefinition, vertical_scrolling::VerticalScrollingDefinitionWrite;
+            /// Tearing Effect Line OFF
+            command tearing_effect_line_off = 0x34;
+            /**
+                This command is used to turn ON the Tearing Effect output signal from the TE signal line. This output is not affected by
+                changing MADCTL bit B4. The Tearing Effect Line On has one parameter which describes the mode of the Tearing Effect
+                Output Line.
+            */
+            write tearing_effect_line_on = 0x35 => tearing_effect_line_on::TearingEffectLineOn, tearing_effect_line_on::TearingEffectLineOnWrite;
+            /**
+                This command defines read/write scanning direction of frame memory.
+                This command makes no change on the other driver status.
+                Note: When BGR bit is changed, the new setting is active immediately without update the content in Frame Memory again.
+            */
+            write memory_access_control = 0x36 => memory_access_control::MemoryAccessControl, memory_access_control::MemoryAccessControlWrite;
+            /**
+                This command is used together with Vertical Scrolling Definition (33h). These two commands describe the scrolling area
+                and the scrolling mode. The Vertical Scrolling Start Address command has one parameter which describes the address of
+                the line in the Frame Memory that will be written as the first line after the last line of the Top Fixed Area
+                on the display.
+            */
+            write vertical_scrolling_start_address = 0x37 => vertical_scrolling_start_address::VerticalScrollingStartAddress, vertical_scrolling_start_address::VerticalScrollingStartAddressWrite;
+            /**
+                This command is used to recover from Idle mode on.
+                In the idle off mode, LCD can display maximum 262,144 colors.
+            */
+            command idle_mode_off = 0x38;
+            /**
+                This command is used to enter into Idle mode on.
+                In the idle on mode, color expression is reduced. The primary and the secondary colors using MSB of each R, G and B in
+                the
+                Frame Memory, 8 color depth data is displayed.
+            */
+            command idle_mode_on = 0x39;
+            /**
+                This command sets the pixel format for the RGB image data used by the interface. DPI [2:0] is the pixel format select
+                of RGB
+                interface and DBI [2:0] is the pixel format of MCU interface. If a particular interface, either RGB interface or MCU
+                interface, is
+                not used then the corresponding bits in the parameter are ignored..
+        
+                If using RGB Interface must selection serial interface.
+            */
+            write pixel_format_set = 0x3A => pixel_format::PixelFormatSet, pixel_format::PixelFormatSetWrite;
+            /**
+                This command transfers image data from the host processor to the display module’s frame memory continuing from the
+                pixel location following the previous write_memory_continue or write_memory_start command.
+        
+                If set_address_mode B5 = 0:
+                Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
+                write_memory_start  or
+                write_memory_continue. The column register is then incremented and pixels are written to the frame memory until the
+                column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
+                incremented. Pixels are written to the frame memory until the page register equals the End Page (EP) value and the
+                column register equals the EC value, or the host processor sends another command. If the number of pixels exceeds (EC –
+                SC + 1) * (EP – SP + 1) the extra pixels are ignored.
+        
+                If set_address_mode B5 = 1:
+                Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
+                write_memory_start  or
+                write_memory_continue. The page register is then incremented and pixels are written to the frame memory until the page
+                register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
+                Pixels are written to the frame memory until the column register equals the End column (EC) value and the page register
+                equals the EP value, or the host processor sends another command. If the number of pixels exceeds (EC – SC + 1) * (EP –
+                SP + 1) the extra pixels are ignored.
+        
+                Sending any other command can stop frame Write.
+        
+                Frame Memory Access and Interface setting (B3h), WEMODE=0
+                When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the exceeding data will be ignored.
+        
+                Frame Memory Access and Interface setting (B3h), WEMODE=1
+                When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the column and page number will be reset, and the
+                exceeding data will be written into the following column and page.
+            */
+            write_slice write_memory_continue = 0x3C;
+            /**
+                This command transfers image data from the host processor to the display module’s frame memory continuing from the
+                pixel location following the previous write_memory_continue or write_memory_start command.
+        
+                If set_address_mode B5 = 0:
+                Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
+                write_memory_start  or
+                write_memory_continue. The column register is then incremented and pixels are written to the frame memory until the
+                column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
+                incremented. Pixels are written to the frame memory until the page register equals the End Page (EP) value and the
+                column register equals the EC value, or the host processor sends another command. If the number of pixels exceeds (EC –
+                SC + 1) * (EP – SP + 1) the extra pixels are ignored.
+        
+                If set_address_mode B5 = 1:
+                Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
+                write_memory_start  or
+                write_memory_continue. The page register is then incremented and pixels are written to the frame memory until the page
+                register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
+                Pixels are written to the frame memory until the column register equals the End column (EC) value and the page register
+                equals the EP value, or the host processor sends another command. If the number of pixels exceeds (EC – SC + 1) * (EP –
+                SP + 1) the extra pixels are ignored.
+        
+                Sending any other command can stop frame Write.
+        
+                Frame Memory Access and Interface setting (B3h), WEMODE=0
+                When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the exceeding data will be ignored.
+        
+                Frame Memory Access and Interface setting (B3h), WEMODE=1
+                When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the column and page number will be reset, and the
+                exceeding data will be written into the following column and page.
+            */
+            write_iter write_memory_continue_iter = 0x3C;
+            /**
+                This command transfers image data from the display module’s frame memory to the host processor continuing from the
+                location following the previous read_memory_continue (3Eh) or read_memory_start (2Eh) command.
+        
+                If set_address_mode B5 = 0:
+                Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
+                read_memory_continue. The column register is then incremented and pixels are read from the frame memory until the
+                column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
+                incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value and the
+                column register equals the EC value, or the host processor sends another command.
+        
+                If set_address_mode B5 = 1:
+                Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
+                read_memory_continue. The page register is then incremented and pixels are read from the frame memory until the page
+                register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
+                Pixels are read from the frame memory until the column register equals the End Column (EC) value and the page register
+                equals the EP value, or the host processor sends another command.
+        
+                This command makes no change to the other driver status.
+            */
+            read_slice read_memory_continue = 0x3E;
+            /**
+                This command transfers image data from the display module’s frame memory to the host processor continuing from the
+                location following the previous read_memory_continue (3Eh) or read_memory_start (2Eh) command.
+        
+                If set_address_mode B5 = 0:
+                Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
+                read_memory_continue. The column register is then incremented and pixels are read from the frame memory until the
+                column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
+                incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value and the
+                column register equals the EC value, or the host processor sends another command.
+        
+                If set_address_mode B5 = 1:
+                Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
+                read_memory_continue. The page register is then incremented and pixels are read from the frame memory until the page
+                register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
+                Pixels are read from the frame memory until the column register equals the End Column (EC) value and the page register
+                equals the EP value, or the host processor sends another command.
+        
+                This command makes no change to the other driver status.
+            */
+            read_iter read_memory_continue_iter = 0x3E;
+            /**
+                This command turns on the display Tearing Effect output signal on the TE signal line when the display reaches line STS.
+                The TE signal is not affected by changing set_address_mode bit B4. The Tearing Effect Line On has one parameter that
+                describes the Tearing Effect Output Line mode.
+                tvdl tvdh
+                Vertical Time Scale
+        
+                Note that set_tear_scanline with STS=0 is equivalent to set_tear_on with M=0.
+                The Tearing Effect Output line shall be active low when the display module is in Sleep mode.
+            */
+            write set_tear_scanline = 0x44 => tear_scanline::SetTearScanline, tear_scanline::SetTearScanlineWrite;
+            /**
+                The display returns the current scan line, GTS, used to update the display device. The total number of scan lines on a
+                display device is defined as VSYNC + VBP + VACT + VFP. The first scan line is defined as the first line of V-Sync and is
+                denoted as Line 0.
+                When in Sleep Mode, the value returned by get_scanline is undefined.
+            */
+            read get_scanline = 0x45 => get_scanline::GetScanline;
+            /**
+                This command is used to adjust the brightness value of the display.
+                It should be checked what is the relationship between this written value and output brightness of the display. This
+                relationship
+                is defined on the display module specification.
+                In principle relationship is that 00h value means the lowest brightness and FFh value means the highest brightness.
+            */
+            write write_display_brightness = 0x51 => write_display_brightness::DisplayBrightness, write_display_brightness::DisplayBrightnessWrite;
+            /**
+                This command returns the brightness value of the display.
+        
+                It should be checked what the relationship between this returned value and output brightness of the display. This
+                relationship is defined on the display module specification.
+                In principle the relationship is that 00h value means the lowest brightness and FFh value means the highest brightness.
+            */
+            read read_display_brightness = 0x52 => read_display_brightness::DisplayBrightness;
+            /**
+                This command is used to control display brightness.
+                BCTRL: Brightness Control Block On/Off, This bit is always used to switch brightness for display.
+                0 = Off (Brightness registers are 00h, DBV[7..0])
+                1 = On (Brightness registers are active, according to the other parameters.)
+        
+                DD: Display Dimming, only for manual brightness setting
+                DD = 0: Display Dimming is off
+                DD = 1: Display Dimming is on
+        
+                BL: Backlight Control On/Off
+                0 = Off (Completely turn off backlight circuit. Control lines must be low. )
+                1 = On
+                Dimming function is adapted to the brightness registers for display when bit BCTRL is changed at DD=1, e.g. BCTRL: 0
+                (cid:4)
+                1 or 1(cid:4) 0.
+        
+                When BL bit change from “On” to “Off”, backlight is turned off without gradual dimming, even if dimming-on (DD=1) are
+                selected.
+            */
+            write write_ctrl_display = 0x53 => write_ctrl_display::CtrlDisplay, write_ctrl_display::CtrlDisplayWrite;
+            /**
+                This command is used to return brightness setting.
+        
+                BCTRL: Brightness Control Block On/Off,
+                ‘0’ = Off (Brightness registers are 00h)
+                ‘1’ = On (Brightness registers are active, according to the DBV[7..0] parameters.)
+        
+                DD: Display Dimming
+                ‘0’ = Display Dimming is off
+                ‘1’ = Display Dimming is on
+        
+                BL: Backlight On/Off
+                ‘0’ = Off (Completely turn off backlight circuit. Control lines must be low. )
+                ‘1’ = On
+            */
+            read read_ctrl_display = 0x54 => read_ctrl_display::CtrlDisplay;
+            /**
+                This command is used to set parameters for image content based adaptive brightness control functionality.
+                There is possible to use 4 different modes for content adaptive image functionality, which are defined on a table
+                below.
+            */
+            write write_content_adaptive_brightness_control = 0x55 => write_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl, write_content_adaptive_brightness_control::ContentAdaptiveBrightnessControlWrite;
+            /**
+                This command is used to read the settings for image content based adaptive brightness control functionality.
+                It is possible to use 4 different modes for content adaptive image functionality, which are defined on a table below.
+            */
+            read read_content_adaptive_brightness_control = 0x56 => read_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl;
+            /**
+                This command is used to set the minimum brightness value of the display for CABC function.
+                CMB[7:0]: CABC minimum brightness control, this parameter is used to avoid too much brightness reduction.
+                When CABC is active, CABC cannot reduce the display brightness to less than CABC minimum brightness setting. Image
+                processing function is worked as normal, even if the brightness cannot be changed.
+                This function does not affect to the other function, manual brightness setting. Manual brightness can be set the display
+                brightness to less than CABC minimum brightness. Smooth transition and dimming function can be worked as normal.
+                When display brightness is turned off (BCTRL=0 of “Write CTRL Display (53h)”), CABC minimum brightness setting is
+                ignored.
+                In principle relationship is that 00h value means the lowest brightness for CABC and FFh value means the highest
+                brightness for CABC.
+            */
+            write write_cabc_minimum_brightness = 0x5E => write_cabc_minimum_brightness::CabcMinimumBrightness, write_cabc_minimum_brightness::CabcMinimumBrightnessWrite;
+            /**
+                This command returns the minimum brightness value of CABC function.
+                In principle the relationship is that 00h value means the lowest brightness and FFh value means the highest brightness.
+                CMB[7:0] is CABC minimum brightness specified with “Write CABC minimum brightness (5Eh)” command. In principle
+                relationship is that 00h value means the lowest brightness for CABC and FFh value means the highest brightness for
+                CABC.
+            */
+            read read_cabc_minimum_brightness = 0x5F => read_cabc_minimum_brightness::CabcMinimumBrightness;
+            /**
+                This read byte identifies the LCD module’s manufacturer ID and it is specified by User
+                The 1st parameter is dummy data.
+                The 2nd parameter is LCD module’s manufacturer ID.
+            */
+            read read_id1 = 0xDA => read_id1::Id1;
+            /**
+                This read byte is used to track the LCD module/driver version. It is defined by display supplier (with User’s
+                agreement) and
+                changes each time a revision is made to the display, material or construction specifications.
+                The 1st parameter is dummy data.
+                The 2nd parameter is LCD module/driver version ID and the ID parameter range is from 80h to FFh.
+                The ID2 can be programmed by MTP function.
+            */
+            read read_id2 = 0xDB => read_id2::Id2;
+            /**
+                This read byte identifies the LCD module/driver and It is specified by User.
+                The 1st parameter is dummy data.
+                The 2nd parameter is LCD module/driver ID.
+                The ID3 can be programmed by MTP function.
+            */
+            read read_id3 = 0xDC => read_id3::Id3;
+            /**
+                Sets the operation status of the display interface. The setting becomes effective as soon as the command is received.
+                EPL: DE polarity (“0”= High enable for RGB interface, “1”= Low enable for RGB interface)
+                DPL: DOTCLK polarity set (“0”= data fetched at the rising time, “1”= data fetched at the falling time)
+                HSPL: HSYNC polarity (“0”= Low level sync clock, “1”= High level sync clock)
+                VSPL: VSYNC polarity (“0”= Low level sync clock, “1”= High level sync clock)
+                RCM [1:0]: RGB interface selection (refer to the RGB interface section).
+        
+                ByPass_MODE: Select display data path whether Memory or Direct to Shift register when RGB Interface is used.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write rgb_interface_signal_control = 0xB0 => rgb_interface_signal_control::RgbInterfaceSignalControl, rgb_interface_signal_control::RgbInterfaceSignalControlWrite;
+            /// Read back the parameters of `rgb_interface_signal_control` (B0h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_rgb_interface_signal_control = 0xB0 => rgb_interface_signal_control::RgbInterfaceSignalControl;
+            /**
+                Formula to calculate frame frequency: FrameRate = fosc / (ClocksPerLine x DivisionRatio x (Lines + VBP + VFP))
+                Sets the division ratio for internal clocks of Normal mode at MCU interface.
+                fosc : internal oscillator frequency
+                Clocks per line : RTNA setting
+                Division ratio : DIVA setting
+                Lines : total driving line number
+                VBP : back porch line number
+                VFP : front porch line number
+        
+                DIVA [1:0] : division ratio for internal clocks when Normal mode.
+        
+                RTNA [4:0] : RTNA[4:0] is used to set 1H (line) period of Normal mode at MCU interface.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write frame_control_in_normal_mode = 0xB1 => frame_control_in_normal_mode::FrameControlInNormalMode, frame_control_in_normal_mode::FrameControlInNormalModeWrite;
+            /// Read back the parameters of `frame_control_in_normal_mode` (B1h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_frame_control_in_normal_mode = 0xB1 => frame_control_in_normal_mode::FrameControlInNormalMode;
+            /**
+                Formula to calculate frame frequency: FrameRate = fosc / (ClocksPerLine x DivisionRatio x (Lines + VBP + VFP))
+                Sets the division ratio for internal clocks of Idle mode at MCU interface.
+                fosc : internal oscillator frequency
+                Clocks per line : RTNB setting
+                Division ratio : DIVB setting
+                Lines : total driving line number
+                VBP : back porch line number
+                VFP : front porch line number
+        
+                DIVB [1:0]: division ratio for internal clocks when Idle mode.
+        
+                RTNB [4:0]: RTNB[4:0] is used to set 1H (line) period of Idle mode at MCU interface.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write frame_control_in_idle_mode = 0xB2 => frame_control_in_idle_mode::FrameControlInIdleMode, frame_control_in_idle_mode::FrameControlInIdleModeWrite;
+            /// Read back the parameters of `frame_control_in_idle_mode` (B2h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_frame_control_in_idle_mode = 0xB2 => frame_control_in_idle_mode::FrameControlInIdleMode;
+            /**
+                Formula to calculate frame frequency: FrameRate = fosc / (ClocksPerLine x DivisionRatio x (Lines + VBP + VFP))
+                Sets the division ratio for internal clocks of Partial mode (Idle mode off) at MCU interface.
+                fosc : internal oscillator frequency
+                Clocks per line : RTNC setting
+                Division ratio : DIVC setting
+                Lines : total driving line number
+                VBP : back porch line number
+                VFP : front porch line number
+        
+                DIVC [1:0]: division ratio for internal clocks when Partial mode.
+        
+                RTNC [4:0]: RTNC [4:0] is used to set 1H (line) period of Partial mode at MCU interface.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write frame_control_in_partial_mode = 0xB3 => frame_control_in_partial_mode::FrameControlInPartialMode, frame_control_in_partial_mode::FrameControlInPartialModeWrite;
+            /// Read back the parameters of `frame_control_in_partial_mode` (B3h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_frame_control_in_partial_mode = 0xB3 => frame_control_in_partial_mode::FrameControlInPartialMode;
+            /**
+                Display inversion mode set
+                NLA: Inversion setting in full colors normal mode (Normal mode on)
+                NLB: Inversion setting in Idle mode (Idle mode on)
+                NLC: Inversion setting in full colors partial mode (Partial mode on / Idle mode off)
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write display_inversion_control = 0xB4 => display_inversion_control::DisplayInversionControl, display_inversion_control::DisplayInversionControlWrite;
+            /// Read back the parameters of `display_inversion_control` (B4h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_display_inversion_control = 0xB4 => display_inversion_control::DisplayInversionControl;
+            /**
+                VFP [6:0] / VBP [6:0]: The VFP [6:0] and VBP [6:0] bits specify the line number of vertical front and back porch period
+                respectively.
+                Note: VFP + VBP ≦ 254 HSYNC signals
+        
+                HFP [4:0] / HBP [4:0]: The HFP [4:0] and HBP [4:0] bits specify the line number of horizontal front and back porch
+                period
+                respectively.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write blanking_porch_control = 0xB5 => blanking_porch_control::BlankingPorchControl, blanking_porch_control::BlankingPorchControlWrite;
+            /// Read back the parameters of `blanking_porch_control` (B5h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_blanking_porch_control = 0xB5 => blanking_porch_control::BlankingPorchControl;
+            /**
+                PTG [1:0]: Set the scan mode in non-display area.
+        
+                PT [1:0]: Determine source/VCOM output in a non-display area in the partial display mode.
+        
+                SS: Select the shift direction of outputs from the source driver.
+                In addition to the shift direction, the settings for both SS and BGR bits are required to change the assignment of R, G,
+                and B dots to the source driver pins.
+                To assign R, G, B dots to the source driver pins from S1 to S720, set SS = 0.
+                To assign R, G, B dots to the source driver pins from S720 to S1, set SS = 1.
+        
+                REV: Select whether the liquid crystal type is normally white type or normally black type.
+        
+                ISC [3:0]: Specify the scan cycle interval of gate driver in non-display area when PTG [1:0] =”10” to select interval
+                scan.
+                Then scan cycle is set as odd number from 0~29 frame periods. The polarity is inverted every scan cycle.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write display_function_control = 0xB6 => display_function_control::DisplayFunctionControl, display_function_control::DisplayFunctionControlWrite;
+            /// Read back the parameters of `display_function_control` (B6h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_display_function_control = 0xB6 => display_function_control::DisplayFunctionControl;
+            /**
+                DSTB: The ILI9341 driver enters the Deep Standby Mode when DSTB is set to high (“1”). In Deep Standby mode, both
+                internal logic power and SRAM power are turn off, the display data stored in the Frame Memory and the instructions are
+                not
+                saved. Rewrite Frame Memory content and instructions after the Deep Standby Mode is exited.
+                Note:  ILI9341 provides two ways to exit the Deep Standby Mode:
+                (1) Exit Deep Standby Mode by pull down CSX to low (“0”) 6 times.
+                (2) Input a RESX pulse with effective low level duration to start up the inside logic regulator and makes a transition
+                to the initial state.
+        
+                1 2 Wait 1ms or more 3 4 5 6
+                CSX
+                WRX “High”
+                RDX “High”
+                D/CX “Low” or “High”
+                D[17:0] Don CareDon Care Don CareDon Care Don CareDon Care
+        
+                GAS: Low voltage detection control.
+        
+                GON/DTE: Set the output level of gate driver G1 ~ G320 as follows
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write entry_mode_set = 0xB7 => entry_mode::EntryModeSet, entry_mode::EntryModeSetWrite;
+            /// Read back the parameters of `entry_mode_set` (B7h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_entry_mode = 0xB7 => entry_mode::EntryModeSet;
+            /**
+                TH_UI [3:0]: These bits are used to set the percentage of grayscale data accumulate histogram value in the user
+                interface
+                (UI) mode. This ratio of maximum number of pixels that makes display image white (=data “255”) to the total of
+                pixels by image processing.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control1 = 0xB8 => backlight_control1::BacklightControl1, backlight_control1::BacklightControl1Write;
+            /// Read back the parameters of `backlight_control1` (B8h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control1 = 0xB8 => backlight_control1::BacklightControl1;
+            /**
+                TH_ST [3:0]: These bits are used to set the percentage of grayscale data accumulate histogram value in the still picture
+                mode. This ratio of maximum number of pixels that makes display image white (=data “255”) to the total of pixels
+                by image processing.
+        
+                TH_MV [3:0]: These bits are used to set the percentage of grayscale data accumulate histogram value in the moving image
+                mode. This ratio of maximum number of pixels that makes display image white (=data “255”) to the total of pixels
+                by image processing.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control2 = 0xB9 => backlight_control2::BacklightControl2, backlight_control2::BacklightControl2Write;
+            /// Read back the parameters of `backlight_control2` (B9h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control2 = 0xB9 => backlight_control2::BacklightControl2;
+            /**
+                DTH_UI [3:0]: This parameter is used set the minimum limitation of grayscale threshold value in User Icon (UI) image
+                mode.
+                This register setting will limit the minimum Dth value to prevent the display image from being too white and
+                the display quality is not acceptable.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control3 = 0xBA => backlight_control3::BacklightControl3, backlight_control3::BacklightControl3Write;
+            /// Read back the parameters of `backlight_control3` (BAh)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control3 = 0xBA => backlight_control3::BacklightControl3;
+            /**
+                DTH_ST [3:0]/DTH_MV [3:0]: This parameter is used set the minimum limitation of grayscale threshold value. This register
+                setting will limit the minimum Dth value to prevent the display image from being too white and the
+                display quality is not acceptable.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control4 = 0xBB => backlight_control4::BacklightControl4, backlight_control4::BacklightControl4Write;
+            /// Read back the parameters of `backlight_control4` (BBh)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control4 = 0xBB => backlight_control4::BacklightControl4;
+            /**
+                DIM1 [2:0]: This parameter is used to set the transition time of brightness level to avoid the sharp brightness
+                transition on
+                vision.
+        
+                DIM2 [3:0]: This parameter is used to set the threshold of brightness change.
+                When the brightness transition difference is smaller than DIM2 [3:0], the brightness transition will be ignored.
+                For example:
+                If | brightness B – brightness A| < DIM2 [2:0], the brightness transition will be ignored and keep the brightness A.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control5 = 0xBC => backlight_control5::BacklightControl5, backlight_control5::BacklightControl5Write;
+            /// Read back the parameters of `backlight_control5` (BCh)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control5 = 0xBC => backlight_control5::BacklightControl5;
+            /**
+                PWM_DIV [7:0]: PWM_OUT output frequency control. This command is used to adjust the PWM waveform frequency of
+                PWM_OUT. The PWM frequency can be calculated by using the following equation.
+        
+                16MHz
+                f  =
+                PWM_OUT   (PWM_DIV[7:0]+1)×255
+        
+                Note: The output frequency tolerance of internal frequency divider in CABC is ±10%
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control7 = 0xBE => backlight_control7::BacklightControl7, backlight_control7::BacklightControl7Write;
+            /// Read back the parameters of `backlight_control7` (BEh)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control7 = 0xBE => backlight_control7::BacklightControl7;
+            /**
+                LEDPWMPOL: The bit is used to define polarity of LEDPWM signal.
+        
+                LEDONPOL: This bit is used to control LEDON pin.
+        
+                LEDONR: This bit is used to control LEDON pin.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write backlight_control8 = 0xBF => backlight_control8::BacklightControl8, backlight_control8::BacklightControl8Write;
+            /// Read back the parameters of `backlight_control8` (BFh)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_backlight_control8 = 0xBF => backlight_control8::BacklightControl8;
+            /**
+                VRH [5:0]: Set the GVDD level, which is a reference level for the VCOM level and the grayscale voltage level.
+        
+                Note1: Make sure that VC and VRH setting restriction: GVDD ≦ (AVDD - 0.5) V.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write power_control1 = 0xC0 => power_control1::PowerControl1, power_control1::PowerControl1Write;
+            /// Read back the parameters of `power_control1` (C0h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_power_control1 = 0xC0 => power_control1::PowerControl1;
+            /**
+                BT [2:0]: Sets the factor used in the step-up circuits.
+                Select the optimal step-up factor for the operating voltage. To reduce power consumption, set a smaller factor.
+                Note1: Make sure that AVDD setting restriction: AVDD ≦ 5.5 V.
+                2: Make sure that VGH and VGL setting restriction: VGH -VGL≦ 32 V.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write power_control2 = 0xC1 => power_control2::PowerControl2, power_control2::PowerControl2Write;
+            /// Read back the parameters of `power_control2` (C1h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_power_control2 = 0xC1 => power_control2::PowerControl2;
+            /**
+                VMH [6:0] : Set the VCOMH voltage.
+        
+                VML [6:0] : Set the VCOML voltage
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write vcom_control1 = 0xC5 => vcom_control1::VcomControl1, vcom_control1::VcomControl1Write;
+            /// Read back the parameters of `vcom_control1` (C5h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_vcom_control1 = 0xC5 => vcom_control1::VcomControl1;
+            /**
+                nVM: nVM equals to “0” after power on reset and VCOM offset equals to program MTP value. When nVM set to “1”, setting
+                of VMF [6:0] becomes valid and VCOMH/VCOML can be adjusted.
+        
+                VMF [6:0]: Set the VCOM offset voltage.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write vcom_control2 = 0xC7 => vcom_control2::VcomControl2, vcom_control2::VcomControl2Write;
+            /// Read back the parameters of `vcom_control2` (C7h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_vcom_control2 = 0xC7 => vcom_control2::VcomControl2;
+            /**
+                REG_VD [2:0]: Vcore voltage control.
+                VBC [2:0]: DDVDH voltage control.
+                The first three parameters are fixed (39h, 2Ch, 00h).
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write power_control_a = 0xCB => power_control_a::PowerControlA, power_control_a::PowerControlAWrite;
+            /**
+                PCEQ: PC and EQ operation for power saving.
+                DC_ena: Discharge path enable, enable to discharge the ESD protection.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write power_control_b = 0xCF => power_control_b::PowerControlB, power_control_b::PowerControlBWrite;
+            /**
+                This command is used to program the NV memory data. After a successful MTP operation, the information of PGM_DATA
+                [7:0] will programmed to NV memory.
+                PGM_ADR [2:0]: The select bits of ID1, ID2, ID3 and VMF [6:0] programming.
+        
+                PGM_DATA [7:0]: The programmed data.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write nv_memory_write = 0xD0 => nv_memory_write::NvMemory, nv_memory_write::NvMemoryWrite;
+            /**
+                KEY [23:0]: NV memory programming protection key. When writing MTP data to D1h, this register must be set to
+                0x55AA66h to enable MTP programming. If D1h register is not written with 0x55AA66h, then NV memory programming will
+                be aborted.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write nv_memory_protection_key = 0xD1 => nv_memory_protection_key::NvMemoryProtectionKey, nv_memory_protection_key::NvMemoryProtectionKeyWrite;
+            /**
+                ID1_CNT [2:0] / ID2_CNT [2:0] / ID3_CNT [2:0] / VMF_CNT [2:0]: NV memory program record. The bits will increase “+1”
+                automatically after writing the PGM_DATA [7:0] to NV memory.
+        
+                BUSY: The status bit of NV memory programming.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext nv_memory_status_read = 0xD2 => nv_memory_status_read::NvMemoryStatus;
+            /**
+                Read IC device code.
+                The 1st parameter is dummy read period.
+                The 2nd parameter means the IC version.
+                The 3rd and 4th parameter mean the IC model name.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_id4 = 0xD3 => read_id4::Id4;
+            /// Positive Gamma Correction
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write positive_gamma_correction = 0xE0 => positive_gamma_correction::PositiveGammaCorrection, positive_gamma_correction::PositiveGammaCorrectionWrite;
+            /// Read back the parameters of `positive_gamma_correction` (E0h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_positive_gamma_correction = 0xE0 => positive_gamma_correction::PositiveGammaCorrection;
+            /// Negative Gamma Correction
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write negative_gamma_correction = 0xE1 => negative_gamma_correction::NegativeGammaCorrection, negative_gamma_correction::NegativeGammaCorrectionWrite;
+            /// Read back the parameters of `negative_gamma_correction` (E1h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_negative_gamma_correction = 0xE1 => negative_gamma_correction::NegativeGammaCorrection;
+            /**
+                RCAx [3:0]: Gamma Macro-adjustment registers for red gamma curve.
+                BCAx [3:0]: Gamma Macro-adjustment registers for blue gamma curve.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write digital_gamma_control1 = 0xE2 => digital_gamma_control1::DigitalGammaControl1, digital_gamma_control1::DigitalGammaControl1Write;
+            /**
+                RFAx [3:0]: Gamma Micro-adjustment register for red gamma curve.
+                BFAx [3:0]: Gamma Micro-adjustment register for blue gamma curve.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write digital_gamma_control2 = 0xE3 => digital_gamma_control2::DigitalGammaControl2, digital_gamma_control2::DigitalGammaControl2Write;
+            /**
+                NOW: Gate driver non-overlap timing control.
+                EQ: EQ timing control.
+                CR: CR timing control.
+                PC [1:0]: Pre-charge timing control.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write driver_timing_control_a = 0xE8 => driver_timing_control_a::DriverTimingControlA, driver_timing_control_a::DriverTimingControlAWrite;
+            /// Driver Timing Control A, alternate command code (not in the datasheet, sent by some vendor init sequences)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write driver_timing_control_a2 = 0xE9 => driver_timing_control_a::DriverTimingControlA, driver_timing_control_a::DriverTimingControlAWrite;
+            /// VG_SW_T1 [1:0] - VG_SW_T4 [1:0]: Gate driver timing control.
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write driver_timing_control_b = 0xEA => driver_timing_control_b::DriverTimingControlB, driver_timing_control_b::DriverTimingControlBWrite;
+            /**
+                CP1 [1:0] / CP23 [1:0]: Soft start control.
+                En_vcl [1:0] / En_ddvdh [1:0] / En_vgh [1:0] / En_vgl [1:0]: Power on sequence control.
+                DDVDH_ENH: DDVDH enhance mode (only for 8 external capacitors).
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write power_on_sequence_control = 0xED => power_on_sequence_control::PowerOnSequenceControl, power_on_sequence_control::PowerOnSequenceControlWrite;
+            /**
+                Not in the datasheet. Sent as 03h, 80h, 02h by most vendor init sequences, which is the default of the register
+                type.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write undocumented_ef = 0xEF => undocumented_ef::UndocumentedEf, undocumented_ef::UndocumentedEfWrite;
+            /// 3G_enb: Enable 3 gamma control.
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write enable_3g = 0xF2 => enable_3g::Enable3G, enable_3g::Enable3GWrite;
+            /**
+                MY_EOR / MX_EOR / MV_EOR / BGR_EOR:
+                The set value of MADCTL is used in the IC is derived as exclusive OR between 1st Parameter of IFCTL and MADCTL
+                Parameter.
+        
+                MDT [1:0]: Select the method of display data transferring.
+        
+                WEMODE: Memory write control
+                WEMODE=0: When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the exceeding data will be ignored.
+                WEMODE=1: When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the column and page number will be
+                reset, and the exceeding data will be written into the following column and page.
+        
+                ENDIAN: Select Little Endian Interface bit. At Little Endian mode, the host sends LSB data first.
+                Note: Little Endian is valid on only 65K 8-bit and 9-bit MCU interface mode.
+        
+                DM [1:0]: Select the display operation mode.
+                The DM [1:0] setting allows switching between internal clock operation mode and external display interface operation
+                mode.
+                However, switching between the RGB interface operation mode and the VSYNC interface operation mode is prohibited.
+            */
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write interface_control = 0xF6 => interface_control::InterfaceControl, interface_control::InterfaceControlWrite;
+            /// Read back the parameters of `interface_control` (F6h)
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            read_ext read_interface_control = 0xF6 => interface_control::InterfaceControl;
+            /// Ratio [1:0]: Ratio control of the DDVDH pump (2xVCI or 3xVCI).
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            write pump_ratio_control = 0xF7 => pump_ratio_control::PumpRatioControl, pump_ratio_control::PumpRatioControlWrite;
+
+            // word transfers, see Interface::send_parameters_u16 ff.
+            /// Memory Write with 16-bit words, e.g. RGB565 pixels on a 16-bit bus
+            write_u16 memory_write_u16 = 0x2C;
+            /// Memory Write with 18-bit words, e.g. RGB666 pixels on an 18-bit bus
+            write_u32 memory_write_u32 = 0x2C;
+            /// Write Memory Continue with 16-bit words, e.g. RGB565 pixels on a 16-bit bus
+            write_u16 write_memory_continue_u16 = 0x3C;
+            /// Write Memory Continue with 18-bit words, e.g. RGB666 pixels on an 18-bit bus
+            write_u32 write_memory_continue_u32 = 0x3C;
+            /// Memory Read with 16-bit words
+            read_u16 memory_read_u16 = 0x2E;
+            /// Memory Read with 18-bit words, e.g. RGB666 pixels on an 18-bit bus
+            read_u32 memory_read_u32 = 0x2E;
+            /// Read Memory Continue with 16-bit words
+            read_u16 read_memory_continue_u16 = 0x3E;
+            /// Read Memory Continue with 18-bit words, e.g. RGB666 pixels on an 18-bit bus
+            read_u32 read_memory_continue_u32 = 0x3E;
+
+            // autogenerated commands end
+        }
+    };
+}
+
+/// Generates the blocking Controller methods from the command table
+macro_rules! blocking_commands {
+    (@fn $(#[$attr:meta])* command $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<(), Iface::Error> {
+            self.command($cmd)
+        }
+    };
+    (@fn $(#[$attr:meta])* timed $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<(), Iface::Error> {
+            self.timed_command($cmd)
+        }
+    };
+    (@fn $(#[$attr:meta])* read $name:ident $cmd:literal $reg:ty) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<$reg, Iface::Error> {
+            let mut r = <$reg>::default();
+            self.read_parameters($cmd, &mut r.data)?;
+            Ok(r)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_ext $name:ident $cmd:literal $reg:ty) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<$reg, Iface::Error> {
+            let mut r = <$reg>::default();
+            self.read_extended($cmd, &mut r.data)?;
+            Ok(r)
+        }
+    };
+    (@fn $(#[$attr:meta])* write $name:ident $cmd:literal $reg:ty, $w:ty) => {
+        $(#[$attr])*
+        pub fn $name<F>(&mut self, f: F) -> Result<(), Iface::Error>
+        where
+            F: FnOnce($w) -> $w
+        {
+            self.send_parameters($cmd, &<$reg>::default().write(f).data)
+        }
+    };
+    (@fn $(#[$attr:meta])* write_slice $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name(&mut self, d: &[u8]) -> Result<(), Iface::Error> {
+            self.send_parameters($cmd, d)
+        }
+    };
+    (@fn $(#[$attr:meta])* write_iter $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name<T: IntoIterator<Item = u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
+            self.send_parameters_iter($cmd, d)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_slice $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name<'l>(&mut self, d: &'l mut [u8]) -> Result<&'l mut [u8], Iface::Error> {
+            self.read_parameters($cmd, d)?;
+            Ok(d)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_iter $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
+            self.read_parameters_iter($cmd, d)
+        }
+    };
+    (@fn $(#[$attr:meta])* write_u16 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name(&mut self, d: &[u16]) -> Result<(), Iface::Error> {
+            self.send_parameters_u16($cmd, d)
+        }
+    };
+    (@fn $(#[$attr:meta])* write_u32 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name(&mut self, d: &[u32]) -> Result<(), Iface::Error> {
+            self.send_parameters_u32($cmd, d)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_u16 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name<'l>(&mut self, d: &'l mut [u16]) -> Result<&'l mut [u16], Iface::Error> {
+            self.read_parameters_u16($cmd, d)?;
+            Ok(d)
+        }
+    };
+    (@fn $(#[$attr:meta])* read_u32 $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name<'l>(&mut self, d: &'l mut [u32]) -> Result<&'l mut [u32], Iface::Error> {
+            self.read_parameters_u32($cmd, d)?;
+            Ok(d)
+        }
+    };
+    ($($(#[$attr:meta])* $kind:ident $name:ident = $cmd:literal $(=> $($ty:ty),+)?;)*) => {
+        $(blocking_commands! { @fn $(#[$attr])* $kind $name $cmd $($($ty),+)? })*
+    };
+}
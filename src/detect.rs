@@ -0,0 +1,195 @@
+//! Identification of the attached controller and panel module
+//!
+//! [`Controller::detect`] reads the display identification (04h), ID1/ID2/ID3 (DAh-DCh) and,
+//! with the extended command set, the IC device code ID4 (D3h), and tells:
+//! - whether nothing answers: every byte reads 00h or FFh (no MISO line, floating bus)
+//! - the controller: ILI9341 (ID4 00h 93h 41h), ILI9340 or ILI9342 by their ID4, or an ST7789,
+//!   which answers 04h with 85h 85h 52h
+//! - the module vendor, looked up by ID1/ID2/ID3 in a table of [`Vendor`]s
+//!
+//! ID1 is the module manufacturer and ID2/ID3 the module version and ID, all programmed by the
+//! module supplier and not published in a common register, so the crate can't ship a vendor table.
+//! The application lists its own suppliers and calls
+//! [`detect_with_vendors`](Controller::detect_with_vendors), [`detect`](Controller::detect) only
+//! matches [`DEFAULT_IDS`], the module IDs of unprogrammed controllers.
+//!
+//! Without the extended command set ID4 can't be read and ILI934x controllers are reported as
+//! [`Chip::Unknown`].
+
+use embedded_hal::delay::DelayNs;
+
+use crate::shadow::RegisterCache;
+use crate::{Controller, Interface};
+
+/// Display controller
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Chip {
+    /// ILI9341
+    Ili9341,
+    /// ILI9340, a compatible predecessor
+    Ili9340,
+    /// ILI9342, the 320x240 variant
+    Ili9342,
+    /// Sitronix ST7789, a compatible clone
+    St7789,
+    /// Something answers, but isn't recognized
+    Unknown,
+    /// Nothing answers, every byte reads 00h or FFh
+    NoResponse,
+}
+
+/// Module vendor, keyed on the module IDs
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Vendor {
+    pub name: &'static str,
+    /// Module manufacturer ID (DAh)
+    pub id1: u8,
+    /// Module version ID (DBh), `None` matches any
+    pub id2: Option<u8>,
+    /// Module ID (DCh), `None` matches any
+    pub id3: Option<u8>,
+}
+
+impl Vendor {
+    /// The module IDs match
+    pub fn matches(&self, ids: [u8; 3]) -> bool {
+        self.id1 == ids[0]
+            && (self.id2.is_none() || self.id2 == Some(ids[1]))
+            && (self.id3.is_none() || self.id3 == Some(ids[2]))
+    }
+}
+
+/// Module IDs of the controllers as shipped, before the module supplier programs them
+pub const DEFAULT_IDS: &[Vendor] = &[
+    Vendor { name: "ILI9341 default", id1: 0x00, id2: Some(0x80), id3: Some(0x00) },
+    Vendor { name: "ST7789V default", id1: 0x85, id2: Some(0x85), id3: Some(0x52) },
+];
+
+/// Result of the detection
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Detected<'v> {
+    pub chip: Chip,
+    /// Module IDs ID1, ID2 and ID3
+    pub ids: [u8; 3],
+    /// The first vendor matching the module IDs
+    pub vendor: Option<&'v Vendor>,
+}
+
+/// Display identification (04h) of the ST7789
+const ST7789_ID: [u8; 3] = [0x85, 0x85, 0x52];
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Identify the controller, only unprogrammed modules are matched with [`DEFAULT_IDS`]
+    pub fn detect(&mut self) -> Result<Detected<'static>, Iface::Error> {
+        self.detect_with_vendors(DEFAULT_IDS)
+    }
+
+    /// Identify the controller and the module vendor with the table `vendors`
+    pub fn detect_with_vendors<'v>(&mut self, vendors: &'v [Vendor]) -> Result<Detected<'v>, Iface::Error> {
+        let identification = self.read_display_identification_information()?.into_bytes();
+        let ids = [self.read_id1()?.into_bytes()[0], self.read_id2()?.into_bytes()[0], self.read_id3()?.into_bytes()[0]];
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        let id4 = Some(self.read_id4()?.into_bytes());
+        #[cfg(not(feature = "Ili9341ExtendedCommandSet"))]
+        let id4: Option<[u8; 3]> = None;
+
+        let mut bytes = identification.iter().chain(ids.iter()).chain(id4.iter().flatten());
+        let first = identification[0];
+        let chip = if (first == 0x00 || first == 0xFF) && bytes.all(|&b| b == first) {
+            Chip::NoResponse
+        } else if identification == ST7789_ID {
+            Chip::St7789
+        } else {
+            match id4 {
+                Some([_, 0x93, 0x41]) => Chip::Ili9341,
+                Some([_, 0x93, 0x40]) => Chip::Ili9340,
+                Some([_, 0x93, 0x42]) => Chip::Ili9342,
+                _ => Chip::Unknown,
+            }
+        };
+        let vendor = match chip {
+            Chip::NoResponse => None,
+            _ => vendors.iter().find(|v| v.matches(ids)),
+        };
+        Ok(Detected { chip, ids, vendor })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInterface;
+
+    /// The Controller of a mock answering the identification reads
+    fn respond(identification: [u8; 3], ids: [u8; 3], id4: [u8; 3]) -> Controller<MockInterface> {
+        let mock = MockInterface::new();
+        mock.push_response(0x04, &identification)
+            .push_response(0xDA, &ids[..1])
+            .push_response(0xDB, &ids[1..2])
+            .push_response(0xDC, &ids[2..]);
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        mock.push_response(0xD3, &id4);
+        #[cfg(not(feature = "Ili9341ExtendedCommandSet"))]
+        let _ = id4;
+        Controller::new(mock)
+    }
+
+    const ACME: Vendor = Vendor { name: "Acme", id1: 0x54, id2: None, id3: Some(0x66) };
+
+    #[test]
+    fn ili9341() {
+        let mut lcd = respond([0x00, 0x80, 0x66], [0x54, 0x80, 0x66], [0x00, 0x93, 0x41]);
+        let detected = lcd.detect_with_vendors(&[ACME]).unwrap();
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        assert_eq!(detected.chip, Chip::Ili9341);
+        #[cfg(not(feature = "Ili9341ExtendedCommandSet"))]
+        assert_eq!(detected.chip, Chip::Unknown);
+        assert_eq!(detected.ids, [0x54, 0x80, 0x66]);
+        assert_eq!(detected.vendor, Some(&ACME));
+    }
+
+    #[test]
+    fn unprogrammed_module() {
+        let mut lcd = respond([0x00, 0x80, 0x00], [0x00, 0x80, 0x00], [0x00, 0x93, 0x41]);
+        let detected = lcd.detect().unwrap();
+        assert_eq!(detected.vendor.map(|v| v.name), Some("ILI9341 default"));
+        // Not a vendor of the application
+        let mut lcd = respond([0x00, 0x80, 0x00], [0x00, 0x80, 0x00], [0x00, 0x93, 0x41]);
+        assert_eq!(lcd.detect_with_vendors(&[ACME]).unwrap().vendor, None);
+    }
+
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    #[test]
+    fn ili934x_clones() {
+        let mut lcd = respond([0x00, 0x80, 0x00], [0x00, 0x80, 0x00], [0x00, 0x93, 0x40]);
+        assert_eq!(lcd.detect().unwrap().chip, Chip::Ili9340);
+        let mut lcd = respond([0x00, 0x80, 0x00], [0x00, 0x80, 0x00], [0x00, 0x93, 0x42]);
+        assert_eq!(lcd.detect().unwrap().chip, Chip::Ili9342);
+        let mut lcd = respond([0x00, 0x80, 0x00], [0x00, 0x80, 0x00], [0x00, 0x77, 0x89]);
+        assert_eq!(lcd.detect().unwrap().chip, Chip::Unknown);
+    }
+
+    #[test]
+    fn st7789() {
+        let mut lcd = respond([0x85, 0x85, 0x52], [0x85, 0x85, 0x52], [0x00, 0x00, 0x00]);
+        let detected = lcd.detect().unwrap();
+        assert_eq!(detected.chip, Chip::St7789);
+        assert_eq!(detected.vendor.map(|v| v.name), Some("ST7789V default"));
+    }
+
+    #[test]
+    fn empty_bus() {
+        for level in [0x00, 0xFF] {
+            let mut lcd = respond([level; 3], [level; 3], [level; 3]);
+            let vendors = [Vendor { name: "any", id1: level, id2: None, id3: None }];
+            let detected = lcd.detect_with_vendors(&vendors).unwrap();
+            assert_eq!(detected.chip, Chip::NoResponse);
+            assert_eq!(detected.vendor, None);
+        }
+    }
+}
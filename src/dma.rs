@@ -0,0 +1,233 @@
+//! Non-blocking frame memory transfers
+//!
+//! Interfaces which can move the data in the background (usually with DMA) implement
+//! [`DmaInterface`]. The Controller then starts frame memory writes from `'static` buffers and
+//! returns a [`Transfer`] handle, which can be polled, waited on or awaited. The buffer belongs to
+//! the interface while the data is sent and is handed back by the finished transfer, so the
+//! application can render the next part of the picture into a second buffer in the meantime and
+//! swap them.
+//!
+//! The `spi::SpiInterface` implements it with blocking transfers, see its example.
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::{Controller, Interface};
+
+/// Trait for interfaces which can send parameters in the background
+pub trait DmaInterface: Interface {
+    /// Send the command and start sending `buffer` in the background
+    /// The interface keeps the buffer until the transfer is done or aborted. If the transfer can't
+    /// be started the buffer is handed back with the error.
+    fn start_send_parameters(&mut self, command: u8, buffer: &'static mut [u8]) -> Result<(), TransferError<Self::Error>>;
+    /// Poll the background transfer
+    /// Returns `Poll::Ready` with the buffer once all the data is sent, otherwise the waker of `cx`
+    /// has to be woken when the transfer makes progress. Without a transfer in progress it returns
+    /// `Poll::Pending`.
+    fn poll_transfer(&mut self, cx: &mut Context<'_>) -> Poll<Result<&'static mut [u8], TransferError<Self::Error>>>;
+    /// Abort the background transfer and hand the buffer back
+    /// Called by [`Transfer::abort`] and when a Transfer handle is dropped before the transfer is
+    /// done. Returns `None` without a transfer in progress.
+    fn abort_transfer(&mut self) -> Option<&'static mut [u8]>;
+}
+
+/// A failed background transfer and its buffer
+#[derive(Debug)]
+pub struct TransferError<E> {
+    pub buffer: &'static mut [u8],
+    pub error: E,
+}
+
+/// Result of a background transfer, the buffer or the error with the buffer
+type TransferResult<E> = Result<&'static mut [u8], TransferError<E>>;
+
+/// Handle of a background transfer
+///
+/// The handle borrows the Controller, so the interface can not be used before the transfer is done
+/// or the handle is dropped. Dropping an unfinished transfer aborts it and the buffer is lost, use
+/// [`abort`](Transfer::abort) to get it back.
+#[must_use = "dropping a Transfer aborts it"]
+pub struct Transfer<'c, Iface>
+where
+    Iface: DmaInterface,
+{
+    iface: &'c mut Iface,
+    /// The result, once the interface reported it
+    result: Option<TransferResult<Iface::Error>>,
+    done: bool,
+}
+
+impl<'c, Iface> Transfer<'c, Iface>
+where
+    Iface: DmaInterface,
+{
+    /// Check whether the transfer is done, without blocking
+    pub fn is_done(&mut self) -> bool {
+        self.poll_interface(&mut Context::from_waker(Waker::noop()));
+        self.done || self.result.is_some()
+    }
+    /// Block until the transfer is done and take the buffer back
+    pub fn wait(mut self) -> TransferResult<Iface::Error> {
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(r) = self.poll_transfer(&mut cx) {
+                return r;
+            }
+        }
+    }
+
+    /// Abort the transfer and take the buffer back
+    /// Returns the buffer of a finished transfer as well, failed or not.
+    pub fn abort(mut self) -> Option<&'static mut [u8]> {
+        self.done = true;
+        match self.result.take() {
+            Some(Ok(buffer)) | Some(Err(TransferError { buffer, .. })) => Some(buffer),
+            None => self.iface.abort_transfer(),
+        }
+    }
+
+    fn poll_interface(&mut self, cx: &mut Context<'_>) {
+        if !self.done && self.result.is_none() {
+            if let Poll::Ready(r) = self.iface.poll_transfer(cx) {
+                self.result = Some(r);
+            }
+        }
+    }
+
+    fn poll_transfer(&mut self, cx: &mut Context<'_>) -> Poll<TransferResult<Iface::Error>> {
+        assert!(!self.done, "Transfer polled after completion");
+        self.poll_interface(cx);
+        match self.result.take() {
+            Some(r) => {
+                self.done = true;
+                Poll::Ready(r)
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+// The transfer is never pinned structurally, the error type doesn't have to be Unpin
+impl<'c, Iface> Unpin for Transfer<'c, Iface>
+where
+    Iface: DmaInterface,
+{
+}
+
+impl<'c, Iface> Future for Transfer<'c, Iface>
+where
+    Iface: DmaInterface,
+{
+    type Output = TransferResult<Iface::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.get_mut().poll_transfer(cx)
+    }
+}
+
+impl<'c, Iface> Drop for Transfer<'c, Iface>
+where
+    Iface: DmaInterface,
+{
+    fn drop(&mut self) {
+        if !self.done && self.result.is_none() {
+            self.iface.abort_transfer();
+        }
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: DmaInterface,
+{
+    fn start_send_parameters(&mut self, command: u8, buffer: &'static mut [u8]) -> Result<Transfer<'_, Iface>, TransferError<Iface::Error>> {
+        self.iface.start_send_parameters(command, buffer)?;
+        Ok(Transfer { iface: &mut self.iface, result: None, done: false })
+    }
+
+    /// Start a Memory Write (2Ch) in the background, see [`memory_write`](Controller::memory_write)
+    pub fn start_memory_write(&mut self, buffer: &'static mut [u8]) -> Result<Transfer<'_, Iface>, TransferError<Iface::Error>> {
+        self.start_send_parameters(0x2C, buffer)
+    }
+    /// Start a Write Memory Continue (3Ch) in the background, see [`write_memory_continue`](Controller::write_memory_continue)
+    pub fn start_write_memory_continue(&mut self, buffer: &'static mut [u8]) -> Result<Transfer<'_, Iface>, TransferError<Iface::Error>> {
+        self.start_send_parameters(0x3C, buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use std::boxed::Box;
+    use std::vec;
+
+    use super::DmaInterface;
+    use crate::mock::{MockInterface, Transaction};
+    use crate::Controller;
+
+    fn buffer(fill: u8) -> &'static mut [u8] {
+        Box::leak(vec![fill; 6].into_boxed_slice())
+    }
+
+    #[test]
+    fn buffers_are_handed_back() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let (front, back) = (buffer(1), buffer(2));
+        let front_ptr = front.as_ptr();
+
+        let mut transfer = lcd.start_memory_write(front).unwrap();
+        assert!(transfer.is_done());
+        let front = transfer.wait().unwrap();
+        assert_eq!(front.as_ptr(), front_ptr);
+
+        let transfer = lcd.start_write_memory_continue(back).unwrap();
+        let mut transfer = pin!(transfer);
+        let back = match transfer.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(r) => r.unwrap(),
+            Poll::Pending => panic!("the mock transfer is done right away"),
+        };
+        assert_eq!(back, [2; 6]);
+
+        mock.expect_parameters(0x2C, &[1; 6]).expect_parameters(0x3C, &[2; 6]).done();
+    }
+
+    #[test]
+    fn dropping_a_transfer_aborts_it() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        drop(lcd.start_memory_write(buffer(3)).unwrap());
+        assert_eq!(mock.transcript(), [Transaction::Write(0x2C, vec![3; 6])]);
+        // The aborted transfer left no buffer behind
+        assert!(lcd.start_memory_write(buffer(4)).unwrap().wait().is_ok());
+    }
+
+    #[test]
+    fn aborting_a_transfer_hands_the_buffer_back() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let front = buffer(5);
+        let front_ptr = front.as_ptr();
+        let front = lcd.start_memory_write(front).unwrap().abort().unwrap();
+        assert_eq!(front.as_ptr(), front_ptr);
+
+        // Also once the interface reported the transfer done
+        let mut transfer = lcd.start_memory_write(front).unwrap();
+        assert!(transfer.is_done());
+        assert_eq!(transfer.abort().unwrap().as_ptr(), front_ptr);
+        mock.expect_parameters(0x2C, &[5; 6]).expect_parameters(0x2C, &[5; 6]).done();
+    }
+
+    #[test]
+    fn polling_without_transfer_is_pending() {
+        let mut mock = MockInterface::new();
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(mock.poll_transfer(&mut cx).is_pending());
+        assert!(mock.abort_transfer().is_none());
+    }
+}
@@ -0,0 +1,452 @@
+//! Software model of the ili9341 for host-side tests
+//!
+//! The Emulator implements the Interface trait on top of a model of the 240x320 18-bit frame
+//! memory (GRAM) and of the registers, so drawing code can be tested without a panel. Clones share
+//! the state, a test hands one clone to the Controller and inspects the frame memory through
+//! another one.
+//!
+//! Modelled:
+//! - Column/Page Address Set (2Ah/2Bh) windows and the address counter, including the MADCTL
+//!   (36h) MY, MX and MV bits
+//! - Memory Write/Write Memory Continue (2Ch/3Ch) in the 16-bit and 18-bit pixel formats (3Ah)
+//! - Memory Read/Read Memory Continue (2Eh/3Eh), which always return 3 bytes per pixel
+//! - The display status reads (09h - 0Fh) and the other registers, which read back what was
+//!   written with the matching write command, directly or through the index read (D9h)
+//!
+//! Only the frame memory is modelled, not the image on the panel: scrolling, partial mode,
+//! inversion, idle mode and the BGR bit don't change the frame memory and are only reported by the
+//! status reads. In the 16-bit format red and blue are expanded to 6 bits by repeating their
+//! MSB, the Color Set (2Dh) LUT is ignored.
+//!
+//! ```
+//! use ili9341_cmd::Controller;
+//! use ili9341_cmd::emulator::Emulator;
+//! use ili9341_cmd::pixel_format::McuInterfaceFormat;
+//!
+//! let emulator = Emulator::new();
+//! let mut lcd = Controller::new(emulator.clone());
+//! lcd.pixel_format_set(|w| w.mcu_interface_format(McuInterfaceFormat::N16Bits)).unwrap();
+//! lcd.column_address_set(|w| w.sc(10).ec(11)).unwrap();
+//! lcd.page_address_set(|w| w.sp(20).ep(20)).unwrap();
+//! lcd.memory_write(&[0xF8, 0x00, 0x07, 0xE0]).unwrap();
+//!
+//! assert_eq!(emulator.pixel(10, 20), 0x3F000);
+//! assert_eq!(emulator.pixel(11, 20), 0x00FC0);
+//! let format = lcd.read_display_pixel_format().unwrap();
+//! assert_eq!(format.read().mcu_interface_format().map(|f| f as u8), Ok(0b101));
+//! ```
+
+extern crate std;
+
+use core::convert::Infallible;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::vec;
+use std::vec::Vec;
+
+use crate::madctl::Madctl;
+use crate::{bytes_to_word18, word18_to_bytes, Interface};
+
+/// Number of frame memory columns
+pub const WIDTH: usize = 240;
+/// Number of frame memory rows (pages)
+pub const HEIGHT: usize = 320;
+
+struct State {
+    /// Frame memory, one 18-bit word per pixel, row by row
+    gram: Vec<u32>,
+    /// Last parameters written with each command
+    regs: HashMap<u8, Vec<u8>>,
+    sleep: bool,
+    partial: bool,
+    idle: bool,
+    inversion: bool,
+    display_on: bool,
+    tearing_effect: bool,
+    /// Column window SC, EC
+    columns: (u16, u16),
+    /// Page window SP, EP
+    pages: (u16, u16),
+    /// Address counter column, page
+    cursor: (u16, u16),
+    /// Bytes of the incomplete pixel of a memory write
+    pending: Vec<u8>,
+    /// Bytes of the current pixel of a memory read which were already read
+    read_offset: usize,
+    /// Parameter selected by the index read command (D9h)
+    read_index: usize,
+}
+
+impl State {
+    fn new() -> State {
+        let mut state = State {
+            gram: vec![0; WIDTH * HEIGHT],
+            regs: HashMap::new(),
+            sleep: true,
+            partial: false,
+            idle: false,
+            inversion: false,
+            display_on: false,
+            tearing_effect: false,
+            columns: (0, 0),
+            pages: (0, 0),
+            cursor: (0, 0),
+            pending: Vec::new(),
+            read_offset: 0,
+            read_index: 0,
+        };
+        state.reset();
+        state
+    }
+
+    /// Software reset, the frame memory is kept
+    fn reset(&mut self) {
+        self.regs.clear();
+        self.regs.insert(0x26, vec![0x01]);
+        self.regs.insert(0x36, vec![0x00]);
+        self.regs.insert(0x3A, vec![0x66]);
+        self.regs.insert(0xD3, vec![0x00, 0x93, 0x41]);
+        self.sleep = true;
+        self.partial = false;
+        self.idle = false;
+        self.inversion = false;
+        self.display_on = false;
+        self.tearing_effect = false;
+        self.columns = (0, WIDTH as u16 - 1);
+        self.pages = (0, HEIGHT as u16 - 1);
+        self.cursor = (0, 0);
+        self.pending.clear();
+        self.read_offset = 0;
+        self.read_index = 0;
+    }
+
+    fn reg(&self, command: u8) -> u8 {
+        self.regs.get(&command).and_then(|r| r.first()).copied().unwrap_or(0)
+    }
+    fn madctl(&self) -> u8 {
+        self.reg(0x36)
+    }
+    /// Number of the selected gamma curve (26h)
+    fn gamma_curve(&self) -> u8 {
+        (self.reg(0x26).trailing_zeros() as u8) & 0x07
+    }
+
+    /// Frame memory index of the address counter
+    ///
+    /// The address counter is in the MADCTL coordinates: MX and MY mirror the column and page
+    /// address, MV exchanges them.
+    fn index(&self) -> Option<usize> {
+        let Madctl { my, mx, mv, .. } = Madctl::from(self.madctl());
+        let (width, height) = if mv { (HEIGHT, WIDTH) } else { (WIDTH, HEIGHT) };
+        let (col, page) = (self.cursor.0 as usize, self.cursor.1 as usize);
+        if col >= width || page >= height {
+            return None;
+        }
+        let col = if mx { width - 1 - col } else { col };
+        let page = if my { height - 1 - page } else { page };
+        let (x, y) = if mv { (page, col) } else { (col, page) };
+        Some(y * WIDTH + x)
+    }
+    fn advance(&mut self) {
+        let (col, page) = &mut self.cursor;
+        if *col < self.columns.1 {
+            *col += 1;
+        } else {
+            *col = self.columns.0;
+            *page = if *page < self.pages.1 { *page + 1 } else { self.pages.0 };
+        }
+    }
+
+    fn write_memory<T: IntoIterator<Item = u8>>(&mut self, data: T) {
+        let bytes_per_pixel = if self.reg(0x3A) & 0x07 == 0b101 { 2 } else { 3 };
+        for d in data {
+            self.pending.push(d);
+            if self.pending.len() < bytes_per_pixel {
+                continue;
+            }
+            let word = match self.pending[..] {
+                [hi, lo] => {
+                    let (r, g, b) = (hi >> 3, ((hi & 0x07) << 3) | (lo >> 5), lo & 0x1F);
+                    ((((r << 1) | (r >> 4)) as u32) << 12) | ((g as u32) << 6) | (((b << 1) | (b >> 4)) as u32)
+                }
+                [r, g, b] => bytes_to_word18([r, g, b]),
+                _ => unreachable!(),
+            };
+            self.pending.clear();
+            if let Some(i) = self.index() {
+                self.gram[i] = word;
+            }
+            self.advance();
+        }
+    }
+    fn read_memory<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, data: T) {
+        for d in data {
+            let word = self.index().map(|i| self.gram[i]).unwrap_or(0);
+            *d = word18_to_bytes(word)[self.read_offset];
+            self.read_offset += 1;
+            if self.read_offset == 3 {
+                self.read_offset = 0;
+                self.advance();
+            }
+        }
+    }
+
+    fn write_register(&mut self, command: u8, data: Vec<u8>) {
+        let word = |i: usize| data.get(i..i + 2).map(|w| u16::from_be_bytes([w[0], w[1]]));
+        match command {
+            0x01 => return self.reset(),
+            0x10 => self.sleep = true,
+            0x11 => self.sleep = false,
+            0x12 => self.partial = true,
+            0x13 => self.partial = false,
+            0x20 => self.inversion = false,
+            0x21 => self.inversion = true,
+            0x28 => self.display_on = false,
+            0x29 => self.display_on = true,
+            0x2A => {
+                if let (Some(sc), Some(ec)) = (word(0), word(2)) {
+                    self.columns = (sc, ec);
+                }
+            }
+            0x2B => {
+                if let (Some(sp), Some(ep)) = (word(0), word(2)) {
+                    self.pages = (sp, ep);
+                }
+            }
+            0x34 => self.tearing_effect = false,
+            0x35 => self.tearing_effect = true,
+            0x38 => self.idle = false,
+            0x39 => self.idle = true,
+            // Index read, not a register
+            0xD9 => {
+                self.read_index = data.first().map_or(0, |&i| (i & 0x0F) as usize);
+                return;
+            }
+            _ => (),
+        }
+        if !data.is_empty() {
+            self.regs.insert(command, data);
+        }
+    }
+    fn read_register(&self, command: u8) -> Vec<u8> {
+        let madctl = self.madctl();
+        let colmod = self.reg(0x3A);
+        let gamma = self.gamma_curve();
+        let booster = !self.sleep;
+        match command {
+            0x09 => vec![
+                ((booster as u8) << 7) | ((madctl >> 1) & 0x7E),
+                (colmod & 0x70) | ((self.idle as u8) << 3) | ((self.partial as u8) << 2)
+                    | ((!self.sleep as u8) << 1) | (!self.partial as u8),
+                ((self.inversion as u8) << 5) | ((self.display_on as u8) << 2) | ((self.tearing_effect as u8) << 1)
+                    | (gamma >> 2),
+                ((gamma & 0x03) << 6) | ((self.reg(0x35) & 0x01) << 5),
+            ],
+            0x0A => vec![
+                ((booster as u8) << 7) | ((self.idle as u8) << 6) | ((self.partial as u8) << 5)
+                    | ((!self.sleep as u8) << 4) | ((!self.partial as u8) << 3) | ((self.display_on as u8) << 2),
+            ],
+            0x0B => vec![madctl & 0xFC],
+            0x0C => vec![colmod & 0x77],
+            0x0D => vec![((self.inversion as u8) << 5) | gamma],
+            0x0E => vec![((self.tearing_effect as u8) << 7) | ((self.reg(0x35) & 0x01) << 6)],
+            0x52 => vec![self.reg(0x51)],
+            0x54 => vec![self.reg(0x53)],
+            0x56 => vec![self.reg(0x55)],
+            0x5F => vec![self.reg(0x5E)],
+            _ => self.regs.get(&command).cloned().unwrap_or_default(),
+        }
+    }
+}
+
+/// Emulated ili9341, see the [module documentation](self)
+#[derive(Clone)]
+pub struct Emulator {
+    state: Rc<RefCell<State>>,
+}
+
+impl Default for Emulator {
+    fn default() -> Self {
+        Emulator::new()
+    }
+}
+
+impl Emulator {
+    /// Create an emulator in the reset state with the frame memory cleared to black
+    pub fn new() -> Emulator {
+        Emulator { state: Rc::new(RefCell::new(State::new())) }
+    }
+
+    /// Frame memory word D[17:0] (R[17:12], G[11:6], B[5:0]) at column `x` and page `y`
+    pub fn pixel(&self, x: usize, y: usize) -> u32 {
+        self.state.borrow().gram[y * WIDTH + x]
+    }
+    /// Copy of the frame memory, one 18-bit word per pixel, row by row
+    pub fn gram(&self) -> Vec<u32> {
+        self.state.borrow().gram.clone()
+    }
+    /// Frame memory as 8-bit RGB, row by row, e.g. for comparing with golden images
+    pub fn to_rgb888(&self) -> Vec<u8> {
+        let expand = |c: u8| (c << 2) | (c >> 4);
+        self.state.borrow().gram.iter().flat_map(|&w| word18_to_bytes(w).map(|c| expand(c >> 2))).collect()
+    }
+    /// Fill the frame memory with a word D[17:0]
+    pub fn fill(&self, word: u32) {
+        self.state.borrow_mut().gram.fill(word & 0x3FFFF);
+    }
+    /// Hardware reset, the frame memory is kept
+    pub fn reset(&self) {
+        self.state.borrow_mut().reset();
+    }
+}
+
+impl Interface for Emulator {
+    type Error = Infallible;
+
+    fn command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, None)
+    }
+    fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, data.iter().copied())
+    }
+    fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        self.read_parameters_iter(command, data.iter_mut())
+    }
+
+    fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        let mut state = self.state.borrow_mut();
+        state.pending.clear();
+        state.read_offset = 0;
+        match command {
+            0x2C => {
+                state.cursor = (state.columns.0, state.pages.0);
+                state.write_memory(data);
+            }
+            0x3C => state.write_memory(data),
+            _ => state.write_register(command, data.into_iter().collect()),
+        }
+        Ok(())
+    }
+    fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        let mut state = self.state.borrow_mut();
+        state.pending.clear();
+        match command {
+            0x2E => {
+                state.cursor = (state.columns.0, state.pages.0);
+                state.read_offset = 0;
+                state.read_memory(data);
+            }
+            0x3E => state.read_memory(data),
+            _ => {
+                let mut r = state.read_register(command).into_iter();
+                for d in data {
+                    *d = r.next().unwrap_or(0);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn read_indexed_parameter(&mut self, command: u8) -> Result<u8, Self::Error> {
+        let state = self.state.borrow();
+        // Index 0 is the dummy parameter
+        let r = state.read_register(command);
+        Ok(state.read_index.checked_sub(1).and_then(|i| r.get(i)).copied().unwrap_or(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pixel_format::McuInterfaceFormat;
+    use crate::read_display_image_format::Inversion;
+    use crate::Controller;
+
+    /// Write the word `0x15555` to column 1, page 2 of the window with `madctl`, return where it landed
+    fn write_with_madctl(madctl: u8) -> (usize, usize) {
+        let emulator = Emulator::new();
+        let mut lcd = Controller::new(emulator.clone());
+        lcd.send_parameters(0x36, &[madctl]).unwrap();
+        lcd.column_address_set(|w| w.sc(1).ec(1)).unwrap();
+        lcd.page_address_set(|w| w.sp(2).ep(2)).unwrap();
+        lcd.memory_write_u32(&[0x15555]).unwrap();
+        let i = emulator.gram().iter().position(|&w| w == 0x15555).unwrap();
+        (i % WIDTH, i / WIDTH)
+    }
+
+    #[test]
+    fn madctl_addressing() {
+        assert_eq!(write_with_madctl(0x00), (1, 2));
+        // MX mirrors the columns
+        assert_eq!(write_with_madctl(0x40), (238, 2));
+        // MY mirrors the pages
+        assert_eq!(write_with_madctl(0x80), (1, 317));
+        // MV exchanges columns and pages
+        assert_eq!(write_with_madctl(0x20), (2, 1));
+        // All three: 320 columns and 240 pages, both mirrored, then exchanged
+        assert_eq!(write_with_madctl(0xE0), (237, 318));
+    }
+
+    #[test]
+    fn write_16_bit_pixels_wrapping_in_the_window() {
+        let emulator = Emulator::new();
+        let mut lcd = Controller::new(emulator.clone());
+        lcd.pixel_format_set(|w| w.mcu_interface_format(McuInterfaceFormat::N16Bits)).unwrap();
+        lcd.column_address_set(|w| w.sc(4).ec(5)).unwrap();
+        lcd.page_address_set(|w| w.sp(7).ep(8)).unwrap();
+        lcd.memory_write_u16(&[0xF800, 0x07E0]).unwrap();
+        lcd.write_memory_continue_u16(&[0x001F, 0x8410]).unwrap();
+        assert_eq!(emulator.pixel(4, 7), 0x3F000);
+        assert_eq!(emulator.pixel(5, 7), 0x00FC0);
+        assert_eq!(emulator.pixel(4, 8), 0x0003F);
+        // 10000b, 100000b, 10000b: red and blue repeat their MSB
+        assert_eq!(emulator.pixel(5, 8), 0x21821);
+    }
+
+    #[test]
+    fn write_18_bit_pixels() {
+        let emulator = Emulator::new();
+        let mut lcd = Controller::new(emulator.clone());
+        lcd.column_address_set(|w| w.sc(0).ec(239)).unwrap();
+        lcd.page_address_set(|w| w.sp(0).ep(0)).unwrap();
+        lcd.memory_write(&[0xFC, 0x00, 0x04]).unwrap();
+        lcd.write_memory_continue_u32(&[0x2A555]).unwrap();
+        assert_eq!(emulator.pixel(0, 0), 0x3F001);
+        assert_eq!(emulator.pixel(1, 0), 0x2A555);
+    }
+
+    #[test]
+    fn memory_read_and_continue() {
+        let emulator = Emulator::new();
+        let mut lcd = Controller::new(emulator.clone());
+        lcd.column_address_set(|w| w.sc(10).ec(29)).unwrap();
+        lcd.page_address_set(|w| w.sp(3).ep(3)).unwrap();
+        let words: Vec<u32> = (0..20).map(|i| 0x1000 * i + i).collect();
+        lcd.memory_write_u32(&words).unwrap();
+
+        let mut bytes = [0u8; 6];
+        lcd.memory_read(&mut bytes).unwrap();
+        assert_eq!(bytes, [0x00, 0x00, 0x00, 0x04, 0x00, 0x04]);
+        let mut next = [0u32; 1];
+        lcd.read_memory_continue_u32(&mut next).unwrap();
+        assert_eq!(next, [words[2]]);
+
+        // 60 bytes, read as 2Eh and resumed with 3Eh
+        let mut all = [0u32; 20];
+        lcd.memory_read_u32(&mut all).unwrap();
+        assert_eq!(all[..], words[..]);
+    }
+
+    #[test]
+    fn image_format_reports_inversion() {
+        let mut lcd = Controller::new(Emulator::new());
+        lcd.gamma_set(|w| w.curve_selected(crate::gamma::CurveSelected::GammaCurve1G2o2)).unwrap();
+        lcd.display_inversion_on().unwrap();
+        let format = lcd.read_display_image_format().unwrap();
+        assert_eq!(format.read().inversion(), Ok(Inversion::InversionOn));
+        assert_eq!(format.into_bytes(), [0x20]);
+        lcd.display_inversion_off().unwrap();
+        assert_eq!(lcd.read_display_image_format().unwrap().read().inversion(), Ok(Inversion::InversionOff));
+    }
+}
@@ -0,0 +1,87 @@
+//! Reading the extended command registers
+//!
+//! The extended registers answer with a dummy parameter in front of the data. Many modules with
+//! a serial interface only return the data through the index read command (D9h): D9h selects
+//! the parameter (10h + index, the dummy parameter being index 0), the next read of the register
+//! returns that single parameter without dummy cycle.
+
+use embedded_hal::delay::DelayNs;
+
+use crate::shadow::RegisterCache;
+use crate::{Controller, Interface};
+
+/// How the Controller reads the extended command registers
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ExtendedRead {
+    /// Read all the parameters with one read command
+    Direct,
+    /// Read every parameter separately through the index read command (D9h)
+    Indexed,
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Read the extended registers with `mode`, the default is `ExtendedRead::Direct`
+    pub fn with_extended_read(mut self, mode: ExtendedRead) -> Self {
+        self.extended_read = mode;
+        self
+    }
+
+    pub(crate) fn read_extended(&mut self, command: u8, data: &mut [u8]) -> Result<(), Iface::Error> {
+        match self.extended_read {
+            ExtendedRead::Direct => self.read_parameters(command, data),
+            ExtendedRead::Indexed => {
+                for (i, d) in data.iter_mut().enumerate() {
+                    self.send_parameters(0xD9, &[0x10 | (i as u8 + 1)])?;
+                    *d = self.iface.read_indexed_parameter(command)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInterface;
+
+    #[test]
+    fn direct_read() {
+        let mock = MockInterface::new();
+        mock.push_response(0xD3, &[0x00, 0x93, 0x41]);
+        let mut lcd = Controller::new(mock.clone());
+        assert_eq!(lcd.read_id4().unwrap().into_bytes(), [0x00, 0x93, 0x41]);
+        mock.expect_read(0xD3, 3).done();
+    }
+
+    #[test]
+    fn indexed_read() {
+        let mock = MockInterface::new();
+        mock.push_response(0xD3, &[0x00]).push_response(0xD3, &[0x93]).push_response(0xD3, &[0x41]);
+        mock.push_response(0xC5, &[0x31]).push_response(0xC5, &[0x3C]);
+        let mut lcd = Controller::new(mock.clone()).with_extended_read(ExtendedRead::Indexed);
+        assert_eq!(lcd.read_id4().unwrap().into_bytes(), [0x00, 0x93, 0x41]);
+        assert_eq!(lcd.read_vcom_control1().unwrap().into_bytes(), [0x31, 0x3C]);
+        // The standard registers are still read directly
+        mock.push_response(0x0A, &[0x9C]);
+        lcd.read_display_power_mode().unwrap();
+
+        mock.expect_parameters(0xD9, &[0x11])
+            .expect_read(0xD3, 1)
+            .expect_parameters(0xD9, &[0x12])
+            .expect_read(0xD3, 1)
+            .expect_parameters(0xD9, &[0x13])
+            .expect_read(0xD3, 1)
+            .expect_parameters(0xD9, &[0x11])
+            .expect_read(0xC5, 1)
+            .expect_parameters(0xD9, &[0x12])
+            .expect_read(0xC5, 1)
+            .expect_read(0x0A, 1)
+            .done();
+    }
+}
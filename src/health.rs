@@ -0,0 +1,274 @@
+//! Health check of a running display
+//!
+//! An ESD event can reset the display without the application noticing: it goes to sleep with
+//! the display off and the registers at their defaults, the panel turns white. A [`HealthMonitor`]
+//! runs the initialization for a [`Config`] and then compares the state read back from the
+//! display with the expected one whenever [`check`](HealthMonitor::check) is called:
+//! - Read Display Power Mode (0Ah): booster on, sleep out, display on
+//! - Read Display Status (09h): MADCTL, pixel format and inversion of the `Config`
+//! - Read Display Self-Diagnostic Result (0Fh): unchanged since the baseline, the display
+//!   toggles D7/D6 on every sleep out whose register loading and functionality checks passed
+//!
+//! The self-diagnostic baseline is recorded by [`init`](HealthMonitor::init) or, for a display
+//! which is already set up, by [`from_current`](HealthMonitor::from_current). The check is
+//! skipped until there is one. An application which sends sleep in/out itself has to record it
+//! again with [`update_baseline`](HealthMonitor::update_baseline) after the sleep out.
+//!
+//! [`recover`](HealthMonitor::recover) runs the initialization again if a fault is found.
+
+use embedded_hal::delay::DelayNs;
+
+use crate::init::{Config, Error};
+use crate::madctl::Madctl;
+use crate::shadow::RegisterCache;
+use crate::timing::ResetPin;
+use crate::{Controller, Interface};
+
+/// Deviation of the display from the expected state
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Fault {
+    /// The display is in sleep mode
+    Sleep,
+    /// The display is off
+    DisplayOff,
+    /// The booster is off or has a fault
+    Booster,
+    /// MADCTL differs from the orientation of the `Config`
+    Madctl { expected: Madctl, actual: Madctl },
+    /// The interface pixel format (D22-D20 of the status) differs from the `Config`
+    PixelFormat { expected: u8, actual: u8 },
+    /// The inversion differs from the `Config`
+    Inversion { expected: bool },
+    /// The register loading or functionality detection bits (D7/D6) changed
+    SelfDiagnostic { expected: u8, actual: u8 },
+}
+
+/// Checks the display against the state set by the initialization, see the [module documentation](self)
+#[derive(Debug, Copy, Clone)]
+pub struct HealthMonitor {
+    config: Config,
+    /// Self-diagnostic result D7/D6 of the baseline
+    self_diagnostic: Option<u8>,
+}
+
+impl HealthMonitor {
+    /// Monitor without self-diagnostic baseline, see [`init`](HealthMonitor::init)
+    pub fn new(config: Config) -> Self {
+        HealthMonitor { config, self_diagnostic: None }
+    }
+
+    /// Monitor of a display which is already set up for `config`, e.g. by [`Controller::init`]
+    /// The current self-diagnostic result is the baseline.
+    pub fn from_current<Iface, Delay, Reset, Cache>(config: Config, lcd: &mut Controller<Iface, Delay, Reset, Cache>) -> Result<Self, Iface::Error>
+    where
+        Iface: Interface,
+        Delay: DelayNs,
+        Cache: RegisterCache,
+    {
+        let mut monitor = HealthMonitor::new(config);
+        monitor.update_baseline(lcd)?;
+        Ok(monitor)
+    }
+
+    /// Record the current self-diagnostic result as baseline
+    pub fn update_baseline<Iface, Delay, Reset, Cache>(&mut self, lcd: &mut Controller<Iface, Delay, Reset, Cache>) -> Result<(), Iface::Error>
+    where
+        Iface: Interface,
+        Delay: DelayNs,
+        Cache: RegisterCache,
+    {
+        let result = lcd.read_display_self_diagnostic_result()?;
+        self.self_diagnostic = Some(result.into_bytes()[0] & 0xC0);
+        Ok(())
+    }
+
+    /// Run the initialization (see [`Controller::init`]) and record the self-diagnostic result
+    pub fn init<Iface, Delay, Reset, Cache>(&mut self, lcd: &mut Controller<Iface, Delay, Reset, Cache>) -> Result<(), Error<Iface::Error, Reset::Error>>
+    where
+        Iface: Interface,
+        Delay: DelayNs,
+        Reset: ResetPin,
+        Cache: RegisterCache,
+    {
+        lcd.init(&self.config)?;
+        self.update_baseline(lcd).map_err(Error::Interface)
+    }
+
+    /// Compare the display with the expected state, `None` if it is healthy
+    /// The first deviation found is reported.
+    pub fn check<Iface, Delay, Reset, Cache>(&self, lcd: &mut Controller<Iface, Delay, Reset, Cache>) -> Result<Option<Fault>, Iface::Error>
+    where
+        Iface: Interface,
+        Delay: DelayNs,
+        Cache: RegisterCache,
+    {
+        let power_mode = lcd.read_display_power_mode()?.into_bytes()[0];
+        if power_mode & 0x10 == 0 {
+            return Ok(Some(Fault::Sleep));
+        }
+        if power_mode & 0x04 == 0 {
+            return Ok(Some(Fault::DisplayOff));
+        }
+        if power_mode & 0x80 == 0 {
+            return Ok(Some(Fault::Booster));
+        }
+
+        let status = lcd.read_display_status()?.into_bytes();
+        let expected = self.config.orientation.madctl(self.config.bgr);
+        // D30-D25 are MADCTL B7-B2
+        let actual = Madctl::from((status[0] & 0x7E) << 1);
+        if actual != expected {
+            return Ok(Some(Fault::Madctl { expected, actual }));
+        }
+        let expected = self.config.pixel_format as u8;
+        let actual = (status[1] >> 4) & 0x07;
+        if actual != expected {
+            return Ok(Some(Fault::PixelFormat { expected, actual }));
+        }
+        if (status[2] & 0x20 != 0) != self.config.inversion {
+            return Ok(Some(Fault::Inversion { expected: self.config.inversion }));
+        }
+
+        if let Some(expected) = self.self_diagnostic {
+            let actual = lcd.read_display_self_diagnostic_result()?.into_bytes()[0] & 0xC0;
+            if actual != expected {
+                return Ok(Some(Fault::SelfDiagnostic { expected, actual }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Check the display and run the initialization again if it isn't healthy
+    /// Returns the fault which was recovered from. The frame memory content can't be trusted after
+    /// such an event, the application should redraw it.
+    pub fn recover<Iface, Delay, Reset, Cache>(&mut self, lcd: &mut Controller<Iface, Delay, Reset, Cache>) -> Result<Option<Fault>, Error<Iface::Error, Reset::Error>>
+    where
+        Iface: Interface,
+        Delay: DelayNs,
+        Reset: ResetPin,
+        Cache: RegisterCache,
+    {
+        let fault = self.check(lcd).map_err(Error::Interface)?;
+        if fault.is_some() {
+            self.init(lcd)?;
+        }
+        Ok(fault)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInterface;
+
+    /// Booster on, sleep out, normal mode, display on
+    const POWER_MODE: u8 = 0x9C;
+    /// MADCTL 48h, 16 bits per pixel, no inversion
+    const STATUS: [u8; 4] = [0x24, 0x50, 0x00, 0x00];
+
+    /// Queue the responses of a check which stops after the reads given
+    fn respond(mock: &MockInterface, power_mode: u8, status: Option<[u8; 4]>, diagnostic: Option<u8>) {
+        mock.push_response(0x0A, &[power_mode]);
+        if let Some(status) = status {
+            mock.push_response(0x09, &status);
+        }
+        if let Some(d) = diagnostic {
+            mock.push_response(0x0F, &[d]);
+        }
+    }
+
+    /// Check with the responses given, all of them have to be read
+    fn check(monitor: &HealthMonitor, power_mode: u8, status: Option<[u8; 4]>, diagnostic: Option<u8>) -> Option<Fault> {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        respond(&mock, power_mode, status, diagnostic);
+        let fault = monitor.check(&mut lcd).unwrap();
+        mock.expect_read(0x0A, 1);
+        if status.is_some() {
+            mock.expect_read(0x09, 4);
+        }
+        if diagnostic.is_some() {
+            mock.expect_read(0x0F, 1);
+        }
+        mock.done();
+        fault
+    }
+
+    #[test]
+    fn self_diagnostic_is_skipped_without_baseline() {
+        let monitor = HealthMonitor::new(Config::default());
+        assert_eq!(check(&monitor, POWER_MODE, Some(STATUS), None), None);
+    }
+
+    #[test]
+    fn baseline_of_a_running_display() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        lcd.init(&Config::default()).unwrap();
+        mock.push_response(0x0F, &[0xC0]);
+        let mut monitor = HealthMonitor::from_current(Config::default(), &mut lcd).unwrap();
+        assert_eq!(check(&monitor, POWER_MODE, Some(STATUS), Some(0xC0)), None);
+        assert_eq!(check(&monitor, POWER_MODE, Some(STATUS), Some(0x40)), Some(Fault::SelfDiagnostic { expected: 0xC0, actual: 0x40 }));
+
+        // After a sleep in/out cycle of the application
+        mock.push_response(0x0F, &[0x00]);
+        monitor.update_baseline(&mut lcd).unwrap();
+        assert_eq!(check(&monitor, POWER_MODE, Some(STATUS), Some(0x00)), None);
+    }
+
+    #[test]
+    fn faults() {
+        let monitor = HealthMonitor::new(Config::default());
+        assert_eq!(check(&monitor, 0x08, None, None).unwrap(), Fault::Sleep);
+        assert_eq!(check(&monitor, 0x98, None, None).unwrap(), Fault::DisplayOff);
+        assert_eq!(check(&monitor, 0x1C, None, None).unwrap(), Fault::Booster);
+        assert_eq!(
+            check(&monitor, POWER_MODE, Some([0x14, 0x50, 0x00, 0x00]), None).unwrap(),
+            Fault::Madctl { expected: Madctl::from(0x48), actual: Madctl::from(0x28) }
+        );
+        assert_eq!(
+            check(&monitor, POWER_MODE, Some([0x24, 0x60, 0x00, 0x00]), None).unwrap(),
+            Fault::PixelFormat { expected: 0x05, actual: 0x06 }
+        );
+        assert_eq!(
+            check(&monitor, POWER_MODE, Some([0x24, 0x50, 0x20, 0x00]), None).unwrap(),
+            Fault::Inversion { expected: false }
+        );
+    }
+
+    /// The initialization of the default Config with the self-diagnostic read
+    fn expect_init(mock: &MockInterface) {
+        mock.expect_command(0x01)
+            .expect_parameters(0x3A, &[0x55])
+            .expect_parameters(0x36, &[0x48])
+            .expect_parameters(0x26, &[0x01])
+            .expect_command(0x20)
+            .expect_command(0x11)
+            .expect_command(0x29)
+            .expect_read(0x0F, 1);
+    }
+
+    #[test]
+    fn recover_runs_the_initialization_again() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let mut monitor = HealthMonitor::new(Config::default());
+        mock.push_response(0x0F, &[0xC0]);
+        monitor.init(&mut lcd).unwrap();
+        expect_init(&mock);
+
+        // Healthy, nothing to do
+        respond(&mock, POWER_MODE, Some(STATUS), Some(0xC0));
+        assert_eq!(monitor.recover(&mut lcd).unwrap(), None);
+        mock.expect_read(0x0A, 1).expect_read(0x09, 4).expect_read(0x0F, 1);
+
+        // Reset by ESD: sleeping, the self-diagnostic result is recorded again
+        respond(&mock, 0x08, None, None);
+        mock.push_response(0x0F, &[0x40]);
+        assert_eq!(monitor.recover(&mut lcd).unwrap(), Some(Fault::Sleep));
+        mock.expect_read(0x0A, 1);
+        expect_init(&mock);
+        mock.done();
+        assert_eq!(monitor.self_diagnostic, Some(0x40));
+    }
+}
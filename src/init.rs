@@ -0,0 +1,200 @@
+//! Initialization sequence
+//!
+//! [`Controller::init`] brings the display from power-on or reset to showing the frame memory,
+//! configured by a [`Config`].
+
+use embedded_hal::delay::DelayNs;
+
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+use crate::frame_control_in_normal_mode::FrameControlInNormalMode;
+use crate::gamma::CurveSelected;
+use crate::orientation::Orientation;
+use crate::pixel_format::{McuInterfaceFormat, RgbInterfaceFormat};
+use crate::shadow::RegisterCache;
+use crate::timing::ResetPin;
+use crate::{Controller, Interface};
+
+/// An enumeration of initialization errors
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error<IfaceError, PinError> {
+    /// Error of the Interface
+    Interface(IfaceError),
+    /// Error while pulsing the reset pin
+    Pin(PinError),
+}
+
+/// Configuration of the initialization sequence
+#[derive(Debug, Copy, Clone)]
+pub struct Config {
+    pub orientation: Orientation,
+    /// Pixel format of the MCU interface, the RGB interface is set to the same format
+    pub pixel_format: McuInterfaceFormat,
+    /// The panel has a BGR color filter
+    pub bgr: bool,
+    /// Invert the display, most IPS panels need it
+    pub inversion: bool,
+    pub gamma: CurveSelected,
+    /// Frame rate in normal mode, `None` keeps the reset default (70 Hz)
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    pub frame_rate: Option<FrameControlInNormalMode>,
+}
+
+impl Default for Config {
+    /// Portrait, 16-bit pixels, BGR panel without inversion
+    fn default() -> Self {
+        Config {
+            orientation: Orientation::Portrait,
+            pixel_format: McuInterfaceFormat::N16Bits,
+            bgr: true,
+            inversion: false,
+            gamma: CurveSelected::GammaCurve1G2o2,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            frame_rate: None,
+        }
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Run the power-up sequence and turn the display on
+    ///
+    /// Reset, pixel format, memory access control, gamma curve, inversion, frame rate, sleep out
+    /// and display on. The reset is done with the reset pin if the Controller has one (see
+    /// [`hard_reset`](Controller::hard_reset)), otherwise with the software reset command.
+    /// The frame memory is not cleared.
+    ///
+    /// The 5 ms wait after the reset and the 120 ms between the reset and Sleep Out are done with
+    /// the delay source of the Controller, see [`with_delay`](Controller::with_delay). With
+    /// [`NoDelay`](crate::timing::NoDelay) they are skipped and the display can miss the
+    /// configuration, the application can't insert them between the commands of `init`. Give the
+    /// Controller a delay source before calling it.
+    pub fn init(&mut self, config: &Config) -> Result<(), Error<Iface::Error, Reset::Error>>
+    where
+        Reset: ResetPin,
+    {
+        if !self.pulse_reset().map_err(Error::Pin)? {
+            self.software_reset().map_err(Error::Interface)?;
+        }
+        self.configure(config).map_err(Error::Interface)
+    }
+
+    /// The sequence of `init` after the reset
+    fn configure(&mut self, config: &Config) -> Result<(), Iface::Error> {
+        self.set_pixel_format(config.pixel_format)?;
+        self.set_orientation(config.orientation, config.bgr)?;
+        self.gamma_set(|w| w.curve_selected(config.gamma))?;
+        if config.inversion {
+            self.display_inversion_on()?;
+        } else {
+            self.display_inversion_off()?;
+        }
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        if let Some(frame_rate) = config.frame_rate {
+            self.frame_control_in_normal_mode(|w| w.copy_from(frame_rate))?;
+        }
+        self.sleep_out()?;
+        self.display_on()
+    }
+
+    /// Pixel Format Set (3Ah) with the RGB interface set to the same format as the MCU interface
+    pub(crate) fn set_pixel_format(&mut self, format: McuInterfaceFormat) -> Result<(), Iface::Error> {
+        let rgb_interface_format = match format {
+            McuInterfaceFormat::N16Bits => RgbInterfaceFormat::N16Bits,
+            McuInterfaceFormat::N18Bits => RgbInterfaceFormat::N18Bits,
+        };
+        self.pixel_format_set(|w| w.rgb_interface_format(rgb_interface_format).mcu_interface_format(format))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::cell::RefCell;
+    use core::convert::Infallible;
+    use std::rc::Rc;
+    use std::vec::Vec;
+
+    use embedded_hal::digital::{ErrorType, OutputPin};
+
+    use super::*;
+    use crate::mock::MockInterface;
+
+    /// Reset pin recording its levels
+    #[derive(Clone, Default)]
+    struct Pin(Rc<RefCell<Vec<bool>>>);
+
+    impl ErrorType for Pin {
+        type Error = Infallible;
+    }
+
+    impl OutputPin for Pin {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.0.borrow_mut().push(false);
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.0.borrow_mut().push(true);
+            Ok(())
+        }
+    }
+
+    /// The transcript of `init` after the reset
+    fn expect_configuration(mock: &MockInterface, madctl: u8, inversion: u8) {
+        mock.expect_parameters(0x3A, &[0x55])
+            .expect_parameters(0x36, &[madctl])
+            .expect_parameters(0x26, &[0x01])
+            .expect_command(inversion);
+    }
+
+    #[test]
+    fn init_with_software_reset() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        lcd.init(&Config::default()).unwrap();
+        mock.expect_command(0x01);
+        expect_configuration(&mock, 0x48, 0x20);
+        mock.expect_command(0x11).expect_command(0x29).done();
+    }
+
+    #[test]
+    fn init_with_reset_pin() {
+        let mock = MockInterface::new();
+        let pin = Pin::default();
+        let mut lcd = Controller::new(mock.clone()).with_reset_pin(pin.clone());
+        let config = Config {
+            orientation: Orientation::Landscape,
+            pixel_format: McuInterfaceFormat::N16Bits,
+            bgr: false,
+            inversion: true,
+            ..Config::default()
+        };
+        lcd.init(&config).unwrap();
+        assert_eq!(*pin.0.borrow(), [false, true]);
+        expect_configuration(&mock, 0x20, 0x21);
+        mock.expect_command(0x11).expect_command(0x29).done();
+    }
+
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    #[test]
+    fn init_with_frame_rate() {
+        use crate::frame_control_in_normal_mode::DivisionRatio;
+
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let mut frame_rate = FrameControlInNormalMode::new();
+        frame_rate.write(|w| w.division_ratio(DivisionRatio::FoscDiv2).clock_per_line(0x10));
+        let config = Config { frame_rate: Some(frame_rate), ..Config::default() };
+        lcd.init(&config).unwrap();
+        mock.expect_command(0x01);
+        expect_configuration(&mock, 0x48, 0x20);
+        mock.expect_parameters(0xB1, &[0x01, 0x10])
+            .expect_command(0x11)
+            .expect_command(0x29)
+            .done();
+    }
+}
@@ -2,11 +2,42 @@
 
 //! Command interface for the ili9341 diplay driver
 
+#[macro_use]
+mod commands;
+
+use embedded_hal::delay::DelayNs;
+use shadow::{NoCache, RegisterCache};
+use timing::{NoDelay, NoResetPin};
+
+pub mod checked;
+pub mod detect;
+pub mod dma;
+pub mod health;
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod extended;
+pub mod init;
+pub mod madctl;
+pub mod orientation;
+pub mod pixel;
+pub mod power;
+pub mod registers;
+pub mod shadow;
+pub mod snapshot;
+pub mod timing;
+#[cfg(feature = "spi")]
+pub mod spi;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+#[cfg(feature = "async")]
+pub mod asynch;
+#[cfg(any(test, feature = "std"))]
+pub mod mock;
+#[cfg(any(test, feature = "std"))]
+pub mod emulator;
 
 /// Trait representing the interface to the hardware.
 /// Intended to abstract the various buses (SPI, MPU 8/9/16/18-bit) from the
 /// Controller code.
-/// TODO Add support for 16/32-bit words
 pub trait Interface {
     /// An enumeration of Interface errors
     type Error;
@@ -23,17 +54,159 @@ pub trait Interface {
     /// Read parameters into an iterator
     /// Note: the implementation needs to add a dummy read between command send and data receive
     fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error>;
+
+    /// Write 16-bit words (one bus cycle each on a 16-bit bus)
+    /// The default implementation sends every word as two bytes, MSB first.
+    fn send_parameters_u16(&mut self, command: u8, data: &[u16]) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, data.iter().flat_map(|w| w.to_be_bytes()))
+    }
+    /// Write 18-bit words D[17:0] (one bus cycle each on an 18-bit bus)
+    /// The default implementation sends every word as three bytes with 6 bits each, left aligned.
+    fn send_parameters_u32(&mut self, command: u8, data: &[u32]) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, data.iter().flat_map(|&w| word18_to_bytes(w)))
+    }
+    /// Read 16-bit words (one bus cycle each on a 16-bit bus)
+    /// The default implementation reads every word as two bytes, MSB first, through a stack buffer.
+    /// Frame memory reads longer than the buffer are resumed with Read Memory Continue (3Eh).
+    /// Other reads are clamped to the buffer.
+    fn read_parameters_u16(&mut self, command: u8, data: &mut [u16]) -> Result<(), Self::Error> {
+        let mut buf = [0u8; WORD_READ_CHUNK];
+        for (command, words) in word_chunks(command, data, 2) {
+            let bytes = &mut buf[..words.len() * 2];
+            self.read_parameters(command, bytes)?;
+            bytes_to_words16(bytes, words);
+        }
+        Ok(())
+    }
+    /// Read 18-bit words D[17:0] (one bus cycle each on an 18-bit bus)
+    /// The default implementation reads every word as three bytes with 6 bits each, left aligned,
+    /// through a stack buffer like `read_parameters_u16`.
+    fn read_parameters_u32(&mut self, command: u8, data: &mut [u32]) -> Result<(), Self::Error> {
+        let mut buf = [0u8; WORD_READ_CHUNK];
+        for (command, words) in word_chunks(command, data, 3) {
+            let bytes = &mut buf[..words.len() * 3];
+            self.read_parameters(command, bytes)?;
+            bytes_to_words18(bytes, words);
+        }
+        Ok(())
+    }
+
+    /// Read the single parameter selected with the index read command (D9h)
+    /// The display answers without dummy cycle. The default implementation reads one byte with
+    /// `read_parameters`, interfaces which skip a dummy cycle for `command` have to override it.
+    fn read_indexed_parameter(&mut self, command: u8) -> Result<u8, Self::Error> {
+        let mut data = [0];
+        self.read_parameters(command, &mut data)?;
+        Ok(data[0])
+    }
+}
+
+/// Split an 18-bit word D[17:0] into the three bytes of the 8-bit bus (D[7:2] each)
+#[inline(always)]
+pub(crate) fn word18_to_bytes(w: u32) -> [u8; 3] {
+    [(((w >> 12) & 0x3F) as u8) << 2, (((w >> 6) & 0x3F) as u8) << 2, ((w & 0x3F) as u8) << 2]
+}
+/// Join the three bytes of the 8-bit bus (D[7:2] each) into an 18-bit word D[17:0]
+#[inline(always)]
+pub(crate) fn bytes_to_word18(b: [u8; 3]) -> u32 {
+    (((b[0] >> 2) as u32) << 12) | (((b[1] >> 2) as u32) << 6) | ((b[2] >> 2) as u32)
+}
+/// Pack two 18-bit words into the three cycles of a 16-bit bus, 6 bits each on D[15:10] and D[7:2]
+/// R1 G1, B1 R2, G2 B2 like the 262K color write of the 16-bit 8080-I interface.
+#[inline(always)]
+pub(crate) fn words18_to_words16(a: u32, b: u32) -> [u16; 3] {
+    let [r1, g1, b1] = word18_to_bytes(a).map(u16::from);
+    let [r2, g2, b2] = word18_to_bytes(b).map(u16::from);
+    [(r1 << 8) | g1, (b1 << 8) | r2, (g2 << 8) | b2]
+}
+/// Undo [`words18_to_words16`]: join three cycles of a 16-bit bus into two 18-bit words
+#[cfg(feature = "parallel")]
+#[inline(always)]
+pub(crate) fn words16_to_words18(c: [u16; 3]) -> [u32; 2] {
+    let [r1, g1] = c[0].to_be_bytes();
+    let [b1, r2] = c[1].to_be_bytes();
+    let [g2, b2] = c[2].to_be_bytes();
+    [bytes_to_word18([r1, g1, b1]), bytes_to_word18([r2, g2, b2])]
+}
+
+/// Command used to resume a read which does not fit into one transfer
+///
+/// Only frame memory reads can be resumed, all the registers are read in one go.
+pub(crate) fn read_continuation(command: u8) -> Option<u8> {
+    match command {
+        0x2E | 0x3E => Some(0x3E),
+        _ => None,
+    }
+}
+
+/// Size of the stack buffer of the default word reads, a multiple of 2 and 3
+pub(crate) const WORD_READ_CHUNK: usize = 48;
+
+/// Split a word read of `bytes_per_word` bytes per word into chunks of [`WORD_READ_CHUNK`] bytes
+///
+/// Yields the command to read each chunk with: `command` for the first one, Read Memory Continue
+/// (3Eh) for the others. The chunks end on a word boundary, so frame memory reads are resumed at
+/// a pixel. A read which can't be resumed is clamped to one chunk, no register is that long, the
+/// words past it are left unchanged.
+pub(crate) fn word_chunks<W>(command: u8, data: &mut [W], bytes_per_word: usize) -> impl Iterator<Item = (u8, &mut [W])> {
+    let words = WORD_READ_CHUNK / bytes_per_word;
+    let continuation = read_continuation(command);
+    let len = if continuation.is_some() { data.len() } else { data.len().min(words) };
+    data[..len].chunks_mut(words)
+        .enumerate()
+        .map(move |(i, chunk)| (if i == 0 { command } else { continuation.unwrap_or(command) }, chunk))
+}
+
+/// Join pairs of bytes, MSB first, into 16-bit words
+pub(crate) fn bytes_to_words16(bytes: &[u8], words: &mut [u16]) {
+    for (w, b) in words.iter_mut().zip(bytes.chunks_exact(2)) {
+        *w = u16::from_be_bytes([b[0], b[1]]);
+    }
+}
+
+/// Join triples of bytes into 18-bit words, see [`bytes_to_word18`]
+pub(crate) fn bytes_to_words18(bytes: &[u8], words: &mut [u32]) {
+    for (w, b) in words.iter_mut().zip(bytes.chunks_exact(3)) {
+        *w = bytes_to_word18([b[0], b[1], b[2]]);
+    }
+}
+
+/// Enum field of a register in the `Display` output of the register types, which list the
+/// fields decoded by name (one per line with `{:#}`)
+/// A value without enum variant is shown as `Invalid(0xNN)`.
+pub(crate) struct Field<T>(Result<T, u8>);
+
+impl<T: core::fmt::Debug> core::fmt::Debug for Field<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            Ok(v) => v.fmt(f),
+            Err(v) => write!(f, "Invalid({:#04X})", v),
+        }
+    }
 }
 
 /// Controller implements the LCD command set and calls on the Interface trait
 /// to communicate with the LCD panel.
+///
+/// The optional delay source and reset pin are added with `with_delay` and `with_reset_pin`,
+/// see [`timing`], the shadow registers with `with_shadow_registers`, see [`shadow`].
 #[derive(Copy, Clone)]
-pub struct Controller<Iface>
+pub struct Controller<Iface, Delay = NoDelay, Reset = NoResetPin, Cache = NoCache>
 where
     Iface: Interface,
 {
     /// Custom interface
     iface: Iface,
+    delay: Delay,
+    /// RESX
+    reset: Reset,
+    /// A reset or sleep in/out command was sent, the next one has to wait 120 ms
+    sleep_guard: bool,
+    /// MV of the last MADCTL written, for the checked address setting
+    row_column_exchange: bool,
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    extended_read: extended::ExtendedRead,
+    cache: Cache,
 }
 
 impl<Iface: Interface> Controller<Iface>
@@ -41,1089 +214,103 @@ where
     Iface: Interface,
 {
     pub fn new(iface: Iface) -> Controller<Iface> {
-        Controller { iface }
+        Controller {
+            iface,
+            delay: NoDelay,
+            reset: NoResetPin,
+            sleep_guard: true,
+            row_column_exchange: false,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended_read: extended::ExtendedRead::Direct,
+            cache: NoCache,
+        }
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Release the interface, the delay source and the reset pin
+    /// The shadow registers, the extended read mode and the tracked MV are discarded.
+    pub fn release(self) -> (Iface, Delay, Reset) {
+        (self.iface, self.delay, self.reset)
+    }
+    /// The interface
+    pub fn iface(&self) -> &Iface {
+        &self.iface
+    }
+    /// The interface, e.g. to share the bus
+    /// Register writes sent directly to it aren't seen by the Controller, use
+    /// [`send_parameters`](Controller::send_parameters) for the commands the crate doesn't model.
+    pub fn iface_mut(&mut self) -> &mut Iface {
+        &mut self.iface
     }
 
+    /// Send a raw command without parameters
+    /// Software reset (01h) and sleep in/out (10h, 11h) wait and reset the tracked state like
+    /// [`software_reset`](Controller::software_reset), [`enter_sleep_mode`](Controller::enter_sleep_mode)
+    /// and [`sleep_out`](Controller::sleep_out).
     #[inline(always)]
-    fn command(&mut self, command: u8) -> Result<(), Iface::Error> {
-        self.iface.command(command)
+    pub fn command(&mut self, command: u8) -> Result<(), Iface::Error> {
+        match command {
+            0x01 | 0x10 | 0x11 => self.timed_command(command),
+            _ => self.iface.command(command),
+        }
     }
+    /// Send a raw command with parameters
+    /// The shadow registers are updated like by the register write methods.
     #[inline(always)]
-    fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Iface::Error> {
-        self.iface.send_parameters(command, data)
+    pub fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Iface::Error> {
+        self.iface.send_parameters(command, data)?;
+        if command == 0x36 {
+            self.row_column_exchange = matches!(data.first(), Some(b) if b & 0x20 != 0);
+        }
+        self.cache.update(command, data);
+        Ok(())
     }
+    /// Send a raw read command and read its parameters
     #[inline(always)]
-    fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Iface::Error> {
+    pub fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Iface::Error> {
         self.iface.read_parameters(command, data)
     }
+    /// Send a raw command with parameters from an iterator
+    /// Meant for the frame memory, the shadow registers and the tracked MV are not updated.
     #[inline(always)]
-    fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Iface::Error> {
+    pub fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Iface::Error> {
         self.iface.send_parameters_iter(command, data)
     }
+    /// Send a raw read command and read its parameters into an iterator
     #[inline(always)]
-    fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Iface::Error> {
+    pub fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Iface::Error> {
         self.iface.read_parameters_iter(command, data)
     }
-
-
-    // autogenerated functions start
-
-    /**
-        This command is an empty command; it does not have any effect on the display module. However it can be used to terminate
-        Frame Memory Write or Read as described in RAMWR (Memory Write) and RAMRD (Memory Read) Commands.
-    */
-    pub fn no_operation(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x00)
-    }
-    /**
-        When the Software Reset command is written, it causes a software reset. It resets the commands and parameters to their
-        S/W Reset default values. (See default tables in each command description.)
-        Note: The Frame Memory contents are unaffected by this command
-    */
-    pub fn software_reset(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x01)
-    }
-    /**
-        This read byte returns 24 bits display identification information.
-        The 1st parameter is dummy data.
-        The 2nd parameter (ID1 [7:0]): LCD module’s manufacturer ID.
-        The 3rd parameter (ID2 [7:0]): LCD module/driver version ID.
-        The 4th parameter (ID3 [7:0]): LCD module/driver ID.
-    */
-    pub fn read_display_identification_information(&mut self) -> Result<read_display_identification_information::DisplayIdentificationInformation, Iface::Error> {
-        let mut r = read_display_identification_information::DisplayIdentificationInformation::default();
-        self.read_parameters(0x04, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display Status
-    pub fn read_display_status(&mut self) -> Result<read_display_status::DisplayStatus, Iface::Error> {
-        let mut r = read_display_status::DisplayStatus::default();
-        self.read_parameters(0x09, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display Power Mode
-    pub fn read_display_power_mode(&mut self) -> Result<read_display_power_mode::DisplayPowerMode, Iface::Error> {
-        let mut r = read_display_power_mode::DisplayPowerMode::default();
-        self.read_parameters(0x0A, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display MADCTL
-    pub fn read_display_madctl(&mut self) -> Result<read_display_madctl::DisplayMadctl, Iface::Error> {
-        let mut r = read_display_madctl::DisplayMadctl::default();
-        self.read_parameters(0x0B, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display Pixel Format
-    pub fn read_display_pixel_format(&mut self) -> Result<read_display_pixel_format::DisplayPixelFormat, Iface::Error> {
-        let mut r = read_display_pixel_format::DisplayPixelFormat::default();
-        self.read_parameters(0x0C, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display Image Format
-    pub fn read_display_image_format(&mut self) -> Result<read_display_image_format::DisplayImageFormat, Iface::Error> {
-        let mut r = read_display_image_format::DisplayImageFormat::default();
-        self.read_parameters(0x0D, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display Signal Mode
-    pub fn read_display_signal_mode(&mut self) -> Result<read_display_signal_mode::DisplaySignalMode, Iface::Error> {
-        let mut r = read_display_signal_mode::DisplaySignalMode::default();
-        self.read_parameters(0x0E, &mut r.data)?;
-        Ok(r)
-    }
-    /// Read Display Self-Diagnostic Result
-    pub fn read_display_self_diagnostic_result(&mut self) -> Result<read_display_self_diagnostic_result::DisplaySelfDiagnosticResult, Iface::Error> {
-        let mut r = read_display_self_diagnostic_result::DisplaySelfDiagnosticResult::default();
-        self.read_parameters(0x0F, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This command causes the LCD module to enter the minimum power consumption mode. In this mode e.g. the DC/DC
-        converter is stopped, Internal oscillator is stopped, and panel scanning is stopped.
-        
-        MCU interface and memory are still working and the memory keeps its contents.
-    */
-    pub fn enter_sleep_mode(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x10)
-    }
-    /**
-        This command turns off sleep mode.
-        In this mode e.g. the DC/DC converter is enabled, Internal oscillator is started, and panel scanning is started.
-    */
-    pub fn sleep_out(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x11)
-    }
-    /**
-        This command turns on partial mode The partial mode window is described by the Partial Area command (30H). To leave
-        Partial mode, the Normal Display Mode On command (13H) should be written.
-    */
-    pub fn partial_mode_on(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x12)
-    }
-    /**
-        This command returns the display to normal mode.
-        Normal display mode on means Partial mode off.
-        Exit from NORON by the Partial mode On command (12h)
-    */
-    pub fn normal_display_mode_on(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x13)
-    }
-    /**
-        This command is used to recover from display inversion mode.
-        This command makes no change of the content of frame memory.
-        This command doesn’t change any other status.
-    */
-    pub fn display_inversion_off(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x20)
-    }
-    /**
-        This command is used to enter into display inversion mode.
-        This command makes no change of the content of frame memory. Every bit is inverted from the frame memory to the display.
-        This command doesn’t change any other status.
-        To exit Display inversion mode, the Display inversion OFF command (20h) should be written.
-    */
-    pub fn display_inversion_on(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x21)
-    }
-    /**
-        This command is used to select the desired Gamma curve for the current display. A maximum of 4 fixed gamma curves can
-        be selected. The curve is selected by setting the appropriate bit in the parameter.
-        Note: All other values are undefined.
-    */
-    pub fn gamma_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(gamma::GammaSetWrite) -> gamma::GammaSetWrite
-    {
-        self.send_parameters(0x26, &gamma::GammaSet::default().write(f).data)
-    }
-    /**
-        This command is used to enter into DISPLAY OFF mode. In this mode, the output from Frame Memory is disabled and blank
-        page inserted.
-        This command makes no change of contents of frame memory.
-        This command does not change any other status.
-        There will be no abnormal visible effect on the display.
-    */
-    pub fn display_off(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x28)
-    }
-    /**
-        This command is used to recover from DISPLAY OFF mode. Output from the Frame Memory is enabled.
-        This command makes no change of contents of frame memory.
-        This command does not change any other status
-    */
-    pub fn display_on(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x29)
-    }
-    /**
-        This command is used to define area of frame memory where MCU can access. This command makes no change on the
-        other driver status. The values of SC [15:0] and EC [15:0] are referred when RAMWR command comes. Each value
-        represents one column line in the Frame Memory.
-    */
-    pub fn column_address_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(column_address::ColumnAddressSetWrite) -> column_address::ColumnAddressSetWrite
-    {
-        self.send_parameters(0x2A, &column_address::ColumnAddressSet::default().write(f).data)
-    }
-    /**
-        This command is used to define area of frame memory where MCU can access. This command makes no change on the
-        other driver status. The values of SP [15:0] and EP [15:0] are referred when RAMWR command comes. Each value
-        represents one Page line in the Frame Memory.
-    */
-    pub fn page_address_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(page_address::PageAddressSetWrite) -> page_address::PageAddressSetWrite
-    {
-        self.send_parameters(0x2B, &page_address::PageAddressSet::default().write(f).data)
-    }
-    /**
-        This command is used to transfer data from MCU to frame memory. This command makes no change to the other driver
-        status. When this command is accepted, the column register and the page register are reset to the Start Column/Start
-        Page positions. The Start Column/Start Page positions are different in accordance with MADCTL setting.) Then D [17:0] is
-        stored in frame memory and the column register and the page register incremented. Sending any other command can stop
-        frame Write.
-    */
-    pub fn memory_write(&mut self, d: &[u8]) -> Result<(), Iface::Error> {
-        self.send_parameters(0x2C, d)
-    }
-    /**
-        This command is used to transfer data from MCU to frame memory. This command makes no change to the other driver
-        status. When this command is accepted, the column register and the page register are reset to the Start Column/Start
-        Page positions. The Start Column/Start Page positions are different in accordance with MADCTL setting.) Then D [17:0] is
-        stored in frame memory and the column register and the page register incremented. Sending any other command can stop
-        frame Write.
-    */
-    pub fn memory_write_iter<T: IntoIterator<Item = u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
-        self.send_parameters_iter(0x2C, d)
-    }
-    /**
-        This command is used to define the LUT for 16-bit to 18-bit color depth conversion.
-        128 bytes must be written to the LUT regardless of the color mode. Only the values in Section 7.4 are referred.
-        This command has no effect on other commands, parameter and contents of frame memory. Visible change takes effect
-        next time the frame memory is written to.
-    */
-    pub fn color_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(color::ColorSetWrite) -> color::ColorSetWrite
-    {
-        self.send_parameters(0x2D, &color::ColorSet::default().write(f).data)
-    }
-    /**
-        This command transfers image data from ILI9341’s frame memory to the host processor starting at the pixel location
-        specified by preceding set_column_address and set_page_address commands.
-        
-        If Memory Access control B5 = 0:
-        The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
-        frame memory at (SC, SP). The column register is then incremented and pixels read from the frame memory until the
-        column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
-        incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value or the host
-        processor sends another command.
-        
-        If Memory Access Control B5 = 1:
-        The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
-        frame memory at (SC, SP). The page register is then incremented and pixels read from the frame memory until the page
-        register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
-        Pixels are read from the frame memory until the column register equals the End Column (EC) value or the host processor
-        sends another command.
-    */
-    pub fn memory_read<'l>(&mut self, d: &'l mut [u8]) -> Result<&'l mut [u8], Iface::Error> {
-        self.read_parameters(0x2E, d)?;
-        Ok(d)
-    }
-    /**
-        This command transfers image data from ILI9341’s frame memory to the host processor starting at the pixel location
-        specified by preceding set_column_address and set_page_address commands.
-        
-        If Memory Access control B5 = 0:
-        The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
-        frame memory at (SC, SP). The column register is then incremented and pixels read from the frame memory until the
-        column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
-        incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value or the host
-        processor sends another command.
-        
-        If Memory Access Control B5 = 1:
-        The column and page registers are reset to the Start Column (SC) and Start Page (SP), respectively. Pixels are read from
-        frame memory at (SC, SP). The page register is then incremented and pixels read from the frame memory until the page
-        register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
-        Pixels are read from the frame memory until the column register equals the End Column (EC) value or the host processor
-        sends another command.
-    */
-    pub fn memory_read_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
-        self.read_parameters_iter(0x2E, d)
-    }
-    /**
-        This command defines the partial mode’s display area. There are 2 parameters associated with this command, the first
-        defines the Start Row (SR) and the second the End Row (ER), as illustrated in the figures below. SR and ER refer to the
-        Frame Memory Line Pointer.
-    */
-    pub fn partial_area<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(partial_area::PartialAreaWrite) -> partial_area::PartialAreaWrite
-    {
-        self.send_parameters(0x30, &partial_area::PartialArea::default().write(f).data)
-    }
-    /// Vertical Scrolling Definition
-    pub fn vertical_scrolling_definition<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(vertical_scrolling::VerticalScrollingDefinitionWrite) -> vertical_scrolling::VerticalScrollingDefinitionWrite
-    {
-        self.send_parameters(0x33, &vertical_scrolling::VerticalScrollingDefinition::default().write(f).data)
-    }
-    /// Tearing Effect Line OFF
-    pub fn tearing_effect_line_off(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x34)
-    }
-    /**
-        This command is used to turn ON the Tearing Effect output signal from the TE signal line. This output is not affected by
-        changing MADCTL bit B4. The Tearing Effect Line On has one parameter which describes the mode of the Tearing Effect
-        Output Line.
-    */
-    pub fn tearing_effect_line_on<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(tearing_effect_line_on::TearingEffectLineOnWrite) -> tearing_effect_line_on::TearingEffectLineOnWrite
-    {
-        self.send_parameters(0x35, &tearing_effect_line_on::TearingEffectLineOn::default().write(f).data)
-    }
-    /**
-        This command defines read/write scanning direction of frame memory.
-        This command makes no change on the other driver status.
-        Note: When BGR bit is changed, the new setting is active immediately without update the content in Frame Memory again.
-    */
-    pub fn memory_access_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(memory_access_control::MemoryAccessControlWrite) -> memory_access_control::MemoryAccessControlWrite
-    {
-        self.send_parameters(0x36, &memory_access_control::MemoryAccessControl::default().write(f).data)
-    }
-    /**
-        This command is used together with Vertical Scrolling Definition (33h). These two commands describe the scrolling area
-        and the scrolling mode. The Vertical Scrolling Start Address command has one parameter which describes the address of
-        the line in the Frame Memory that will be written as the first line after the last line of the Top Fixed Area
-        on the display.
-    */
-    pub fn vertical_scrolling_start_address<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(vertical_scrolling_start_address::VerticalScrollingStartAddressWrite) -> vertical_scrolling_start_address::VerticalScrollingStartAddressWrite
-    {
-        self.send_parameters(0x37, &vertical_scrolling_start_address::VerticalScrollingStartAddress::default().write(f).data)
-    }
-    /**
-        This command is used to recover from Idle mode on.
-        In the idle off mode, LCD can display maximum 262,144 colors.
-    */
-    pub fn idle_mode_off(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x38)
-    }
-    /**
-        This command is used to enter into Idle mode on.
-        In the idle on mode, color expression is reduced. The primary and the secondary colors using MSB of each R, G and B in
-        the
-        Frame Memory, 8 color depth data is displayed.
-    */
-    pub fn idle_mode_on(&mut self) -> Result<(), Iface::Error> {
-        self.command(0x39)
-    }
-    /**
-        This command sets the pixel format for the RGB image data used by the interface. DPI [2:0] is the pixel format select
-        of RGB
-        interface and DBI [2:0] is the pixel format of MCU interface. If a particular interface, either RGB interface or MCU
-        interface, is
-        not used then the corresponding bits in the parameter are ignored..
-        
-        If using RGB Interface must selection serial interface.
-    */
-    pub fn pixel_format_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(pixel_format::PixelFormatSetWrite) -> pixel_format::PixelFormatSetWrite
-    {
-        self.send_parameters(0x3A, &pixel_format::PixelFormatSet::default().write(f).data)
-    }
-    /**
-        This command transfers image data from the host processor to the display module’s frame memory continuing from the
-        pixel location following the previous write_memory_continue or write_memory_start command.
-        
-        If set_address_mode B5 = 0:
-        Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
-        write_memory_start  or
-        write_memory_continue. The column register is then incremented and pixels are written to the frame memory until the
-        column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
-        incremented. Pixels are written to the frame memory until the page register equals the End Page (EP) value and the
-        column register equals the EC value, or the host processor sends another command. If the number of pixels exceeds (EC –
-        SC + 1) * (EP – SP + 1) the extra pixels are ignored.
-        
-        If set_address_mode B5 = 1:
-        Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
-        write_memory_start  or
-        write_memory_continue. The page register is then incremented and pixels are written to the frame memory until the page
-        register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
-        Pixels are written to the frame memory until the column register equals the End column (EC) value and the page register
-        equals the EP value, or the host processor sends another command. If the number of pixels exceeds (EC – SC + 1) * (EP –
-        SP + 1) the extra pixels are ignored.
-        
-        Sending any other command can stop frame Write.
-        
-        Frame Memory Access and Interface setting (B3h), WEMODE=0
-        When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the exceeding data will be ignored.
-        
-        Frame Memory Access and Interface setting (B3h), WEMODE=1
-        When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the column and page number will be reset, and the
-        exceeding data will be written into the following column and page.
-    */
-    pub fn write_memory_continue(&mut self, d: &[u8]) -> Result<(), Iface::Error> {
-        self.send_parameters(0x3C, d)
-    }
-    /**
-        This command transfers image data from the host processor to the display module’s frame memory continuing from the
-        pixel location following the previous write_memory_continue or write_memory_start command.
-        
-        If set_address_mode B5 = 0:
-        Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
-        write_memory_start  or
-        write_memory_continue. The column register is then incremented and pixels are written to the frame memory until the
-        column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
-        incremented. Pixels are written to the frame memory until the page register equals the End Page (EP) value and the
-        column register equals the EC value, or the host processor sends another command. If the number of pixels exceeds (EC –
-        SC + 1) * (EP – SP + 1) the extra pixels are ignored.
-        
-        If set_address_mode B5 = 1:
-        Data  is  written  continuing  from  the  pixel  location  after  the  write  range  of  the  previous
-        write_memory_start  or
-        write_memory_continue. The page register is then incremented and pixels are written to the frame memory until the page
-        register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
-        Pixels are written to the frame memory until the column register equals the End column (EC) value and the page register
-        equals the EP value, or the host processor sends another command. If the number of pixels exceeds (EC – SC + 1) * (EP –
-        SP + 1) the extra pixels are ignored.
-        
-        Sending any other command can stop frame Write.
-        
-        Frame Memory Access and Interface setting (B3h), WEMODE=0
-        When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the exceeding data will be ignored.
-        
-        Frame Memory Access and Interface setting (B3h), WEMODE=1
-        When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the column and page number will be reset, and the
-        exceeding data will be written into the following column and page.
-    */
-    pub fn write_memory_continue_iter<T: IntoIterator<Item = u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
-        self.send_parameters_iter(0x3C, d)
-    }
-    /**
-        This command transfers image data from the display module’s frame memory to the host processor continuing from the
-        location following the previous read_memory_continue (3Eh) or read_memory_start (2Eh) command.
-        
-        If set_address_mode B5 = 0:
-        Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
-        read_memory_continue. The column register is then incremented and pixels are read from the frame memory until the
-        column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
-        incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value and the
-        column register equals the EC value, or the host processor sends another command.
-        
-        If set_address_mode B5 = 1:
-        Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
-        read_memory_continue. The page register is then incremented and pixels are read from the frame memory until the page
-        register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
-        Pixels are read from the frame memory until the column register equals the End Column (EC) value and the page register
-        equals the EP value, or the host processor sends another command.
-        
-        This command makes no change to the other driver status.
-    */
-    pub fn read_memory_continue<'l>(&mut self, d: &'l mut [u8]) -> Result<&'l mut [u8], Iface::Error> {
-        self.read_parameters(0x3E, d)?;
-        Ok(d)
-    }
-    /**
-        This command transfers image data from the display module’s frame memory to the host processor continuing from the
-        location following the previous read_memory_continue (3Eh) or read_memory_start (2Eh) command.
-        
-        If set_address_mode B5 = 0:
-        Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
-        read_memory_continue. The column register is then incremented and pixels are read from the frame memory until the
-        column register equals the End Column (EC) value. The column register is then reset to SC and the page register is
-        incremented. Pixels are read from the frame memory until the page register equals the End Page (EP) value and the
-        column register equals the EC value, or the host processor sends another command.
-        
-        If set_address_mode B5 = 1:
-        Pixels are read continuing from the pixel location after the read range of the previous read_memory_start or
-        read_memory_continue. The page register is then incremented and pixels are read from the frame memory until the page
-        register equals the End Page (EP) value. The page register is then reset to SP and the column register is incremented.
-        Pixels are read from the frame memory until the column register equals the End Column (EC) value and the page register
-        equals the EP value, or the host processor sends another command.
-        
-        This command makes no change to the other driver status.
-    */
-    pub fn read_memory_continue_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
-        self.read_parameters_iter(0x3E, d)
-    }
-    /**
-        This command turns on the display Tearing Effect output signal on the TE signal line when the display reaches line STS.
-        The TE signal is not affected by changing set_address_mode bit B4. The Tearing Effect Line On has one parameter that
-        describes the Tearing Effect Output Line mode.
-        tvdl tvdh
-        Vertical Time Scale
-        
-        Note that set_tear_scanline with STS=0 is equivalent to set_tear_on with M=0.
-        The Tearing Effect Output line shall be active low when the display module is in Sleep mode.
-    */
-    pub fn set_tear_scanline<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(tear_scanline::SetTearScanlineWrite) -> tear_scanline::SetTearScanlineWrite
-    {
-        self.send_parameters(0x44, &tear_scanline::SetTearScanline::default().write(f).data)
-    }
-    /**
-        The display returns the current scan line, GTS, used to update the display device. The total number of scan lines on a
-        display device is defined as VSYNC + VBP + VACT + VFP. The first scan line is defined as the first line of V-Sync and is
-        denoted as Line 0.
-        When in Sleep Mode, the value returned by get_scanline is undefined.
-    */
-    pub fn get_scanline(&mut self) -> Result<get_scanline::GetScanline, Iface::Error> {
-        let mut r = get_scanline::GetScanline::default();
-        self.read_parameters(0x45, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This command is used to adjust the brightness value of the display.
-        It should be checked what is the relationship between this written value and output brightness of the display. This
-        relationship
-        is defined on the display module specification.
-        In principle relationship is that 00h value means the lowest brightness and FFh value means the highest brightness.
-    */
-    pub fn write_display_brightness<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(write_display_brightness::DisplayBrightnessWrite) -> write_display_brightness::DisplayBrightnessWrite
-    {
-        self.send_parameters(0x51, &write_display_brightness::DisplayBrightness::default().write(f).data)
-    }
-    /**
-        This command returns the brightness value of the display.
-        
-        It should be checked what the relationship between this returned value and output brightness of the display. This
-        relationship is defined on the display module specification.
-        In principle the relationship is that 00h value means the lowest brightness and FFh value means the highest brightness.
-    */
-    pub fn read_display_brightness(&mut self) -> Result<read_display_brightness::DisplayBrightness, Iface::Error> {
-        let mut r = read_display_brightness::DisplayBrightness::default();
-        self.read_parameters(0x52, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This command is used to control display brightness.
-        BCTRL: Brightness Control Block On/Off, This bit is always used to switch brightness for display.
-        0 = Off (Brightness registers are 00h, DBV[7..0])
-        1 = On (Brightness registers are active, according to the other parameters.)
-        
-        DD: Display Dimming, only for manual brightness setting
-        DD = 0: Display Dimming is off
-        DD = 1: Display Dimming is on
-        
-        BL: Backlight Control On/Off
-        0 = Off (Completely turn off backlight circuit. Control lines must be low. )
-        1 = On
-        Dimming function is adapted to the brightness registers for display when bit BCTRL is changed at DD=1, e.g. BCTRL: 0
-        (cid:4)
-        1 or 1(cid:4) 0.
-        
-        When BL bit change from “On” to “Off”, backlight is turned off without gradual dimming, even if dimming-on (DD=1) are
-        selected.
-    */
-    pub fn write_ctrl_display<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(write_ctrl_display::CtrlDisplayWrite) -> write_ctrl_display::CtrlDisplayWrite
-    {
-        self.send_parameters(0x53, &write_ctrl_display::CtrlDisplay::default().write(f).data)
-    }
-    /**
-        This command is used to return brightness setting.
-        
-        BCTRL: Brightness Control Block On/Off,
-        ‘0’ = Off (Brightness registers are 00h)
-        ‘1’ = On (Brightness registers are active, according to the DBV[7..0] parameters.)
-        
-        DD: Display Dimming
-        ‘0’ = Display Dimming is off
-        ‘1’ = Display Dimming is on
-        
-        BL: Backlight On/Off
-        ‘0’ = Off (Completely turn off backlight circuit. Control lines must be low. )
-        ‘1’ = On
-    */
-    pub fn read_ctrl_display(&mut self) -> Result<read_ctrl_display::CtrlDisplay, Iface::Error> {
-        let mut r = read_ctrl_display::CtrlDisplay::default();
-        self.read_parameters(0x54, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This command is used to set parameters for image content based adaptive brightness control functionality.
-        There is possible to use 4 different modes for content adaptive image functionality, which are defined on a table
-        below.
-    */
-    pub fn write_content_adaptive_brightness_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(write_content_adaptive_brightness_control::ContentAdaptiveBrightnessControlWrite) -> write_content_adaptive_brightness_control::ContentAdaptiveBrightnessControlWrite
-    {
-        self.send_parameters(0x55, &write_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl::default().write(f).data)
-    }
-    /**
-        This command is used to read the settings for image content based adaptive brightness control functionality.
-        It is possible to use 4 different modes for content adaptive image functionality, which are defined on a table below.
-    */
-    pub fn read_content_adaptive_brightness_control(&mut self) -> Result<read_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl, Iface::Error> {
-        let mut r = read_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl::default();
-        self.read_parameters(0x56, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This command is used to set the minimum brightness value of the display for CABC function.
-        CMB[7:0]: CABC minimum brightness control, this parameter is used to avoid too much brightness reduction.
-        When CABC is active, CABC cannot reduce the display brightness to less than CABC minimum brightness setting. Image
-        processing function is worked as normal, even if the brightness cannot be changed.
-        This function does not affect to the other function, manual brightness setting. Manual brightness can be set the display
-        brightness to less than CABC minimum brightness. Smooth transition and dimming function can be worked as normal.
-        When display brightness is turned off (BCTRL=0 of “Write CTRL Display (53h)”), CABC minimum brightness setting is
-        ignored.
-        In principle relationship is that 00h value means the lowest brightness for CABC and FFh value means the highest
-        brightness for CABC.
-    */
-    pub fn write_cabc_minimum_brightness<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(write_cabc_minimum_brightness::CabcMinimumBrightnessWrite) -> write_cabc_minimum_brightness::CabcMinimumBrightnessWrite
-    {
-        self.send_parameters(0x5E, &write_cabc_minimum_brightness::CabcMinimumBrightness::default().write(f).data)
-    }
-    /**
-        This command returns the minimum brightness value of CABC function.
-        In principle the relationship is that 00h value means the lowest brightness and FFh value means the highest brightness.
-        CMB[7:0] is CABC minimum brightness specified with “Write CABC minimum brightness (5Eh)” command. In principle
-        relationship is that 00h value means the lowest brightness for CABC and FFh value means the highest brightness for
-        CABC.
-    */
-    pub fn read_cabc_minimum_brightness(&mut self) -> Result<read_cabc_minimum_brightness::CabcMinimumBrightness, Iface::Error> {
-        let mut r = read_cabc_minimum_brightness::CabcMinimumBrightness::default();
-        self.read_parameters(0x5F, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This read byte identifies the LCD module’s manufacturer ID and it is specified by User
-        The 1st parameter is dummy data.
-        The 2nd parameter is LCD module’s manufacturer ID.
-    */
-    pub fn read_id1(&mut self) -> Result<read_id1::Id1, Iface::Error> {
-        let mut r = read_id1::Id1::default();
-        self.read_parameters(0xDA, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This read byte is used to track the LCD module/driver version. It is defined by display supplier (with User’s
-        agreement) and
-        changes each time a revision is made to the display, material or construction specifications.
-        The 1st parameter is dummy data.
-        The 2nd parameter is LCD module/driver version ID and the ID parameter range is from 80h to FFh.
-        The ID2 can be programmed by MTP function.
-    */
-    pub fn read_id2(&mut self) -> Result<read_id2::Id2, Iface::Error> {
-        let mut r = read_id2::Id2::default();
-        self.read_parameters(0xDB, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        This read byte identifies the LCD module/driver and It is specified by User.
-        The 1st parameter is dummy data.
-        The 2nd parameter is LCD module/driver ID.
-        The ID3 can be programmed by MTP function.
-    */
-    pub fn read_id3(&mut self) -> Result<read_id3::Id3, Iface::Error> {
-        let mut r = read_id3::Id3::default();
-        self.read_parameters(0xDC, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        Sets the operation status of the display interface. The setting becomes effective as soon as the command is received.
-        EPL: DE polarity (“0”= High enable for RGB interface, “1”= Low enable for RGB interface)
-        DPL: DOTCLK polarity set (“0”= data fetched at the rising time, “1”= data fetched at the falling time)
-        HSPL: HSYNC polarity (“0”= Low level sync clock, “1”= High level sync clock)
-        VSPL: VSYNC polarity (“0”= Low level sync clock, “1”= High level sync clock)
-        RCM [1:0]: RGB interface selection (refer to the RGB interface section).
-        
-        ByPass_MODE: Select display data path whether Memory or Direct to Shift register when RGB Interface is used.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn rgb_interface_signal_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(rgb_interface_signal_control::RgbInterfaceSignalControlWrite) -> rgb_interface_signal_control::RgbInterfaceSignalControlWrite
-    {
-        self.send_parameters(0xB0, &rgb_interface_signal_control::RgbInterfaceSignalControl::default().write(f).data)
-    }
-    /**
-        Formula to calculate frame frequency: FrameRate = fosc / (ClocksPerLine x DivisionRatio x (Lines + VBP + VFP))
-        Sets the division ratio for internal clocks of Normal mode at MCU interface.
-        fosc : internal oscillator frequency
-        Clocks per line : RTNA setting
-        Division ratio : DIVA setting
-        Lines : total driving line number
-        VBP : back porch line number
-        VFP : front porch line number
-        
-        DIVA [1:0] : division ratio for internal clocks when Normal mode.
-        
-        RTNA [4:0] : RTNA[4:0] is used to set 1H (line) period of Normal mode at MCU interface.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn frame_control_in_normal_mode<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(frame_control_in_normal_mode::FrameControlInNormalModeWrite) -> frame_control_in_normal_mode::FrameControlInNormalModeWrite
-    {
-        self.send_parameters(0xB1, &frame_control_in_normal_mode::FrameControlInNormalMode::default().write(f).data)
-    }
-    /**
-        Formula to calculate frame frequency: FrameRate = fosc / (ClocksPerLine x DivisionRatio x (Lines + VBP + VFP))
-        Sets the division ratio for internal clocks of Idle mode at MCU interface.
-        fosc : internal oscillator frequency
-        Clocks per line : RTNB setting
-        Division ratio : DIVB setting
-        Lines : total driving line number
-        VBP : back porch line number
-        VFP : front porch line number
-        
-        DIVB [1:0]: division ratio for internal clocks when Idle mode.
-        
-        RTNB [4:0]: RTNB[4:0] is used to set 1H (line) period of Idle mode at MCU interface.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn frame_control_in_idle_mode<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(frame_control_in_idle_mode::FrameControlInIdleModeWrite) -> frame_control_in_idle_mode::FrameControlInIdleModeWrite
-    {
-        self.send_parameters(0xB2, &frame_control_in_idle_mode::FrameControlInIdleMode::default().write(f).data)
-    }
-    /**
-        Formula to calculate frame frequency: FrameRate = fosc / (ClocksPerLine x DivisionRatio x (Lines + VBP + VFP))
-        Sets the division ratio for internal clocks of Partial mode (Idle mode off) at MCU interface.
-        fosc : internal oscillator frequency
-        Clocks per line : RTNC setting
-        Division ratio : DIVC setting
-        Lines : total driving line number
-        VBP : back porch line number
-        VFP : front porch line number
-        
-        DIVC [1:0]: division ratio for internal clocks when Partial mode.
-        
-        RTNC [4:0]: RTNC [4:0] is used to set 1H (line) period of Partial mode at MCU interface.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn frame_control_in_partial_mode<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(frame_control_in_partial_mode::FrameControlInPartialModeWrite) -> frame_control_in_partial_mode::FrameControlInPartialModeWrite
-    {
-        self.send_parameters(0xB3, &frame_control_in_partial_mode::FrameControlInPartialMode::default().write(f).data)
-    }
-    /**
-        Display inversion mode set
-        NLA: Inversion setting in full colors normal mode (Normal mode on)
-        NLB: Inversion setting in Idle mode (Idle mode on)
-        NLC: Inversion setting in full colors partial mode (Partial mode on / Idle mode off)
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn display_inversion_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(display_inversion_control::DisplayInversionControlWrite) -> display_inversion_control::DisplayInversionControlWrite
-    {
-        self.send_parameters(0xB4, &display_inversion_control::DisplayInversionControl::default().write(f).data)
-    }
-    /**
-        VFP [6:0] / VBP [6:0]: The VFP [6:0] and VBP [6:0] bits specify the line number of vertical front and back porch period
-        respectively.
-        Note: VFP + VBP ≦ 254 HSYNC signals
-        
-        HFP [4:0] / HBP [4:0]: The HFP [4:0] and HBP [4:0] bits specify the line number of horizontal front and back porch
-        period
-        respectively.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn blanking_porch_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(blanking_porch_control::BlankingPorchControlWrite) -> blanking_porch_control::BlankingPorchControlWrite
-    {
-        self.send_parameters(0xB5, &blanking_porch_control::BlankingPorchControl::default().write(f).data)
-    }
-    /**
-        PTG [1:0]: Set the scan mode in non-display area.
-        
-        PT [1:0]: Determine source/VCOM output in a non-display area in the partial display mode.
-        
-        SS: Select the shift direction of outputs from the source driver.
-        In addition to the shift direction, the settings for both SS and BGR bits are required to change the assignment of R, G,
-        and B dots to the source driver pins.
-        To assign R, G, B dots to the source driver pins from S1 to S720, set SS = 0.
-        To assign R, G, B dots to the source driver pins from S720 to S1, set SS = 1.
-        
-        REV: Select whether the liquid crystal type is normally white type or normally black type.
-        
-        ISC [3:0]: Specify the scan cycle interval of gate driver in non-display area when PTG [1:0] =”10” to select interval
-        scan.
-        Then scan cycle is set as odd number from 0~29 frame periods. The polarity is inverted every scan cycle.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn display_function_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(display_function_control::DisplayFunctionControlWrite) -> display_function_control::DisplayFunctionControlWrite
-    {
-        self.send_parameters(0xB6, &display_function_control::DisplayFunctionControl::default().write(f).data)
-    }
-    /**
-        DSTB: The ILI9341 driver enters the Deep Standby Mode when DSTB is set to high (“1”). In Deep Standby mode, both
-        internal logic power and SRAM power are turn off, the display data stored in the Frame Memory and the instructions are
-        not
-        saved. Rewrite Frame Memory content and instructions after the Deep Standby Mode is exited.
-        Note:  ILI9341 provides two ways to exit the Deep Standby Mode:
-        (1) Exit Deep Standby Mode by pull down CSX to low (“0”) 6 times.
-        (2) Input a RESX pulse with effective low level duration to start up the inside logic regulator and makes a transition
-        to the initial state.
-        
-        1 2 Wait 1ms or more 3 4 5 6
-        CSX
-        WRX “High”
-        RDX “High”
-        D/CX “Low” or “High”
-        D[17:0] Don CareDon Care Don CareDon Care Don CareDon Care
-        
-        GAS: Low voltage detection control.
-        
-        GON/DTE: Set the output level of gate driver G1 ~ G320 as follows
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn entry_mode_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(entry_mode::EntryModeSetWrite) -> entry_mode::EntryModeSetWrite
-    {
-        self.send_parameters(0xB7, &entry_mode::EntryModeSet::default().write(f).data)
-    }
-    /**
-        TH_UI [3:0]: These bits are used to set the percentage of grayscale data accumulate histogram value in the user
-        interface
-        (UI) mode. This ratio of maximum number of pixels that makes display image white (=data “255”) to the total of
-        pixels by image processing.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control1<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control1::BacklightControl1Write) -> backlight_control1::BacklightControl1Write
-    {
-        self.send_parameters(0xB8, &backlight_control1::BacklightControl1::default().write(f).data)
-    }
-    /**
-        TH_ST [3:0]: These bits are used to set the percentage of grayscale data accumulate histogram value in the still picture
-        mode. This ratio of maximum number of pixels that makes display image white (=data “255”) to the total of pixels
-        by image processing.
-        
-        TH_MV [3:0]: These bits are used to set the percentage of grayscale data accumulate histogram value in the moving image
-        mode. This ratio of maximum number of pixels that makes display image white (=data “255”) to the total of pixels
-        by image processing.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control2<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control2::BacklightControl2Write) -> backlight_control2::BacklightControl2Write
-    {
-        self.send_parameters(0xB9, &backlight_control2::BacklightControl2::default().write(f).data)
-    }
-    /**
-        DTH_UI [3:0]: This parameter is used set the minimum limitation of grayscale threshold value in User Icon (UI) image
-        mode.
-        This register setting will limit the minimum Dth value to prevent the display image from being too white and
-        the display quality is not acceptable.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control3<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control3::BacklightControl3Write) -> backlight_control3::BacklightControl3Write
-    {
-        self.send_parameters(0xBA, &backlight_control3::BacklightControl3::default().write(f).data)
-    }
-    /**
-        DTH_ST [3:0]/DTH_MV [3:0]: This parameter is used set the minimum limitation of grayscale threshold value. This register
-        setting will limit the minimum Dth value to prevent the display image from being too white and the
-        display quality is not acceptable.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control4<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control4::BacklightControl4Write) -> backlight_control4::BacklightControl4Write
-    {
-        self.send_parameters(0xBB, &backlight_control4::BacklightControl4::default().write(f).data)
-    }
-    /**
-        DIM1 [2:0]: This parameter is used to set the transition time of brightness level to avoid the sharp brightness
-        transition on
-        vision.
-        
-        DIM2 [3:0]: This parameter is used to set the threshold of brightness change.
-        When the brightness transition difference is smaller than DIM2 [3:0], the brightness transition will be ignored.
-        For example:
-        If | brightness B – brightness A| < DIM2 [2:0], the brightness transition will be ignored and keep the brightness A.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control5<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control5::BacklightControl5Write) -> backlight_control5::BacklightControl5Write
-    {
-        self.send_parameters(0xBC, &backlight_control5::BacklightControl5::default().write(f).data)
-    }
-    /**
-        PWM_DIV [7:0]: PWM_OUT output frequency control. This command is used to adjust the PWM waveform frequency of
-        PWM_OUT. The PWM frequency can be calculated by using the following equation.
-        
-        16MHz
-        f  =
-        PWM_OUT   (PWM_DIV[7:0]+1)×255
-        
-        Note: The output frequency tolerance of internal frequency divider in CABC is ±10%
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control7<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control7::BacklightControl7Write) -> backlight_control7::BacklightControl7Write
-    {
-        self.send_parameters(0xBE, &backlight_control7::BacklightControl7::default().write(f).data)
-    }
-    /**
-        LEDPWMPOL: The bit is used to define polarity of LEDPWM signal.
-        
-        LEDONPOL: This bit is used to control LEDON pin.
-        
-        LEDONR: This bit is used to control LEDON pin.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn backlight_control8<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(backlight_control8::BacklightControl8Write) -> backlight_control8::BacklightControl8Write
-    {
-        self.send_parameters(0xBF, &backlight_control8::BacklightControl8::default().write(f).data)
-    }
-    /**
-        VRH [5:0]: Set the GVDD level, which is a reference level for the VCOM level and the grayscale voltage level.
-        
-        Note1: Make sure that VC and VRH setting restriction: GVDD ≦ (AVDD - 0.5) V.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn power_control1<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(power_control1::PowerControl1Write) -> power_control1::PowerControl1Write
-    {
-        self.send_parameters(0xC0, &power_control1::PowerControl1::default().write(f).data)
-    }
-    /**
-        BT [2:0]: Sets the factor used in the step-up circuits.
-        Select the optimal step-up factor for the operating voltage. To reduce power consumption, set a smaller factor.
-        Note1: Make sure that AVDD setting restriction: AVDD ≦ 5.5 V.
-        2: Make sure that VGH and VGL setting restriction: VGH -VGL≦ 32 V.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn power_control2<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(power_control2::PowerControl2Write) -> power_control2::PowerControl2Write
-    {
-        self.send_parameters(0xC1, &power_control2::PowerControl2::default().write(f).data)
-    }
-    /**
-        VMH [6:0] : Set the VCOMH voltage.
-        
-        VML [6:0] : Set the VCOML voltage
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn vcom_control1<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(vcom_control1::VcomControl1Write) -> vcom_control1::VcomControl1Write
-    {
-        self.send_parameters(0xC5, &vcom_control1::VcomControl1::default().write(f).data)
-    }
-    /**
-        nVM: nVM equals to “0” after power on reset and VCOM offset equals to program MTP value. When nVM set to “1”, setting
-        of VMF [6:0] becomes valid and VCOMH/VCOML can be adjusted.
-        
-        VMF [6:0]: Set the VCOM offset voltage.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn vcom_control2<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(vcom_control2::VcomControl2Write) -> vcom_control2::VcomControl2Write
-    {
-        self.send_parameters(0xC7, &vcom_control2::VcomControl2::default().write(f).data)
-    }
-    /**
-        This command is used to program the NV memory data. After a successful MTP operation, the information of PGM_DATA
-        [7:0] will programmed to NV memory.
-        PGM_ADR [2:0]: The select bits of ID1, ID2, ID3 and VMF [6:0] programming.
-        
-        PGM_DATA [7:0]: The programmed data.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn nv_memory_write<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(nv_memory_write::NvMemoryWrite) -> nv_memory_write::NvMemoryWrite
-    {
-        self.send_parameters(0xD0, &nv_memory_write::NvMemory::default().write(f).data)
-    }
-    /**
-        KEY [23:0]: NV memory programming protection key. When writing MTP data to D1h, this register must be set to
-        0x55AA66h to enable MTP programming. If D1h register is not written with 0x55AA66h, then NV memory programming will
-        be aborted.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn nv_memory_protection_key<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(nv_memory_protection_key::NvMemoryProtectionKeyWrite) -> nv_memory_protection_key::NvMemoryProtectionKeyWrite
-    {
-        self.send_parameters(0xD1, &nv_memory_protection_key::NvMemoryProtectionKey::default().write(f).data)
-    }
-    /**
-        ID1_CNT [2:0] / ID2_CNT [2:0] / ID3_CNT [2:0] / VMF_CNT [2:0]: NV memory program record. The bits will increase “+1”
-        automatically after writing the PGM_DATA [7:0] to NV memory.
-        
-        BUSY: The status bit of NV memory programming.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn nv_memory_status_read(&mut self) -> Result<nv_memory_status_read::NvMemoryStatus, Iface::Error> {
-        let mut r = nv_memory_status_read::NvMemoryStatus::default();
-        self.read_parameters(0xD2, &mut r.data)?;
-        Ok(r)
-    }
-    /**
-        Read IC device code.
-        The 1st parameter is dummy read period.
-        The 2nd parameter means the IC version.
-        The 3rd and 4th parameter mean the IC model name.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn read_id4(&mut self) -> Result<read_id4::Id4, Iface::Error> {
-        let mut r = read_id4::Id4::default();
-        self.read_parameters(0xD3, &mut r.data)?;
-        Ok(r)
+    /// Send a raw command with 16-bit bus words, see [`Interface::send_parameters_u16`]
+    /// Meant for the frame memory, the shadow registers and the tracked MV are not updated.
+    #[inline(always)]
+    pub fn send_parameters_u16(&mut self, command: u8, data: &[u16]) -> Result<(), Iface::Error> {
+        self.iface.send_parameters_u16(command, data)
     }
-    /// Positive Gamma Correction
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn positive_gamma_correction<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(positive_gamma_correction::PositiveGammaCorrectionWrite) -> positive_gamma_correction::PositiveGammaCorrectionWrite
-    {
-        self.send_parameters(0xE0, &positive_gamma_correction::PositiveGammaCorrection::default().write(f).data)
+    /// Send a raw command with 18-bit bus words, see [`Interface::send_parameters_u32`]
+    /// Meant for the frame memory, the shadow registers and the tracked MV are not updated.
+    #[inline(always)]
+    pub fn send_parameters_u32(&mut self, command: u8, data: &[u32]) -> Result<(), Iface::Error> {
+        self.iface.send_parameters_u32(command, data)
     }
-    /// Negative Gamma Correction
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn negative_gamma_correction<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(negative_gamma_correction::NegativeGammaCorrectionWrite) -> negative_gamma_correction::NegativeGammaCorrectionWrite
-    {
-        self.send_parameters(0xE1, &negative_gamma_correction::NegativeGammaCorrection::default().write(f).data)
-    }
-    /**
-        RCAx [3:0]: Gamma Macro-adjustment registers for red gamma curve.
-        BCAx [3:0]: Gamma Macro-adjustment registers for blue gamma curve.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn digital_gamma_control1<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(digital_gamma_control1::DigitalGammaControl1Write) -> digital_gamma_control1::DigitalGammaControl1Write
-    {
-        self.send_parameters(0xE2, &digital_gamma_control1::DigitalGammaControl1::default().write(f).data)
-    }
-    /**
-        RFAx [3:0]: Gamma Micro-adjustment register for red gamma curve.
-        BFAx [3:0]: Gamma Micro-adjustment register for blue gamma curve.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn digital_gamma_control2<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(digital_gamma_control2::DigitalGammaControl2Write) -> digital_gamma_control2::DigitalGammaControl2Write
-    {
-        self.send_parameters(0xE3, &digital_gamma_control2::DigitalGammaControl2::default().write(f).data)
-    }
-    /**
-        MY_EOR / MX_EOR / MV_EOR / BGR_EOR:
-        The set value of MADCTL is used in the IC is derived as exclusive OR between 1st Parameter of IFCTL and MADCTL
-        Parameter.
-        
-        MDT [1:0]: Select the method of display data transferring.
-        
-        WEMODE: Memory write control
-        WEMODE=0: When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the exceeding data will be ignored.
-        WEMODE=1: When the transfer number of data exceeds (EC-SC+1)*(EP-SP+1), the column and page number will be
-        reset, and the exceeding data will be written into the following column and page.
-        
-        ENDIAN: Select Little Endian Interface bit. At Little Endian mode, the host sends LSB data first.
-        Note: Little Endian is valid on only 65K 8-bit and 9-bit MCU interface mode.
-        
-        DM [1:0]: Select the display operation mode.
-        The DM [1:0] setting allows switching between internal clock operation mode and external display interface operation
-        mode.
-        However, switching between the RGB interface operation mode and the VSYNC interface operation mode is prohibited.
-    */
-    #[cfg(feature = "Ili9341ExtendedCommandSet")]
-    pub fn interface_control<F>(&mut self, f: F) -> Result<(), Iface::Error>
-    where
-        F: FnOnce(interface_control::InterfaceControlWrite) -> interface_control::InterfaceControlWrite
-    {
-        self.send_parameters(0xF6, &interface_control::InterfaceControl::default().write(f).data)
+    /// Send a raw read command and read 16-bit bus words, see [`Interface::read_parameters_u16`]
+    #[inline(always)]
+    pub fn read_parameters_u16(&mut self, command: u8, data: &mut [u16]) -> Result<(), Iface::Error> {
+        self.iface.read_parameters_u16(command, data)
+    }
+    /// Send a raw read command and read 18-bit bus words, see [`Interface::read_parameters_u32`]
+    #[inline(always)]
+    pub fn read_parameters_u32(&mut self, command: u8, data: &mut [u32]) -> Result<(), Iface::Error> {
+        self.iface.read_parameters_u32(command, data)
     }
-    // autogenerated functions end
+
+    command_table!(blocking_commands);
 }
 
 // autogenerated macros start
@@ -1150,6 +337,7 @@ macro_rules! enum_with_from {
             impl TryFrom<$repr_type> for $name {
                 type Error = $repr_type;
                 fn try_from(v: $repr_type) -> Result<Self, Self::Error> {
+                    #[allow(unreachable_patterns)]
                     match v {
                         $($value => Ok(Self::$entry),)+
                         _ => Err(v)
@@ -1165,12 +353,30 @@ macro_rules! enum_with_from {
 // autogenerated types start
 
 pub mod read_display_identification_information {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayIdentificationInformation {
         pub(super) data: [u8; 3],
     }
     impl DisplayIdentificationInformation {
-        pub fn read(&self) -> DisplayIdentificationInformationRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayIdentificationInformation {
+                data: [ 0x00, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            DisplayIdentificationInformation { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayIdentificationInformationRead<'_> {
             DisplayIdentificationInformationRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1205,6 +411,12 @@ pub mod read_display_identification_information {
         d: &'l mut DisplayIdentificationInformation,
     }
     impl<'l> DisplayIdentificationInformationWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayIdentificationInformation) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// lcd_modules_manufacturer_id
         #[inline(always)]
         pub fn lcd_modules_manufacturer_id(self, w: u8) -> Self {
@@ -1227,9 +439,17 @@ pub mod read_display_identification_information {
     }
     impl Default for DisplayIdentificationInformation {
         fn default() -> Self {
-            DisplayIdentificationInformation {
-                data: [ 0x00, 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayIdentificationInformation {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayIdentificationInformation")
+                .field("lcd_modules_manufacturer_id", &r.lcd_modules_manufacturer_id())
+                .field("lcd_module_driver_version_id", &r.lcd_module_driver_version_id())
+                .field("lcd_module_driver_id", &r.lcd_module_driver_id())
+                .finish()
         }
     }
 }
@@ -1253,12 +473,30 @@ pub mod read_display_status {
         GammaCurveSelection(u8) => { Gc0 = 0x00 },
         TearingEffectLineMode(u8) => { Mode1VBlankingOnly = 0x00, Mode2BothHBlankingAndVBlanking = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayStatus {
         pub(super) data: [u8; 4],
     }
     impl DisplayStatus {
-        pub fn read(&self) -> DisplayStatusRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayStatus {
+                data: [ 0x00, 0x61, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            DisplayStatus { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayStatusRead<'_> {
             DisplayStatusRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1363,6 +601,12 @@ pub mod read_display_status {
         d: &'l mut DisplayStatus,
     }
     impl<'l> DisplayStatusWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayStatus) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// booster_voltage_status
         #[inline(always)]
         pub fn booster_voltage_status(self, w: BoosterVoltageStatus) -> Self {
@@ -1505,9 +749,31 @@ pub mod read_display_status {
     }
     impl Default for DisplayStatus {
         fn default() -> Self {
-            DisplayStatus {
-                data: [ 0x00, 0x61, 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayStatus {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayStatus")
+                .field("booster_voltage_status", &super::Field(r.booster_voltage_status()))
+                .field("row_address_order", &super::Field(r.row_address_order()))
+                .field("column_address_order", &super::Field(r.column_address_order()))
+                .field("row_column_exchange", &super::Field(r.row_column_exchange()))
+                .field("vertical_refresh", &super::Field(r.vertical_refresh()))
+                .field("rgb_bgr_order", &super::Field(r.rgb_bgr_order()))
+                .field("horizontal_refresh_order", &super::Field(r.horizontal_refresh_order()))
+                .field("interface_color_pixel_format", &super::Field(r.interface_color_pixel_format()))
+                .field("idle_mode", &super::Field(r.idle_mode()))
+                .field("partial_mode", &super::Field(r.partial_mode()))
+                .field("sleep", &super::Field(r.sleep()))
+                .field("display_normal_mode", &super::Field(r.display_normal_mode()))
+                .field("vertical_scrolling_status", &super::Field(r.vertical_scrolling_status()))
+                .field("display", &super::Field(r.display()))
+                .field("tearing_effect_line", &super::Field(r.tearing_effect_line()))
+                .field("gamma_curve_selection", &super::Field(r.gamma_curve_selection()))
+                .field("tearing_effect_line_mode", &super::Field(r.tearing_effect_line_mode()))
+                .finish()
         }
     }
 }
@@ -1520,12 +786,30 @@ pub mod read_display_power_mode {
         DisplayNormalMode(u8) => { DisplayNormalModeOff = 0x00, DisplayNormalModeOn = 0x01 },
         DisplayIs(u8) => { DisplayIsOff = 0x00, DisplayIsOn = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayPowerMode {
         pub(super) data: [u8; 1],
     }
     impl DisplayPowerMode {
-        pub fn read(&self) -> DisplayPowerModeRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayPowerMode {
+                data: [ 0x08 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayPowerMode { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayPowerModeRead<'_> {
             DisplayPowerModeRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1575,6 +859,12 @@ pub mod read_display_power_mode {
         d: &'l mut DisplayPowerMode,
     }
     impl<'l> DisplayPowerModeWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayPowerMode) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// booster
         #[inline(always)]
         pub fn booster(self, w: Booster) -> Self {
@@ -1627,9 +917,20 @@ pub mod read_display_power_mode {
     }
     impl Default for DisplayPowerMode {
         fn default() -> Self {
-            DisplayPowerMode {
-                data: [ 0x08 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayPowerMode {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayPowerMode")
+                .field("booster", &super::Field(r.booster()))
+                .field("idle_mode", &super::Field(r.idle_mode()))
+                .field("partial_mode", &super::Field(r.partial_mode()))
+                .field("sleep", &super::Field(r.sleep()))
+                .field("display_normal_mode", &super::Field(r.display_normal_mode()))
+                .field("display_is", &super::Field(r.display_is()))
+                .finish()
         }
     }
 }
@@ -1642,12 +943,30 @@ pub mod read_display_madctl {
         RgbBgrOrder(u8) => { RgbWhenMadctlB3Eq0 = 0x00, BgrWhenMadctlB3Eq1 = 0x01 },
         HorizontalRefreshOrder(u8) => { LcdRefreshLeftToRightWhenMadctlB2Eq0 = 0x00, LcdRefreshRightToLeftWhenMadctlB2Eq1 = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayMadctl {
         pub(super) data: [u8; 1],
     }
     impl DisplayMadctl {
-        pub fn read(&self) -> DisplayMadctlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayMadctl {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayMadctl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayMadctlRead<'_> {
             DisplayMadctlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1697,6 +1016,12 @@ pub mod read_display_madctl {
         d: &'l mut DisplayMadctl,
     }
     impl<'l> DisplayMadctlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayMadctl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// row_address_order
         #[inline(always)]
         pub fn row_address_order(self, w: RowAddressOrder) -> Self {
@@ -1749,9 +1074,20 @@ pub mod read_display_madctl {
     }
     impl Default for DisplayMadctl {
         fn default() -> Self {
-            DisplayMadctl {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayMadctl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayMadctl")
+                .field("row_address_order", &super::Field(r.row_address_order()))
+                .field("column_address_order", &super::Field(r.column_address_order()))
+                .field("row_column_exchange", &super::Field(r.row_column_exchange()))
+                .field("vertical_refresh", &super::Field(r.vertical_refresh()))
+                .field("rgb_bgr_order", &super::Field(r.rgb_bgr_order()))
+                .field("horizontal_refresh_order", &super::Field(r.horizontal_refresh_order()))
+                .finish()
         }
     }
 }
@@ -1760,12 +1096,30 @@ pub mod read_display_pixel_format {
         RgbInterfaceFormat(u8) => { N16Bits = 0x05, N18Bits = 0x06, N16Bits6Bit3TimesDataTransfer = 0x0D, N18Bits6Bit3TimesDataTransfer = 0x0E },
         McuInterfaceFormat(u8) => { N16Bits = 0x05, N18Bits = 0x06 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayPixelFormat {
         pub(super) data: [u8; 1],
     }
     impl DisplayPixelFormat {
-        pub fn read(&self) -> DisplayPixelFormatRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayPixelFormat {
+                data: [ 0x06 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayPixelFormat { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayPixelFormatRead<'_> {
             DisplayPixelFormatRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1795,6 +1149,12 @@ pub mod read_display_pixel_format {
         d: &'l mut DisplayPixelFormat,
     }
     impl<'l> DisplayPixelFormatWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayPixelFormat) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// rgb_interface_format
         #[inline(always)]
         pub fn rgb_interface_format(self, w: RgbInterfaceFormat) -> Self {
@@ -1815,22 +1175,48 @@ pub mod read_display_pixel_format {
     }
     impl Default for DisplayPixelFormat {
         fn default() -> Self {
-            DisplayPixelFormat {
-                data: [ 0x06 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayPixelFormat {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayPixelFormat")
+                .field("rgb_interface_format", &super::Field(r.rgb_interface_format()))
+                .field("mcu_interface_format", &super::Field(r.mcu_interface_format()))
+                .finish()
         }
     }
 }
 pub mod read_display_image_format {
     enum_with_from! {
+        Inversion(u8) => { InversionOff = 0x00, InversionOn = 0x01 },
         GammaCurveSelection(u8) => { GammaCurve1G2o2 = 0x00 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayImageFormat {
         pub(super) data: [u8; 1],
     }
     impl DisplayImageFormat {
-        pub fn read(&self) -> DisplayImageFormatRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayImageFormat {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayImageFormat { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayImageFormatRead<'_> {
             DisplayImageFormatRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1844,6 +1230,11 @@ pub mod read_display_image_format {
         d: &'l DisplayImageFormat,
     }
     impl<'l> DisplayImageFormatRead<'l> {
+        /// inversion
+        #[inline(always)]
+        pub fn inversion(&self) -> Result<Inversion, u8> {
+            Inversion::try_from((self.d.data[0] >> 5) & 0x01)
+        }
         /// gamma_curve_selection
         #[inline(always)]
         pub fn gamma_curve_selection(&self) -> Result<GammaCurveSelection, u8> {
@@ -1855,6 +1246,20 @@ pub mod read_display_image_format {
         d: &'l mut DisplayImageFormat,
     }
     impl<'l> DisplayImageFormatWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayImageFormat) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// inversion
+        #[inline(always)]
+        pub fn inversion(self, w: Inversion) -> Self {
+            let w = w as u8;
+            self.d.data[0] &= !(0x01 << 5);
+            self.d.data[0] |= (w & 0x01) << 5;
+            self
+        }
         /// gamma_curve_selection
         #[inline(always)]
         pub fn gamma_curve_selection(self, w: GammaCurveSelection) -> Self {
@@ -1867,9 +1272,16 @@ pub mod read_display_image_format {
     }
     impl Default for DisplayImageFormat {
         fn default() -> Self {
-            DisplayImageFormat {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayImageFormat {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayImageFormat")
+                .field("inversion", &super::Field(r.inversion()))
+                .field("gamma_curve_selection", &super::Field(r.gamma_curve_selection()))
+                .finish()
         }
     }
 }
@@ -1882,12 +1294,30 @@ pub mod read_display_signal_mode {
         PixelClock(u8) => { PixelClockDotclkRgbInterfaceOff = 0x00, PixelClockDotclkRgbInterfaceOn = 0x01 },
         DataEnable(u8) => { DataEnableDeRgbInterfaceOff = 0x00, DataEnableDeRgbInterfaceOn = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplaySignalMode {
         pub(super) data: [u8; 1],
     }
     impl DisplaySignalMode {
-        pub fn read(&self) -> DisplaySignalModeRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplaySignalMode {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplaySignalMode { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplaySignalModeRead<'_> {
             DisplaySignalModeRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -1937,6 +1367,12 @@ pub mod read_display_signal_mode {
         d: &'l mut DisplaySignalMode,
     }
     impl<'l> DisplaySignalModeWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplaySignalMode) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// tearing_effect_line
         #[inline(always)]
         pub fn tearing_effect_line(self, w: TearingEffectLine) -> Self {
@@ -1989,19 +1425,48 @@ pub mod read_display_signal_mode {
     }
     impl Default for DisplaySignalMode {
         fn default() -> Self {
-            DisplaySignalMode {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplaySignalMode {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplaySignalMode")
+                .field("tearing_effect_line", &super::Field(r.tearing_effect_line()))
+                .field("tearing_effect_line_mode", &super::Field(r.tearing_effect_line_mode()))
+                .field("horizontal_sync", &super::Field(r.horizontal_sync()))
+                .field("vertical_sync", &super::Field(r.vertical_sync()))
+                .field("pixel_clock", &super::Field(r.pixel_clock()))
+                .field("data_enable", &super::Field(r.data_enable()))
+                .finish()
         }
     }
 }
 pub mod read_display_self_diagnostic_result {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplaySelfDiagnosticResult {
         pub(super) data: [u8; 1],
     }
     impl DisplaySelfDiagnosticResult {
-        pub fn read(&self) -> DisplaySelfDiagnosticResultRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplaySelfDiagnosticResult {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplaySelfDiagnosticResult { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplaySelfDiagnosticResultRead<'_> {
             DisplaySelfDiagnosticResultRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2026,6 +1491,12 @@ pub mod read_display_self_diagnostic_result {
         d: &'l mut DisplaySelfDiagnosticResult,
     }
     impl<'l> DisplaySelfDiagnosticResultWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplaySelfDiagnosticResult) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// d
         #[inline(always)]
         pub fn d(self, w: u8) -> Self {
@@ -2037,9 +1508,15 @@ pub mod read_display_self_diagnostic_result {
     }
     impl Default for DisplaySelfDiagnosticResult {
         fn default() -> Self {
-            DisplaySelfDiagnosticResult {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplaySelfDiagnosticResult {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplaySelfDiagnosticResult")
+                .field("d", &r.d())
+                .finish()
         }
     }
 }
@@ -2047,12 +1524,30 @@ pub mod gamma {
     enum_with_from! {
         CurveSelected(u8) => { GammaCurve1G2o2 = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct GammaSet {
         pub(super) data: [u8; 1],
     }
     impl GammaSet {
-        pub fn read(&self) -> GammaSetRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            GammaSet {
+                data: [ 0x01 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            GammaSet { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> GammaSetRead<'_> {
             GammaSetRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2077,6 +1572,12 @@ pub mod gamma {
         d: &'l mut GammaSet,
     }
     impl<'l> GammaSetWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: GammaSet) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// curve_selected
         #[inline(always)]
         pub fn curve_selected(self, w: CurveSelected) -> Self {
@@ -2088,19 +1589,43 @@ pub mod gamma {
     }
     impl Default for GammaSet {
         fn default() -> Self {
-            GammaSet {
-                data: [ 0x01 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for GammaSet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("GammaSet")
+                .field("curve_selected", &super::Field(r.curve_selected()))
+                .finish()
         }
     }
 }
 pub mod column_address {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct ColumnAddressSet {
         pub(super) data: [u8; 4],
     }
     impl ColumnAddressSet {
-        pub fn read(&self) -> ColumnAddressSetRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            ColumnAddressSet {
+                data: [ 0x00, 0x00, 0x00, 0xEF ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            ColumnAddressSet { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> ColumnAddressSetRead<'_> {
             ColumnAddressSetRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2130,6 +1655,12 @@ pub mod column_address {
         d: &'l mut ColumnAddressSet,
     }
     impl<'l> ColumnAddressSetWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: ColumnAddressSet) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// sc
         #[inline(always)]
         pub fn sc(self, w: u16) -> Self {
@@ -2148,19 +1679,44 @@ pub mod column_address {
     }
     impl Default for ColumnAddressSet {
         fn default() -> Self {
-            ColumnAddressSet {
-                data: [ 0x00, 0x00, 0x00, 0xEF ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for ColumnAddressSet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("ColumnAddressSet")
+                .field("sc", &r.sc())
+                .field("ec", &r.ec())
+                .finish()
         }
     }
 }
 pub mod page_address {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct PageAddressSet {
         pub(super) data: [u8; 4],
     }
     impl PageAddressSet {
-        pub fn read(&self) -> PageAddressSetRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            PageAddressSet {
+                data: [ 0x00, 0x00, 0x01, 0x3F ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            PageAddressSet { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> PageAddressSetRead<'_> {
             PageAddressSetRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2190,6 +1746,12 @@ pub mod page_address {
         d: &'l mut PageAddressSet,
     }
     impl<'l> PageAddressSetWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PageAddressSet) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// sp
         #[inline(always)]
         pub fn sp(self, w: u16) -> Self {
@@ -2208,19 +1770,44 @@ pub mod page_address {
     }
     impl Default for PageAddressSet {
         fn default() -> Self {
-            PageAddressSet {
-                data: [ 0x00, 0x00, 0x01, 0x3F ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PageAddressSet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PageAddressSet")
+                .field("sp", &r.sp())
+                .field("ep", &r.ep())
+                .finish()
         }
     }
 }
 pub mod color {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct ColorSet {
         pub(super) data: [u8; 128],
     }
     impl ColorSet {
-        pub fn read(&self) -> ColorSetRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            ColorSet {
+                data: [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 128]) -> Self {
+            ColorSet { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 128] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 128] {
+            self.data
+        }
+        pub fn read(&self) -> ColorSetRead<'_> {
             ColorSetRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2258,6 +1845,12 @@ pub mod color {
         d: &'l mut ColorSet,
     }
     impl<'l> ColorSetWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: ColorSet) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// r
         #[inline(always)]
         pub fn r(self, w: &'l[u8]) -> Self {
@@ -2280,19 +1873,45 @@ pub mod color {
     }
     impl Default for ColorSet {
         fn default() -> Self {
-            ColorSet {
-                data: [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for ColorSet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("ColorSet")
+                .field("r", &r.r())
+                .field("g", &r.g())
+                .field("b", &r.b())
+                .finish()
         }
     }
 }
 pub mod partial_area {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct PartialArea {
         pub(super) data: [u8; 4],
     }
     impl PartialArea {
-        pub fn read(&self) -> PartialAreaRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            PartialArea {
+                data: [ 0x00, 0x00, 0x01, 0x3F ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            PartialArea { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> PartialAreaRead<'_> {
             PartialAreaRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2322,6 +1941,12 @@ pub mod partial_area {
         d: &'l mut PartialArea,
     }
     impl<'l> PartialAreaWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PartialArea) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// sr
         #[inline(always)]
         pub fn sr(self, w: u16) -> Self {
@@ -2340,19 +1965,44 @@ pub mod partial_area {
     }
     impl Default for PartialArea {
         fn default() -> Self {
-            PartialArea {
-                data: [ 0x00, 0x00, 0x01, 0x3F ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PartialArea {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PartialArea")
+                .field("sr", &r.sr())
+                .field("er", &r.er())
+                .finish()
         }
     }
 }
 pub mod vertical_scrolling {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct VerticalScrollingDefinition {
         pub(super) data: [u8; 6],
     }
     impl VerticalScrollingDefinition {
-        pub fn read(&self) -> VerticalScrollingDefinitionRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            VerticalScrollingDefinition {
+                data: [ 0x00, 0x00, 0x01, 0x40, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 6]) -> Self {
+            VerticalScrollingDefinition { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 6] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 6] {
+            self.data
+        }
+        pub fn read(&self) -> VerticalScrollingDefinitionRead<'_> {
             VerticalScrollingDefinitionRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2387,6 +2037,12 @@ pub mod vertical_scrolling {
         d: &'l mut VerticalScrollingDefinition,
     }
     impl<'l> VerticalScrollingDefinitionWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: VerticalScrollingDefinition) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// tfa
         #[inline(always)]
         pub fn tfa(self, w: u16) -> Self {
@@ -2412,19 +2068,45 @@ pub mod vertical_scrolling {
     }
     impl Default for VerticalScrollingDefinition {
         fn default() -> Self {
-            VerticalScrollingDefinition {
-                data: [ 0x00, 0x00, 0x01, 0x40, 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for VerticalScrollingDefinition {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("VerticalScrollingDefinition")
+                .field("tfa", &r.tfa())
+                .field("vsa", &r.vsa())
+                .field("bfa", &r.bfa())
+                .finish()
         }
     }
 }
 pub mod tearing_effect_line_on {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct TearingEffectLineOn {
         pub(super) data: [u8; 1],
     }
     impl TearingEffectLineOn {
-        pub fn read(&self) -> TearingEffectLineOnRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            TearingEffectLineOn {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            TearingEffectLineOn { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> TearingEffectLineOnRead<'_> {
             TearingEffectLineOnRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2449,6 +2131,12 @@ pub mod tearing_effect_line_on {
         d: &'l mut TearingEffectLineOn,
     }
     impl<'l> TearingEffectLineOnWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: TearingEffectLineOn) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// m
         #[inline(always)]
         pub fn m(self, w: bool) -> Self {
@@ -2460,19 +2148,43 @@ pub mod tearing_effect_line_on {
     }
     impl Default for TearingEffectLineOn {
         fn default() -> Self {
-            TearingEffectLineOn {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for TearingEffectLineOn {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("TearingEffectLineOn")
+                .field("m", &r.m())
+                .finish()
         }
     }
 }
 pub mod memory_access_control {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct MemoryAccessControl {
         pub(super) data: [u8; 1],
     }
     impl MemoryAccessControl {
-        pub fn read(&self) -> MemoryAccessControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            MemoryAccessControl {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            MemoryAccessControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> MemoryAccessControlRead<'_> {
             MemoryAccessControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2522,6 +2234,12 @@ pub mod memory_access_control {
         d: &'l mut MemoryAccessControl,
     }
     impl<'l> MemoryAccessControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: MemoryAccessControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// row_address_order
         #[inline(always)]
         pub fn row_address_order(self, w: bool) -> Self {
@@ -2568,19 +2286,48 @@ pub mod memory_access_control {
     }
     impl Default for MemoryAccessControl {
         fn default() -> Self {
-            MemoryAccessControl {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for MemoryAccessControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("MemoryAccessControl")
+                .field("row_address_order", &r.row_address_order())
+                .field("column_address_order", &r.column_address_order())
+                .field("row_column_exchange", &r.row_column_exchange())
+                .field("vertical_refresh_order", &r.vertical_refresh_order())
+                .field("rgb_bgr_order", &r.rgb_bgr_order())
+                .field("horizontal_refresh_order", &r.horizontal_refresh_order())
+                .finish()
         }
     }
 }
 pub mod vertical_scrolling_start_address {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct VerticalScrollingStartAddress {
         pub(super) data: [u8; 2],
     }
     impl VerticalScrollingStartAddress {
-        pub fn read(&self) -> VerticalScrollingStartAddressRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            VerticalScrollingStartAddress {
+                data: [ 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            VerticalScrollingStartAddress { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> VerticalScrollingStartAddressRead<'_> {
             VerticalScrollingStartAddressRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2605,6 +2352,12 @@ pub mod vertical_scrolling_start_address {
         d: &'l mut VerticalScrollingStartAddress,
     }
     impl<'l> VerticalScrollingStartAddressWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: VerticalScrollingStartAddress) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// vsp
         #[inline(always)]
         pub fn vsp(self, w: u16) -> Self {
@@ -2616,9 +2369,15 @@ pub mod vertical_scrolling_start_address {
     }
     impl Default for VerticalScrollingStartAddress {
         fn default() -> Self {
-            VerticalScrollingStartAddress {
-                data: [ 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for VerticalScrollingStartAddress {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("VerticalScrollingStartAddress")
+                .field("vsp", &r.vsp())
+                .finish()
         }
     }
 }
@@ -2627,12 +2386,30 @@ pub mod pixel_format {
         RgbInterfaceFormat(u8) => { N16Bits = 0x05, N18Bits = 0x06 },
         McuInterfaceFormat(u8) => { N16Bits = 0x05, N18Bits = 0x06 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct PixelFormatSet {
         pub(super) data: [u8; 1],
     }
     impl PixelFormatSet {
-        pub fn read(&self) -> PixelFormatSetRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            PixelFormatSet {
+                data: [ 0x66 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            PixelFormatSet { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> PixelFormatSetRead<'_> {
             PixelFormatSetRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2662,6 +2439,12 @@ pub mod pixel_format {
         d: &'l mut PixelFormatSet,
     }
     impl<'l> PixelFormatSetWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PixelFormatSet) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// rgb_interface_format
         #[inline(always)]
         pub fn rgb_interface_format(self, w: RgbInterfaceFormat) -> Self {
@@ -2682,19 +2465,44 @@ pub mod pixel_format {
     }
     impl Default for PixelFormatSet {
         fn default() -> Self {
-            PixelFormatSet {
-                data: [ 0x66 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PixelFormatSet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PixelFormatSet")
+                .field("rgb_interface_format", &super::Field(r.rgb_interface_format()))
+                .field("mcu_interface_format", &super::Field(r.mcu_interface_format()))
+                .finish()
         }
     }
 }
 pub mod tear_scanline {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct SetTearScanline {
         pub(super) data: [u8; 2],
     }
     impl SetTearScanline {
-        pub fn read(&self) -> SetTearScanlineRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            SetTearScanline {
+                data: [ 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            SetTearScanline { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> SetTearScanlineRead<'_> {
             SetTearScanlineRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2719,6 +2527,12 @@ pub mod tear_scanline {
         d: &'l mut SetTearScanline,
     }
     impl<'l> SetTearScanlineWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: SetTearScanline) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// sts
         #[inline(always)]
         pub fn sts(self, w: u16) -> Self {
@@ -2731,19 +2545,43 @@ pub mod tear_scanline {
     }
     impl Default for SetTearScanline {
         fn default() -> Self {
-            SetTearScanline {
-                data: [ 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for SetTearScanline {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("SetTearScanline")
+                .field("sts", &r.sts())
+                .finish()
         }
     }
 }
 pub mod get_scanline {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct GetScanline {
         pub(super) data: [u8; 2],
     }
     impl GetScanline {
-        pub fn read(&self) -> GetScanlineRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            GetScanline {
+                data: [ 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            GetScanline { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> GetScanlineRead<'_> {
             GetScanlineRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2768,6 +2606,12 @@ pub mod get_scanline {
         d: &'l mut GetScanline,
     }
     impl<'l> GetScanlineWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: GetScanline) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// gts
         #[inline(always)]
         pub fn gts(self, w: u16) -> Self {
@@ -2780,19 +2624,43 @@ pub mod get_scanline {
     }
     impl Default for GetScanline {
         fn default() -> Self {
-            GetScanline {
-                data: [ 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for GetScanline {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("GetScanline")
+                .field("gts", &r.gts())
+                .finish()
         }
     }
 }
 pub mod write_display_brightness {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayBrightness {
         pub(super) data: [u8; 1],
     }
     impl DisplayBrightness {
-        pub fn read(&self) -> DisplayBrightnessRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayBrightness {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayBrightness { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayBrightnessRead<'_> {
             DisplayBrightnessRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2817,6 +2685,12 @@ pub mod write_display_brightness {
         d: &'l mut DisplayBrightness,
     }
     impl<'l> DisplayBrightnessWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayBrightness) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// dbv
         #[inline(always)]
         pub fn dbv(self, w: u8) -> Self {
@@ -2827,19 +2701,43 @@ pub mod write_display_brightness {
     }
     impl Default for DisplayBrightness {
         fn default() -> Self {
-            DisplayBrightness {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayBrightness {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayBrightness")
+                .field("dbv", &r.dbv())
+                .finish()
         }
     }
 }
 pub mod read_display_brightness {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayBrightness {
         pub(super) data: [u8; 1],
     }
     impl DisplayBrightness {
-        pub fn read(&self) -> DisplayBrightnessRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayBrightness {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayBrightness { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayBrightnessRead<'_> {
             DisplayBrightnessRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2864,6 +2762,12 @@ pub mod read_display_brightness {
         d: &'l mut DisplayBrightness,
     }
     impl<'l> DisplayBrightnessWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayBrightness) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// dbv
         #[inline(always)]
         pub fn dbv(self, w: u8) -> Self {
@@ -2874,19 +2778,43 @@ pub mod read_display_brightness {
     }
     impl Default for DisplayBrightness {
         fn default() -> Self {
-            DisplayBrightness {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayBrightness {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayBrightness")
+                .field("dbv", &r.dbv())
+                .finish()
         }
     }
 }
 pub mod write_ctrl_display {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct CtrlDisplay {
         pub(super) data: [u8; 1],
     }
     impl CtrlDisplay {
-        pub fn read(&self) -> CtrlDisplayRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            CtrlDisplay {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            CtrlDisplay { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> CtrlDisplayRead<'_> {
             CtrlDisplayRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2921,6 +2849,12 @@ pub mod write_ctrl_display {
         d: &'l mut CtrlDisplay,
     }
     impl<'l> CtrlDisplayWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: CtrlDisplay) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// brightness_control_block
         #[inline(always)]
         pub fn brightness_control_block(self, w: bool) -> Self {
@@ -2946,19 +2880,45 @@ pub mod write_ctrl_display {
     }
     impl Default for CtrlDisplay {
         fn default() -> Self {
-            CtrlDisplay {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for CtrlDisplay {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("CtrlDisplay")
+                .field("brightness_control_block", &r.brightness_control_block())
+                .field("display_dimming", &r.display_dimming())
+                .field("backlight_control", &r.backlight_control())
+                .finish()
         }
     }
 }
 pub mod read_ctrl_display {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct CtrlDisplay {
         pub(super) data: [u8; 1],
     }
     impl CtrlDisplay {
-        pub fn read(&self) -> CtrlDisplayRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            CtrlDisplay {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            CtrlDisplay { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> CtrlDisplayRead<'_> {
             CtrlDisplayRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -2993,6 +2953,12 @@ pub mod read_ctrl_display {
         d: &'l mut CtrlDisplay,
     }
     impl<'l> CtrlDisplayWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: CtrlDisplay) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// brightness_control_block
         #[inline(always)]
         pub fn brightness_control_block(self, w: bool) -> Self {
@@ -3018,9 +2984,17 @@ pub mod read_ctrl_display {
     }
     impl Default for CtrlDisplay {
         fn default() -> Self {
-            CtrlDisplay {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for CtrlDisplay {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("CtrlDisplay")
+                .field("brightness_control_block", &r.brightness_control_block())
+                .field("display_dimming", &r.display_dimming())
+                .field("backlight", &r.backlight())
+                .finish()
         }
     }
 }
@@ -3028,12 +3002,30 @@ pub mod write_content_adaptive_brightness_control {
     enum_with_from! {
         AdaptiveBrightnessControlMode(u8) => { Off = 0x00, UserInterfaceImage = 0x01, StillPicture = 0x02, MovingImage = 0x03 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct ContentAdaptiveBrightnessControl {
         pub(super) data: [u8; 1],
     }
     impl ContentAdaptiveBrightnessControl {
-        pub fn read(&self) -> ContentAdaptiveBrightnessControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            ContentAdaptiveBrightnessControl {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            ContentAdaptiveBrightnessControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> ContentAdaptiveBrightnessControlRead<'_> {
             ContentAdaptiveBrightnessControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3058,6 +3050,12 @@ pub mod write_content_adaptive_brightness_control {
         d: &'l mut ContentAdaptiveBrightnessControl,
     }
     impl<'l> ContentAdaptiveBrightnessControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: ContentAdaptiveBrightnessControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// adaptive_brightness_control_mode
         #[inline(always)]
         pub fn adaptive_brightness_control_mode(self, w: AdaptiveBrightnessControlMode) -> Self {
@@ -3070,9 +3068,15 @@ pub mod write_content_adaptive_brightness_control {
     }
     impl Default for ContentAdaptiveBrightnessControl {
         fn default() -> Self {
-            ContentAdaptiveBrightnessControl {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for ContentAdaptiveBrightnessControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("ContentAdaptiveBrightnessControl")
+                .field("adaptive_brightness_control_mode", &super::Field(r.adaptive_brightness_control_mode()))
+                .finish()
         }
     }
 }
@@ -3080,12 +3084,30 @@ pub mod read_content_adaptive_brightness_control {
     enum_with_from! {
         AdaptiveBrightnessControlMode(u8) => { Off = 0x00, UserInterfaceImage = 0x01, StillPicture = 0x02, MovingImage = 0x03 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct ContentAdaptiveBrightnessControl {
         pub(super) data: [u8; 1],
     }
     impl ContentAdaptiveBrightnessControl {
-        pub fn read(&self) -> ContentAdaptiveBrightnessControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            ContentAdaptiveBrightnessControl {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            ContentAdaptiveBrightnessControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> ContentAdaptiveBrightnessControlRead<'_> {
             ContentAdaptiveBrightnessControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3110,6 +3132,12 @@ pub mod read_content_adaptive_brightness_control {
         d: &'l mut ContentAdaptiveBrightnessControl,
     }
     impl<'l> ContentAdaptiveBrightnessControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: ContentAdaptiveBrightnessControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// adaptive_brightness_control_mode
         #[inline(always)]
         pub fn adaptive_brightness_control_mode(self, w: AdaptiveBrightnessControlMode) -> Self {
@@ -3122,19 +3150,43 @@ pub mod read_content_adaptive_brightness_control {
     }
     impl Default for ContentAdaptiveBrightnessControl {
         fn default() -> Self {
-            ContentAdaptiveBrightnessControl {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for ContentAdaptiveBrightnessControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("ContentAdaptiveBrightnessControl")
+                .field("adaptive_brightness_control_mode", &super::Field(r.adaptive_brightness_control_mode()))
+                .finish()
         }
     }
 }
 pub mod write_cabc_minimum_brightness {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct CabcMinimumBrightness {
         pub(super) data: [u8; 1],
     }
     impl CabcMinimumBrightness {
-        pub fn read(&self) -> CabcMinimumBrightnessRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            CabcMinimumBrightness {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            CabcMinimumBrightness { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> CabcMinimumBrightnessRead<'_> {
             CabcMinimumBrightnessRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3159,6 +3211,12 @@ pub mod write_cabc_minimum_brightness {
         d: &'l mut CabcMinimumBrightness,
     }
     impl<'l> CabcMinimumBrightnessWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: CabcMinimumBrightness) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// cabc_minimum_brightness
         #[inline(always)]
         pub fn cabc_minimum_brightness(self, w: u8) -> Self {
@@ -3169,19 +3227,43 @@ pub mod write_cabc_minimum_brightness {
     }
     impl Default for CabcMinimumBrightness {
         fn default() -> Self {
-            CabcMinimumBrightness {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for CabcMinimumBrightness {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("CabcMinimumBrightness")
+                .field("cabc_minimum_brightness", &r.cabc_minimum_brightness())
+                .finish()
         }
     }
 }
 pub mod read_cabc_minimum_brightness {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct CabcMinimumBrightness {
         pub(super) data: [u8; 1],
     }
     impl CabcMinimumBrightness {
-        pub fn read(&self) -> CabcMinimumBrightnessRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            CabcMinimumBrightness {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            CabcMinimumBrightness { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> CabcMinimumBrightnessRead<'_> {
             CabcMinimumBrightnessRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3206,6 +3288,12 @@ pub mod read_cabc_minimum_brightness {
         d: &'l mut CabcMinimumBrightness,
     }
     impl<'l> CabcMinimumBrightnessWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: CabcMinimumBrightness) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// cabc_minimum_brightness
         #[inline(always)]
         pub fn cabc_minimum_brightness(self, w: u8) -> Self {
@@ -3216,19 +3304,43 @@ pub mod read_cabc_minimum_brightness {
     }
     impl Default for CabcMinimumBrightness {
         fn default() -> Self {
-            CabcMinimumBrightness {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for CabcMinimumBrightness {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("CabcMinimumBrightness")
+                .field("cabc_minimum_brightness", &r.cabc_minimum_brightness())
+                .finish()
         }
     }
 }
 pub mod read_id1 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct Id1 {
         pub(super) data: [u8; 1],
     }
     impl Id1 {
-        pub fn read(&self) -> Id1Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            Id1 {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            Id1 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> Id1Read<'_> {
             Id1Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3253,7 +3365,13 @@ pub mod read_id1 {
         d: &'l mut Id1,
     }
     impl<'l> Id1Write<'l> {
-        /// id1
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: Id1) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// id1
         #[inline(always)]
         pub fn id1(self, w: u8) -> Self {
             self.d.data[0] = w;
@@ -3263,19 +3381,43 @@ pub mod read_id1 {
     }
     impl Default for Id1 {
         fn default() -> Self {
-            Id1 {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for Id1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("Id1")
+                .field("id1", &r.id1())
+                .finish()
         }
     }
 }
 pub mod read_id2 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct Id2 {
         pub(super) data: [u8; 1],
     }
     impl Id2 {
-        pub fn read(&self) -> Id2Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            Id2 {
+                data: [ 0x80 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            Id2 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> Id2Read<'_> {
             Id2Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3300,6 +3442,12 @@ pub mod read_id2 {
         d: &'l mut Id2,
     }
     impl<'l> Id2Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: Id2) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// id2
         #[inline(always)]
         pub fn id2(self, w: u8) -> Self {
@@ -3311,19 +3459,43 @@ pub mod read_id2 {
     }
     impl Default for Id2 {
         fn default() -> Self {
-            Id2 {
-                data: [ 0x80 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for Id2 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("Id2")
+                .field("id2", &r.id2())
+                .finish()
         }
     }
 }
 pub mod read_id3 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct Id3 {
         pub(super) data: [u8; 1],
     }
     impl Id3 {
-        pub fn read(&self) -> Id3Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            Id3 {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            Id3 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> Id3Read<'_> {
             Id3Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3348,6 +3520,12 @@ pub mod read_id3 {
         d: &'l mut Id3,
     }
     impl<'l> Id3Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: Id3) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// id3
         #[inline(always)]
         pub fn id3(self, w: u8) -> Self {
@@ -3358,9 +3536,15 @@ pub mod read_id3 {
     }
     impl Default for Id3 {
         fn default() -> Self {
-            Id3 {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for Id3 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("Id3")
+                .field("id3", &r.id3())
+                .finish()
         }
     }
 }
@@ -3369,12 +3553,30 @@ pub mod rgb_interface_signal_control {
     enum_with_from! {
         DisplayDataPath(u8) => { DirectToShiftRegisterDefault = 0x00, Memory = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct RgbInterfaceSignalControl {
         pub(super) data: [u8; 1],
     }
     impl RgbInterfaceSignalControl {
-        pub fn read(&self) -> RgbInterfaceSignalControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            RgbInterfaceSignalControl {
+                data: [ 0x41 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            RgbInterfaceSignalControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> RgbInterfaceSignalControlRead<'_> {
             RgbInterfaceSignalControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3424,6 +3626,12 @@ pub mod rgb_interface_signal_control {
         d: &'l mut RgbInterfaceSignalControl,
     }
     impl<'l> RgbInterfaceSignalControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: RgbInterfaceSignalControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// display_data_path
         #[inline(always)]
         pub fn display_data_path(self, w: DisplayDataPath) -> Self {
@@ -3471,23 +3679,53 @@ pub mod rgb_interface_signal_control {
     }
     impl Default for RgbInterfaceSignalControl {
         fn default() -> Self {
-            RgbInterfaceSignalControl {
-                data: [ 0x41 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for RgbInterfaceSignalControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("RgbInterfaceSignalControl")
+                .field("display_data_path", &super::Field(r.display_data_path()))
+                .field("rgb_interface_selection", &r.rgb_interface_selection())
+                .field("vsync_polarity", &r.vsync_polarity())
+                .field("hsync_polarity", &r.hsync_polarity())
+                .field("dotclk_polarity", &r.dotclk_polarity())
+                .field("de_polarity", &r.de_polarity())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
+#[allow(clippy::unnecessary_cast)] // offset fields are generated with a cast for every width
 pub mod frame_control_in_normal_mode {
     enum_with_from! {
         DivisionRatio(u8) => { Fosc = 0x00, FoscDiv2 = 0x01, FoscDiv4 = 0x02, FoscDiv8 = 0x03 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct FrameControlInNormalMode {
         pub(super) data: [u8; 2],
     }
     impl FrameControlInNormalMode {
-        pub fn read(&self) -> FrameControlInNormalModeRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            FrameControlInNormalMode {
+                data: [ 0x00, 0x1B ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            FrameControlInNormalMode { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> FrameControlInNormalModeRead<'_> {
             FrameControlInNormalModeRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3517,6 +3755,12 @@ pub mod frame_control_in_normal_mode {
         d: &'l mut FrameControlInNormalMode,
     }
     impl<'l> FrameControlInNormalModeWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: FrameControlInNormalMode) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// division_ratio
         #[inline(always)]
         pub fn division_ratio(self, w: DivisionRatio) -> Self {
@@ -3537,23 +3781,49 @@ pub mod frame_control_in_normal_mode {
     }
     impl Default for FrameControlInNormalMode {
         fn default() -> Self {
-            FrameControlInNormalMode {
-                data: [ 0x00, 0x1B ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for FrameControlInNormalMode {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("FrameControlInNormalMode")
+                .field("division_ratio", &super::Field(r.division_ratio()))
+                .field("clock_per_line", &r.clock_per_line())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
+#[allow(clippy::unnecessary_cast)] // offset fields are generated with a cast for every width
 pub mod frame_control_in_idle_mode {
     enum_with_from! {
         DivisionRatio(u8) => { Fosc = 0x00, FoscDiv2 = 0x01, FoscDiv4 = 0x02, FoscDiv8 = 0x03 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct FrameControlInIdleMode {
         pub(super) data: [u8; 2],
     }
     impl FrameControlInIdleMode {
-        pub fn read(&self) -> FrameControlInIdleModeRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            FrameControlInIdleMode {
+                data: [ 0x00, 0x1B ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            FrameControlInIdleMode { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> FrameControlInIdleModeRead<'_> {
             FrameControlInIdleModeRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3583,6 +3853,12 @@ pub mod frame_control_in_idle_mode {
         d: &'l mut FrameControlInIdleMode,
     }
     impl<'l> FrameControlInIdleModeWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: FrameControlInIdleMode) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// division_ratio
         #[inline(always)]
         pub fn division_ratio(self, w: DivisionRatio) -> Self {
@@ -3603,23 +3879,49 @@ pub mod frame_control_in_idle_mode {
     }
     impl Default for FrameControlInIdleMode {
         fn default() -> Self {
-            FrameControlInIdleMode {
-                data: [ 0x00, 0x1B ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for FrameControlInIdleMode {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("FrameControlInIdleMode")
+                .field("division_ratio", &super::Field(r.division_ratio()))
+                .field("clock_per_line", &r.clock_per_line())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
+#[allow(clippy::unnecessary_cast)] // offset fields are generated with a cast for every width
 pub mod frame_control_in_partial_mode {
     enum_with_from! {
         DivisionRatio(u8) => { Fosc = 0x00, FoscDiv2 = 0x01, FoscDiv4 = 0x02, FoscDiv8 = 0x03 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct FrameControlInPartialMode {
         pub(super) data: [u8; 2],
     }
     impl FrameControlInPartialMode {
-        pub fn read(&self) -> FrameControlInPartialModeRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            FrameControlInPartialMode {
+                data: [ 0x00, 0x1B ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            FrameControlInPartialMode { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> FrameControlInPartialModeRead<'_> {
             FrameControlInPartialModeRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3649,6 +3951,12 @@ pub mod frame_control_in_partial_mode {
         d: &'l mut FrameControlInPartialMode,
     }
     impl<'l> FrameControlInPartialModeWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: FrameControlInPartialMode) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// division_ratio
         #[inline(always)]
         pub fn division_ratio(self, w: DivisionRatio) -> Self {
@@ -3669,9 +3977,16 @@ pub mod frame_control_in_partial_mode {
     }
     impl Default for FrameControlInPartialMode {
         fn default() -> Self {
-            FrameControlInPartialMode {
-                data: [ 0x00, 0x1B ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for FrameControlInPartialMode {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("FrameControlInPartialMode")
+                .field("division_ratio", &super::Field(r.division_ratio()))
+                .field("clock_per_line", &r.clock_per_line())
+                .finish()
         }
     }
 }
@@ -3682,12 +3997,30 @@ pub mod display_inversion_control {
         InversionSettingInIdleMode(u8) => { LineInversion = 0x00, FrameInversion = 0x01 },
         InversionSettingInFullColorsPartialMode(u8) => { LineInversion = 0x00, FrameInversion = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayInversionControl {
         pub(super) data: [u8; 1],
     }
     impl DisplayInversionControl {
-        pub fn read(&self) -> DisplayInversionControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayInversionControl {
+                data: [ 0x02 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            DisplayInversionControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayInversionControlRead<'_> {
             DisplayInversionControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3722,6 +4055,12 @@ pub mod display_inversion_control {
         d: &'l mut DisplayInversionControl,
     }
     impl<'l> DisplayInversionControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayInversionControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// inversion_setting_in_full_colors_normal_mode
         #[inline(always)]
         pub fn inversion_setting_in_full_colors_normal_mode(self, w: InversionSettingInFullColorsNormalMode) -> Self {
@@ -3750,20 +4089,47 @@ pub mod display_inversion_control {
     }
     impl Default for DisplayInversionControl {
         fn default() -> Self {
-            DisplayInversionControl {
-                data: [ 0x02 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayInversionControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayInversionControl")
+                .field("inversion_setting_in_full_colors_normal_mode", &super::Field(r.inversion_setting_in_full_colors_normal_mode()))
+                .field("inversion_setting_in_idle_mode", &super::Field(r.inversion_setting_in_idle_mode()))
+                .field("inversion_setting_in_full_colors_partial_mode", &super::Field(r.inversion_setting_in_full_colors_partial_mode()))
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
+#[allow(clippy::unnecessary_cast)] // offset fields are generated with a cast for every width
 pub mod blanking_porch_control {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BlankingPorchControl {
         pub(super) data: [u8; 4],
     }
     impl BlankingPorchControl {
-        pub fn read(&self) -> BlankingPorchControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            BlankingPorchControl {
+                data: [ 0x02, 0x02, 0x0A, 0x14 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            BlankingPorchControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> BlankingPorchControlRead<'_> {
             BlankingPorchControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3803,6 +4169,12 @@ pub mod blanking_porch_control {
         d: &'l mut BlankingPorchControl,
     }
     impl<'l> BlankingPorchControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BlankingPorchControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// vfp
         #[inline(always)]
         pub fn vfp(self, w: u8) -> Self {
@@ -3839,9 +4211,18 @@ pub mod blanking_porch_control {
     }
     impl Default for BlankingPorchControl {
         fn default() -> Self {
-            BlankingPorchControl {
-                data: [ 0x02, 0x02, 0x0A, 0x14 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BlankingPorchControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BlankingPorchControl")
+                .field("vfp", &r.vfp())
+                .field("vbp", &r.vbp())
+                .field("hfp", &r.hfp())
+                .field("hbp", &r.hbp())
+                .finish()
         }
     }
 }
@@ -3855,12 +4236,30 @@ pub mod display_function_control {
         ScanCycle(u8) => { N1Frame = 0x00, N3Frames = 0x01, N5Frames = 0x02, N7Frames = 0x03, N9Frames = 0x04, N11Frames = 0x05, N13Frames = 0x06, N15Frames = 0x07, N17Frames = 0x08, N19Frames = 0x09, N21Frames = 0x0A, N23Frames = 0x0B, N25Frames = 0x0C, N27Frames = 0x0D, N29Frames = 0x0E, N31Frames = 0x0F },
         LcdDriverLine(u8) => { N16Lines = 0x01, N24Lines = 0x02, N32Lines = 0x03, N40Lines = 0x04, N48Lines = 0x05, N56Lines = 0x06, N64Lines = 0x07, N72Lines = 0x08, N80Lines = 0x09, N88Lines = 0x0A, N96Lines = 0x0B, N104Lines = 0x0C, N112Lines = 0x0D, N120Lines = 0x0E, N128Lines = 0x0F, N136Lines = 0x10, N144Lines = 0x11, N152Lines = 0x12, N160Lines = 0x13, N168Lines = 0x14, N176Lines = 0x15, N184Lines = 0x16, N192Lines = 0x17, N200Lines = 0x18, N208Lines = 0x19, N216Lines = 0x1A, N224Lines = 0x1B, N232Lines = 0x1C, N240Lines = 0x1D, N248Lines = 0x1E, N256Lines = 0x1F, N264Lines = 0x20, N272Lines = 0x21, N280Lines = 0x22, N288Lines = 0x23, N296Lines = 0x24, N304Lines = 0x25, N312Lines = 0x26, N320Lines = 0x27 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DisplayFunctionControl {
         pub(super) data: [u8; 4],
     }
     impl DisplayFunctionControl {
-        pub fn read(&self) -> DisplayFunctionControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            DisplayFunctionControl {
+                data: [ 0x0A, 0x82, 0x27, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            DisplayFunctionControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> DisplayFunctionControlRead<'_> {
             DisplayFunctionControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -3925,6 +4324,12 @@ pub mod display_function_control {
         d: &'l mut DisplayFunctionControl,
     }
     impl<'l> DisplayFunctionControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DisplayFunctionControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// gate_outputs_in_non_display_area
         #[inline(always)]
         pub fn gate_outputs_in_non_display_area(self, w: GateOutputsInNonDisplayArea) -> Self {
@@ -3998,9 +4403,23 @@ pub mod display_function_control {
     }
     impl Default for DisplayFunctionControl {
         fn default() -> Self {
-            DisplayFunctionControl {
-                data: [ 0x0A, 0x82, 0x27, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DisplayFunctionControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DisplayFunctionControl")
+                .field("gate_outputs_in_non_display_area", &super::Field(r.gate_outputs_in_non_display_area()))
+                .field("determine_source_and_vcom_output_in_an_on_display_area_in_the_partial_display_mode", &r.determine_source_and_vcom_output_in_an_on_display_area_in_the_partial_display_mode())
+                .field("liquid_crystal_type", &super::Field(r.liquid_crystal_type()))
+                .field("gate_output_scan_direction", &super::Field(r.gate_output_scan_direction()))
+                .field("source_output_scan_direction", &super::Field(r.source_output_scan_direction()))
+                .field("sm", &r.sm())
+                .field("scan_cycle", &super::Field(r.scan_cycle()))
+                .field("lcd_driver_line", &super::Field(r.lcd_driver_line()))
+                .field("pcdiv", &r.pcdiv())
+                .finish()
         }
     }
 }
@@ -4010,12 +4429,30 @@ pub mod entry_mode {
         G1G320GateOutput(u8) => { Vgh = 0x01, Vgl = 0x02, NormalDisplay = 0x03 },
         LowVoltageDetection(u8) => { Enable = 0x00, Disable = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct EntryModeSet {
         pub(super) data: [u8; 1],
     }
     impl EntryModeSet {
-        pub fn read(&self) -> EntryModeSetRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            EntryModeSet {
+                data: [ 0x06 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            EntryModeSet { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> EntryModeSetRead<'_> {
             EntryModeSetRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4050,6 +4487,12 @@ pub mod entry_mode {
         d: &'l mut EntryModeSet,
     }
     impl<'l> EntryModeSetWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: EntryModeSet) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// deep_standby_mode
         #[inline(always)]
         pub fn deep_standby_mode(self, w: bool) -> Self {
@@ -4077,9 +4520,17 @@ pub mod entry_mode {
     }
     impl Default for EntryModeSet {
         fn default() -> Self {
-            EntryModeSet {
-                data: [ 0x06 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for EntryModeSet {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("EntryModeSet")
+                .field("deep_standby_mode", &r.deep_standby_mode())
+                .field("g1_g320_gate_output", &super::Field(r.g1_g320_gate_output()))
+                .field("low_voltage_detection", &super::Field(r.low_voltage_detection()))
+                .finish()
         }
     }
 }
@@ -4088,12 +4539,30 @@ pub mod backlight_control1 {
     enum_with_from! {
         HistogramThresholdInUserInterfaceMode(u8) => { N99 = 0x00, N98 = 0x01, N96 = 0x02, N94 = 0x03, N92 = 0x04, N90 = 0x05, N88 = 0x06, N86 = 0x07, N84 = 0x08, N82 = 0x09, N80 = 0x0A, N78 = 0x0B, N76 = 0x0C, N74 = 0x0D, N72 = 0x0E, N70 = 0x0F },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl1 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl1 {
-        pub fn read(&self) -> BacklightControl1Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl1 {
+                data: [ 0x06 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl1 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl1Read<'_> {
             BacklightControl1Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4118,6 +4587,12 @@ pub mod backlight_control1 {
         d: &'l mut BacklightControl1,
     }
     impl<'l> BacklightControl1Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl1) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// histogram_threshold_in_user_interface_mode
         #[inline(always)]
         pub fn histogram_threshold_in_user_interface_mode(self, w: HistogramThresholdInUserInterfaceMode) -> Self {
@@ -4130,9 +4605,15 @@ pub mod backlight_control1 {
     }
     impl Default for BacklightControl1 {
         fn default() -> Self {
-            BacklightControl1 {
-                data: [ 0x06 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl1")
+                .field("histogram_threshold_in_user_interface_mode", &super::Field(r.histogram_threshold_in_user_interface_mode()))
+                .finish()
         }
     }
 }
@@ -4142,12 +4623,30 @@ pub mod backlight_control2 {
         HistogramThresholdInMovingImageMode(u8) => { N99 = 0x00, N98 = 0x01, N96 = 0x02, N94 = 0x03, N92 = 0x04, N90 = 0x05, N88 = 0x06, N86 = 0x07, N84 = 0x08, N82 = 0x09, N80 = 0x0A, N78 = 0x0B, N76 = 0x0C, N74 = 0x0D, N72 = 0x0E, N70 = 0x0F },
         HistogramThresholdInStillPictureMode(u8) => { N99 = 0x00, N98 = 0x01, N96 = 0x02, N94 = 0x03, N92 = 0x04, N90 = 0x05, N88 = 0x06, N86 = 0x07, N84 = 0x08, N82 = 0x09, N80 = 0x0A, N78 = 0x0B, N76 = 0x0C, N74 = 0x0D, N72 = 0x0E, N70 = 0x0F },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl2 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl2 {
-        pub fn read(&self) -> BacklightControl2Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl2 {
+                data: [ 0xCC ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl2 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl2Read<'_> {
             BacklightControl2Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4177,6 +4676,12 @@ pub mod backlight_control2 {
         d: &'l mut BacklightControl2,
     }
     impl<'l> BacklightControl2Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl2) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// histogram_threshold_in_moving_image_mode
         #[inline(always)]
         pub fn histogram_threshold_in_moving_image_mode(self, w: HistogramThresholdInMovingImageMode) -> Self {
@@ -4197,9 +4702,16 @@ pub mod backlight_control2 {
     }
     impl Default for BacklightControl2 {
         fn default() -> Self {
-            BacklightControl2 {
-                data: [ 0xCC ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl2 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl2")
+                .field("histogram_threshold_in_moving_image_mode", &super::Field(r.histogram_threshold_in_moving_image_mode()))
+                .field("histogram_threshold_in_still_picture_mode", &super::Field(r.histogram_threshold_in_still_picture_mode()))
+                .finish()
         }
     }
 }
@@ -4208,12 +4720,30 @@ pub mod backlight_control3 {
     enum_with_from! {
         PixelThresholdInUserInterfaceMode(u8) => { N252 = 0x00, N248 = 0x01, N244 = 0x02, N240 = 0x03, N236 = 0x04, N232 = 0x05, N228 = 0x06, N224 = 0x07, N220 = 0x08, N216 = 0x09, N212 = 0x0A, N208 = 0x0B, N204 = 0x0C, N200 = 0x0D, N196 = 0x0E, N192 = 0x0F },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl3 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl3 {
-        pub fn read(&self) -> BacklightControl3Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl3 {
+                data: [ 0x04 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl3 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl3Read<'_> {
             BacklightControl3Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4238,6 +4768,12 @@ pub mod backlight_control3 {
         d: &'l mut BacklightControl3,
     }
     impl<'l> BacklightControl3Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl3) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// pixel_threshold_in_user_interface_mode
         #[inline(always)]
         pub fn pixel_threshold_in_user_interface_mode(self, w: PixelThresholdInUserInterfaceMode) -> Self {
@@ -4250,9 +4786,15 @@ pub mod backlight_control3 {
     }
     impl Default for BacklightControl3 {
         fn default() -> Self {
-            BacklightControl3 {
-                data: [ 0x04 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl3 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl3")
+                .field("pixel_threshold_in_user_interface_mode", &super::Field(r.pixel_threshold_in_user_interface_mode()))
+                .finish()
         }
     }
 }
@@ -4262,12 +4804,30 @@ pub mod backlight_control4 {
         PixelThresholdInMovingImageMode(u8) => { N224 = 0x00, N220 = 0x01, N216 = 0x02, N212 = 0x03, N208 = 0x04, N204 = 0x05, N200 = 0x06, N196 = 0x07, N192 = 0x08, N188 = 0x09, N184 = 0x0A, N180 = 0x0B, N176 = 0x0C, N172 = 0x0D, N168 = 0x0E, N164 = 0x0F },
         PixelThresholdInStillPictureMode(u8) => { N224 = 0x00, N220 = 0x01, N216 = 0x02, N212 = 0x03, N208 = 0x04, N204 = 0x05, N200 = 0x06, N196 = 0x07, N192 = 0x08, N188 = 0x09, N184 = 0x0A, N180 = 0x0B, N176 = 0x0C, N172 = 0x0D, N168 = 0x0E, N164 = 0x0F },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl4 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl4 {
-        pub fn read(&self) -> BacklightControl4Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl4 {
+                data: [ 0x65 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl4 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl4Read<'_> {
             BacklightControl4Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4297,6 +4857,12 @@ pub mod backlight_control4 {
         d: &'l mut BacklightControl4,
     }
     impl<'l> BacklightControl4Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl4) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// pixel_threshold_in_moving_image_mode
         #[inline(always)]
         pub fn pixel_threshold_in_moving_image_mode(self, w: PixelThresholdInMovingImageMode) -> Self {
@@ -4317,9 +4883,16 @@ pub mod backlight_control4 {
     }
     impl Default for BacklightControl4 {
         fn default() -> Self {
-            BacklightControl4 {
-                data: [ 0x65 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl4 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl4")
+                .field("pixel_threshold_in_moving_image_mode", &super::Field(r.pixel_threshold_in_moving_image_mode()))
+                .field("pixel_threshold_in_still_picture_mode", &super::Field(r.pixel_threshold_in_still_picture_mode()))
+                .finish()
         }
     }
 }
@@ -4328,12 +4901,30 @@ pub mod backlight_control5 {
     enum_with_from! {
         TransitionTime(u8) => { N1Frame = 0x01, N2Frames = 0x02, N4Frames = 0x03, N8Frames = 0x04, N16Frames = 0x05, N32Frames = 0x06, N64Frames = 0x07 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl5 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl5 {
-        pub fn read(&self) -> BacklightControl5Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl5 {
+                data: [ 0x44 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl5 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl5Read<'_> {
             BacklightControl5Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4363,6 +4954,12 @@ pub mod backlight_control5 {
         d: &'l mut BacklightControl5,
     }
     impl<'l> BacklightControl5Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl5) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// brightness_change_threshold
         #[inline(always)]
         pub fn brightness_change_threshold(self, w: u8) -> Self {
@@ -4382,9 +4979,16 @@ pub mod backlight_control5 {
     }
     impl Default for BacklightControl5 {
         fn default() -> Self {
-            BacklightControl5 {
-                data: [ 0x44 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl5 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl5")
+                .field("brightness_change_threshold", &r.brightness_change_threshold())
+                .field("transition_time", &super::Field(r.transition_time()))
+                .finish()
         }
     }
 }
@@ -4393,12 +4997,30 @@ pub mod backlight_control7 {
     enum_with_from! {
         FpWmOut(u8) => { Pwm62745Hz = 0x00, Pwm31373Hz = 0x01, Pwm20915Hz = 0x02, Pwm15686Hz = 0x03, Pwm12549Hz = 0x04, Pwm10458Hz = 0x05, Pwm8964Hz = 0x06, Pwm7843Hz = 0x07, Pwm6972Hz = 0x08, Pwm6275Hz = 0x09, Pwm5704Hz = 0x0A, Pwm5229Hz = 0x0B, Pwm4827Hz = 0x0C, Pwm4482Hz = 0x0D, Pwm4183Hz = 0x0E, Pwm3922Hz = 0x0F, Pwm3691Hz = 0x10, Pwm3486Hz = 0x11, Pwm3302Hz = 0x12, Pwm3137Hz = 0x13, Pwm2988Hz = 0x14, Pwm2852Hz = 0x15, Pwm2728Hz = 0x16, Pwm2614Hz = 0x17, Pwm2510Hz = 0x18, Pwm2413Hz = 0x19, Pwm2324Hz = 0x1A, Pwm2241Hz = 0x1B, Pwm2164Hz = 0x1C, Pwm2092Hz = 0x1D, Pwm2024Hz = 0x1E, Pwm1961Hz = 0x1F, Pwm1901Hz = 0x20, Pwm1845Hz = 0x21, Pwm1793Hz = 0x22, Pwm1743Hz = 0x23, Pwm1696Hz = 0x24, Pwm1651Hz = 0x25, Pwm1609Hz = 0x26, Pwm1569Hz = 0x27, Pwm1530Hz = 0x28, Pwm1494Hz = 0x29, Pwm1459Hz = 0x2A, Pwm1426Hz = 0x2B, Pwm1394Hz = 0x2C, Pwm1364Hz = 0x2D, Pwm1335Hz = 0x2E, Pwm1307Hz = 0x2F, Pwm1281Hz = 0x30, Pwm1255Hz = 0x31, Pwm1230Hz = 0x32, Pwm1207Hz = 0x33, Pwm1184Hz = 0x34, Pwm1162Hz = 0x35, Pwm1141Hz = 0x36, Pwm1120Hz = 0x37, Pwm1101Hz = 0x38, Pwm1082Hz = 0x39, Pwm1063Hz = 0x3A, Pwm1046Hz = 0x3B, Pwm1029Hz = 0x3C, Pwm1012Hz = 0x3D, Pwm996Hz = 0x3E, Pwm980Hz = 0x3F, Pwm965Hz = 0x40, Pwm951Hz = 0x41, Pwm936Hz = 0x42, Pwm923Hz = 0x43, Pwm909Hz = 0x44, Pwm896Hz = 0x45, Pwm884Hz = 0x46, Pwm871Hz = 0x47, Pwm860Hz = 0x48, Pwm848Hz = 0x49, Pwm837Hz = 0x4A, Pwm826Hz = 0x4B, Pwm815Hz = 0x4C, Pwm804Hz = 0x4D, Pwm794Hz = 0x4E, Pwm784Hz = 0x4F, Pwm775Hz = 0x50, Pwm765Hz = 0x51, Pwm756Hz = 0x52, Pwm747Hz = 0x53, Pwm738Hz = 0x54, Pwm730Hz = 0x55, Pwm721Hz = 0x56, Pwm713Hz = 0x57, Pwm705Hz = 0x58, Pwm697Hz = 0x59, Pwm690Hz = 0x5A, Pwm682Hz = 0x5B, Pwm675Hz = 0x5C, Pwm668Hz = 0x5D, Pwm660Hz = 0x5E, Pwm654Hz = 0x5F, Pwm647Hz = 0x60, Pwm640Hz = 0x61, Pwm634Hz = 0x62, Pwm627Hz = 0x63, Pwm621Hz = 0x64, Pwm615Hz = 0x65, Pwm609Hz = 0x66, Pwm603Hz = 0x67, Pwm598Hz = 0x68, Pwm592Hz = 0x69, Pwm586Hz = 0x6A, Pwm581Hz = 0x6B, Pwm576Hz = 0x6C, Pwm570Hz = 0x6D, Pwm565Hz = 0x6E, Pwm560Hz = 0x6F, Pwm555Hz = 0x70, Pwm550Hz = 0x71, Pwm546Hz = 0x72, Pwm541Hz = 0x73, Pwm536Hz = 0x74, Pwm532Hz = 0x75, Pwm527Hz = 0x76, Pwm523Hz = 0x77, Pwm519Hz = 0x78, Pwm514Hz = 0x79, Pwm510Hz = 0x7A, Pwm506Hz = 0x7B, Pwm502Hz = 0x7C, Pwm498Hz = 0x7D, Pwm494Hz = 0x7E, Pwm490Hz = 0x7F, Pwm486Hz = 0x80, Pwm483Hz = 0x81, Pwm479Hz = 0x82, Pwm475Hz = 0x83, Pwm472Hz = 0x84, Pwm468Hz = 0x85, Pwm465Hz = 0x86, Pwm461Hz = 0x87, Pwm458Hz = 0x88, Pwm455Hz = 0x89, Pwm451Hz = 0x8A, Pwm448Hz = 0x8B, Pwm445Hz = 0x8C, Pwm442Hz = 0x8D, Pwm439Hz = 0x8E, Pwm436Hz = 0x8F, Pwm433Hz = 0x90, Pwm430Hz = 0x91, Pwm427Hz = 0x92, Pwm424Hz = 0x93, Pwm421Hz = 0x94, Pwm418Hz = 0x95, Pwm416Hz = 0x96, Pwm413Hz = 0x97, Pwm410Hz = 0x98, Pwm407Hz = 0x99, Pwm405Hz = 0x9A, Pwm402Hz = 0x9B, Pwm400Hz = 0x9C, Pwm397Hz = 0x9D, Pwm395Hz = 0x9E, Pwm392Hz = 0x9F, Pwm390Hz = 0xA0, Pwm387Hz = 0xA1, Pwm385Hz = 0xA2, Pwm383Hz = 0xA3, Pwm380Hz = 0xA4, Pwm378Hz = 0xA5, Pwm376Hz = 0xA6, Pwm373Hz = 0xA7, Pwm371Hz = 0xA8, Pwm369Hz = 0xA9, Pwm367Hz = 0xAA, Pwm365Hz = 0xAB, Pwm363Hz = 0xAC, Pwm361Hz = 0xAD, Pwm359Hz = 0xAE, Pwm357Hz = 0xAF, Pwm354Hz = 0xB0, Pwm353Hz = 0xB1, Pwm351Hz = 0xB2, Pwm349Hz = 0xB3, Pwm347Hz = 0xB4, Pwm345Hz = 0xB5, Pwm343Hz = 0xB6, Pwm341Hz = 0xB7, Pwm339Hz = 0xB8, Pwm337Hz = 0xB9, Pwm336Hz = 0xBA, Pwm334Hz = 0xBB, Pwm332Hz = 0xBC, Pwm330Hz = 0xBD, Pwm329Hz = 0xBE, Pwm327Hz = 0xBF, Pwm325Hz = 0xC0, Pwm323Hz = 0xC1, Pwm322Hz = 0xC2, Pwm320Hz = 0xC3, Pwm319Hz = 0xC4, Pwm317Hz = 0xC5, Pwm315Hz = 0xC6, Pwm314Hz = 0xC7, Pwm312Hz = 0xC8, Pwm311Hz = 0xC9, Pwm309Hz = 0xCA, Pwm308Hz = 0xCB, Pwm306Hz = 0xCC, Pwm305Hz = 0xCD, Pwm303Hz = 0xCE, Pwm302Hz = 0xCF, Pwm300Hz = 0xD0, Pwm299Hz = 0xD1, Pwm297Hz = 0xD2, Pwm296Hz = 0xD3, Pwm295Hz = 0xD4, Pwm293Hz = 0xD5, Pwm292Hz = 0xD6, Pwm290Hz = 0xD7, Pwm289Hz = 0xD8, Pwm288Hz = 0xD9, Pwm287Hz = 0xDA, Pwm285Hz = 0xDB, Pwm284Hz = 0xDC, Pwm283Hz = 0xDD, Pwm281Hz = 0xDE, Pwm280Hz = 0xDF, Pwm279Hz = 0xE0, Pwm278Hz = 0xE1, Pwm276Hz = 0xE2, Pwm275Hz = 0xE3, Pwm274Hz = 0xE4, Pwm273Hz = 0xE5, Pwm272Hz = 0xE6, Pwm270Hz = 0xE7, Pwm269Hz = 0xE8, Pwm268Hz = 0xE9, Pwm267Hz = 0xEA, Pwm266Hz = 0xEB, Pwm265Hz = 0xEC, Pwm264Hz = 0xED, Pwm263Hz = 0xEE, Pwm261Hz = 0xEF, Pwm260Hz = 0xF0, Pwm259Hz = 0xF1, Pwm258Hz = 0xF2, Pwm257Hz = 0xF3, Pwm256Hz = 0xF4, Pwm255Hz = 0xF5, Pwm254Hz = 0xF6, Pwm253Hz = 0xF7, Pwm252Hz = 0xF8, Pwm251Hz = 0xF9, Pwm250Hz = 0xFA, Pwm249Hz = 0xFB, Pwm248Hz = 0xFC, Pwm247Hz = 0xFD, Pwm246Hz = 0xFE, Pwm245Hz = 0xFF },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl7 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl7 {
-        pub fn read(&self) -> BacklightControl7Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl7 {
+                data: [ 0x0F ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl7 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl7Read<'_> {
             BacklightControl7Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4423,6 +5045,12 @@ pub mod backlight_control7 {
         d: &'l mut BacklightControl7,
     }
     impl<'l> BacklightControl7Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl7) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// fp_wm_out
         #[inline(always)]
         pub fn fp_wm_out(self, w: FpWmOut) -> Self {
@@ -4434,9 +5062,15 @@ pub mod backlight_control7 {
     }
     impl Default for BacklightControl7 {
         fn default() -> Self {
-            BacklightControl7 {
-                data: [ 0x0F ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl7 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl7")
+                .field("fp_wm_out", &super::Field(r.fp_wm_out()))
+                .finish()
         }
     }
 }
@@ -4447,12 +5081,30 @@ pub mod backlight_control8 {
         LedonPin(u8) => { Ledonr = 0x00, InversedLedonr = 0x01 },
         LedpwmPin(u8) => { OriginalPolarityOfPwmSignal = 0x00, InversedPolarityOfPwmSignal = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct BacklightControl8 {
         pub(super) data: [u8; 1],
     }
     impl BacklightControl8 {
-        pub fn read(&self) -> BacklightControl8Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            BacklightControl8 {
+                data: [ 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            BacklightControl8 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> BacklightControl8Read<'_> {
             BacklightControl8Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4487,6 +5139,12 @@ pub mod backlight_control8 {
         d: &'l mut BacklightControl8,
     }
     impl<'l> BacklightControl8Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: BacklightControl8) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// polarity
         #[inline(always)]
         pub fn polarity(self, w: Polarity) -> Self {
@@ -4515,9 +5173,17 @@ pub mod backlight_control8 {
     }
     impl Default for BacklightControl8 {
         fn default() -> Self {
-            BacklightControl8 {
-                data: [ 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for BacklightControl8 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("BacklightControl8")
+                .field("polarity", &super::Field(r.polarity()))
+                .field("ledon_pin", &super::Field(r.ledon_pin()))
+                .field("ledpwm_pin", &super::Field(r.ledpwm_pin()))
+                .finish()
         }
     }
 }
@@ -4526,12 +5192,30 @@ pub mod power_control1 {
     enum_with_from! {
         Gvdd(u8) => { N3o00V = 0x03, N3o05V = 0x04, N3o10V = 0x05, N3o15V = 0x06, N3o20V = 0x07, N3o25V = 0x08, N3o30V = 0x09, N3o35V = 0x0A, N3o40V = 0x0B, N3o45V = 0x0C, N3o50V = 0x0D, N3o55V = 0x0E, N3o60V = 0x0F, N3o65V = 0x10, N3o70V = 0x11, N3o75V = 0x12, N3o80V = 0x13, N3o85V = 0x14, N3o90V = 0x15, N3o95V = 0x16, N4o00V = 0x17, N4o05V = 0x18, N4o10V = 0x19, N4o15V = 0x1A, N4o20V = 0x1B, N4o25V = 0x1C, N4o30V = 0x1D, N4o35V = 0x1E, N4o40V = 0x1F, N4o45V = 0x20, N4o50V = 0x21, N4o55V = 0x22, N4o60V = 0x23, N4o65V = 0x24, N4o70V = 0x25, N4o75V = 0x26, N4o80V = 0x27, N4o85V = 0x28, N4o90V = 0x29, N4o95V = 0x2A, N5o00V = 0x2B, N5o05V = 0x2C, N5o10V = 0x2D, N5o15V = 0x2E, N5o20V = 0x2F, N5o25V = 0x30, N5o30V = 0x31, N5o35V = 0x32, N5o40V = 0x33, N5o45V = 0x34, N5o50V = 0x35, N5o55V = 0x36, N5o60V = 0x37, N5o65V = 0x38, N5o70V = 0x39, N5o75V = 0x3A, N5o80V = 0x3B, N5o85V = 0x3C, N5o90V = 0x3D, N5o95V = 0x3E, N6o00V = 0x3F },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct PowerControl1 {
         pub(super) data: [u8; 1],
     }
     impl PowerControl1 {
-        pub fn read(&self) -> PowerControl1Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            PowerControl1 {
+                data: [ 0x21 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            PowerControl1 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> PowerControl1Read<'_> {
             PowerControl1Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4556,6 +5240,12 @@ pub mod power_control1 {
         d: &'l mut PowerControl1,
     }
     impl<'l> PowerControl1Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PowerControl1) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// gvdd
         #[inline(always)]
         pub fn gvdd(self, w: Gvdd) -> Self {
@@ -4568,9 +5258,15 @@ pub mod power_control1 {
     }
     impl Default for PowerControl1 {
         fn default() -> Self {
-            PowerControl1 {
-                data: [ 0x21 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PowerControl1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PowerControl1")
+                .field("gvdd", &super::Field(r.gvdd()))
+                .finish()
         }
     }
 }
@@ -4579,12 +5275,30 @@ pub mod power_control2 {
     enum_with_from! {
         Avdd(u8) => { VciX2VciX7VciX4 = 0x00, VciX2VciX7VciX3 = 0x01, VciX2VciX6VciX4 = 0x02, VciX2VciX6VciX3 = 0x03 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct PowerControl2 {
         pub(super) data: [u8; 1],
     }
     impl PowerControl2 {
-        pub fn read(&self) -> PowerControl2Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            PowerControl2 {
+                data: [ 0x10 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            PowerControl2 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> PowerControl2Read<'_> {
             PowerControl2Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4609,6 +5323,12 @@ pub mod power_control2 {
         d: &'l mut PowerControl2,
     }
     impl<'l> PowerControl2Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PowerControl2) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// avdd
         #[inline(always)]
         pub fn avdd(self, w: Avdd) -> Self {
@@ -4621,9 +5341,15 @@ pub mod power_control2 {
     }
     impl Default for PowerControl2 {
         fn default() -> Self {
-            PowerControl2 {
-                data: [ 0x10 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PowerControl2 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PowerControl2")
+                .field("avdd", &super::Field(r.avdd()))
+                .finish()
         }
     }
 }
@@ -4633,12 +5359,30 @@ pub mod vcom_control1 {
         VcomhV(u8) => { N2o700 = 0x00, N2o725 = 0x01, N2o750 = 0x02, N2o775 = 0x03, N2o800 = 0x04, N2o825 = 0x05, N2o850 = 0x06, N2o875 = 0x07, N2o900 = 0x08, N2o925 = 0x09, N2o950 = 0x0A, N2o975 = 0x0B, N3o000 = 0x0C, N3o025 = 0x0D, N3o050 = 0x0E, N3o075 = 0x0F, N3o100 = 0x10, N3o125 = 0x11, N3o150 = 0x12, N3o175 = 0x13, N3o200 = 0x14, N3o225 = 0x15, N3o250 = 0x16, N3o275 = 0x17, N3o300 = 0x18, N3o325 = 0x19, N3o350 = 0x1A, N3o375 = 0x1B, N3o400 = 0x1C, N3o425 = 0x1D, N3o450 = 0x1E, N3o475 = 0x1F, N3o500 = 0x20, N3o525 = 0x21, N3o550 = 0x22, N3o575 = 0x23, N3o600 = 0x24, N3o625 = 0x25, N3o650 = 0x26, N3o675 = 0x27, N3o700 = 0x28, N3o725 = 0x29, N3o750 = 0x2A, N3o775 = 0x2B, N3o800 = 0x2C, N3o825 = 0x2D, N3o850 = 0x2E, N3o875 = 0x2F, N3o900 = 0x30, N3o925 = 0x31, N3o950 = 0x32, N3o975 = 0x33, N4o000 = 0x34, N4o025 = 0x35, N4o050 = 0x36, N4o075 = 0x37, N4o100 = 0x38, N4o125 = 0x39, N4o150 = 0x3A, N4o175 = 0x3B, N4o200 = 0x3C, N4o225 = 0x3D, N4o250 = 0x3E, N4o275 = 0x3F, N4o300 = 0x40, N4o325 = 0x41, N4o350 = 0x42, N4o375 = 0x43, N4o400 = 0x44, N4o425 = 0x45, N4o450 = 0x46, N4o475 = 0x47, N4o500 = 0x48, N4o525 = 0x49, N4o550 = 0x4A, N4o575 = 0x4B, N4o600 = 0x4C, N4o625 = 0x4D, N4o650 = 0x4E, N4o675 = 0x4F, N4o700 = 0x50, N4o725 = 0x51, N4o750 = 0x52, N4o775 = 0x53, N4o800 = 0x54, N4o825 = 0x55, N4o850 = 0x56, N4o875 = 0x57, N4o900 = 0x58, N4o925 = 0x59, N4o950 = 0x5A, N4o975 = 0x5B, N5o000 = 0x5C, N5o025 = 0x5D, N5o050 = 0x5E, N5o075 = 0x5F, N5o100 = 0x60, N5o125 = 0x61, N5o150 = 0x62, N5o175 = 0x63, N5o200 = 0x64, N5o225 = 0x65, N5o250 = 0x66, N5o275 = 0x67, N5o300 = 0x68, N5o325 = 0x69, N5o350 = 0x6A, N5o375 = 0x6B, N5o400 = 0x6C, N5o425 = 0x6D, N5o450 = 0x6E, N5o475 = 0x6F, N5o500 = 0x70, N5o525 = 0x71, N5o550 = 0x72, N5o575 = 0x73, N5o600 = 0x74, N5o625 = 0x75, N5o650 = 0x76, N5o675 = 0x77, N5o700 = 0x78, N5o725 = 0x79, N5o750 = 0x7A, N5o775 = 0x7B, N5o800 = 0x7C, N5o825 = 0x7D, N5o850 = 0x7E, N5o875 = 0x7F },
         VcomlV(u8) => { NNeg2o500 = 0x00, NNeg2o475 = 0x01, NNeg2o450 = 0x02, NNeg2o425 = 0x03, NNeg2o400 = 0x04, NNeg2o375 = 0x05, NNeg2o350 = 0x06, NNeg2o325 = 0x07, NNeg2o300 = 0x08, NNeg2o275 = 0x09, NNeg2o250 = 0x0A, NNeg2o225 = 0x0B, NNeg2o200 = 0x0C, NNeg2o175 = 0x0D, NNeg2o150 = 0x0E, NNeg2o125 = 0x0F, NNeg2o100 = 0x10, NNeg2o075 = 0x11, NNeg2o050 = 0x12, NNeg2o025 = 0x13, NNeg2o000 = 0x14, NNeg1o975 = 0x15, NNeg1o950 = 0x16, NNeg1o925 = 0x17, NNeg1o900 = 0x18, NNeg1o875 = 0x19, NNeg1o850 = 0x1A, NNeg1o825 = 0x1B, NNeg1o800 = 0x1C, NNeg1o775 = 0x1D, NNeg1o750 = 0x1E, NNeg1o725 = 0x1F, NNeg1o700 = 0x20, NNeg1o675 = 0x21, NNeg1o650 = 0x22, NNeg1o625 = 0x23, NNeg1o600 = 0x24, NNeg1o575 = 0x25, NNeg1o550 = 0x26, NNeg1o525 = 0x27, NNeg1o500 = 0x28, NNeg1o475 = 0x29, NNeg1o450 = 0x2A, NNeg1o425 = 0x2B, NNeg1o400 = 0x2C, NNeg1o375 = 0x2D, NNeg1o350 = 0x2E, NNeg1o325 = 0x2F, NNeg1o300 = 0x30, NNeg1o275 = 0x31, NNeg1o250 = 0x32, NNeg1o225 = 0x33, NNeg1o200 = 0x34, NNeg1o175 = 0x35, NNeg1o150 = 0x36, NNeg1o125 = 0x37, NNeg1o100 = 0x38, NNeg1o075 = 0x39, NNeg1o050 = 0x3A, NNeg1o025 = 0x3B, NNeg1o000 = 0x3C, NNeg0o975 = 0x3D, NNeg0o950 = 0x3E, NNeg0o925 = 0x3F, NNeg0o900 = 0x40, NNeg0o875 = 0x41, NNeg0o850 = 0x42, NNeg0o825 = 0x43, NNeg0o800 = 0x44, NNeg0o775 = 0x45, NNeg0o750 = 0x46, NNeg0o725 = 0x47, NNeg0o700 = 0x48, NNeg0o675 = 0x49, NNeg0o650 = 0x4A, NNeg0o625 = 0x4B, NNeg0o600 = 0x4C, NNeg0o575 = 0x4D, NNeg0o550 = 0x4E, NNeg0o525 = 0x4F, NNeg0o500 = 0x50, NNeg0o475 = 0x51, NNeg0o450 = 0x52, NNeg0o425 = 0x53, NNeg0o400 = 0x54, NNeg0o375 = 0x55, NNeg0o350 = 0x56, NNeg0o325 = 0x57, NNeg0o300 = 0x58, NNeg0o275 = 0x59, NNeg0o250 = 0x5A, NNeg0o225 = 0x5B, NNeg0o200 = 0x5C, NNeg0o175 = 0x5D, NNeg0o150 = 0x5E, NNeg0o125 = 0x5F, NNeg0o100 = 0x60, NNeg0o075 = 0x61, NNeg0o050 = 0x62, NNeg0o025 = 0x63, N0 = 0x64 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct VcomControl1 {
         pub(super) data: [u8; 2],
     }
     impl VcomControl1 {
-        pub fn read(&self) -> VcomControl1Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            VcomControl1 {
+                data: [ 0x31, 0x3C ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            VcomControl1 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> VcomControl1Read<'_> {
             VcomControl1Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4668,6 +5412,12 @@ pub mod vcom_control1 {
         d: &'l mut VcomControl1,
     }
     impl<'l> VcomControl1Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: VcomControl1) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// vcomh_v
         #[inline(always)]
         pub fn vcomh_v(self, w: VcomhV) -> Self {
@@ -4688,20 +5438,45 @@ pub mod vcom_control1 {
     }
     impl Default for VcomControl1 {
         fn default() -> Self {
-            VcomControl1 {
-                data: [ 0x31, 0x3C ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for VcomControl1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("VcomControl1")
+                .field("vcomh_v", &super::Field(r.vcomh_v()))
+                .field("vcoml_v", &super::Field(r.vcoml_v()))
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod vcom_control2 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct VcomControl2 {
         pub(super) data: [u8; 1],
     }
     impl VcomControl2 {
-        pub fn read(&self) -> VcomControl2Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            VcomControl2 {
+                data: [ 0xC0 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            VcomControl2 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> VcomControl2Read<'_> {
             VcomControl2Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4731,6 +5506,12 @@ pub mod vcom_control2 {
         d: &'l mut VcomControl2,
     }
     impl<'l> VcomControl2Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: VcomControl2) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// vcom_setting_source
         #[inline(always)]
         pub fn vcom_setting_source(self, w: bool) -> Self {
@@ -4750,10 +5531,220 @@ pub mod vcom_control2 {
     }
     impl Default for VcomControl2 {
         fn default() -> Self {
-            VcomControl2 {
-                data: [ 0xC0 ],
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for VcomControl2 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("VcomControl2")
+                .field("vcom_setting_source", &r.vcom_setting_source())
+                .field("vcomh", &r.vcomh())
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod power_control_a {
+    enum_with_from! {
+        RegVd(u8) => { N1o55V = 0x00, N1o40V = 0x01, N1o50V = 0x02, N1o65V = 0x03, N1o60V = 0x04, N1o70V = 0x05 },
+        Vbc(u8) => { N5o8V = 0x00, N5o7V = 0x01, N5o6V = 0x02, N5o5V = 0x03, N5o4V = 0x04, N5o3V = 0x05, N5o2V = 0x06 },
+    }
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct PowerControlA {
+        pub(super) data: [u8; 5],
+    }
+    impl PowerControlA {
+        /// The reset default
+        pub const fn new() -> Self {
+            PowerControlA {
+                data: [ 0x39, 0x2C, 0x00, 0x34, 0x02 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 5]) -> Self {
+            PowerControlA { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 5] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 5] {
+            self.data
+        }
+        pub fn read(&self) -> PowerControlARead<'_> {
+            PowerControlARead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(PowerControlAWrite) -> PowerControlAWrite,
+        {
+            f(PowerControlAWrite { d: self }).d
+        }
+    }
+    pub struct PowerControlARead<'l> {
+        d: &'l PowerControlA,
+    }
+    impl<'l> PowerControlARead<'l> {
+        /// reg_vd
+        #[inline(always)]
+        pub fn reg_vd(&self) -> Result<RegVd, u8> {
+            RegVd::try_from(self.d.data[3] & 0x07)
+        }
+        /// vbc
+        #[inline(always)]
+        pub fn vbc(&self) -> Result<Vbc, u8> {
+            Vbc::try_from(self.d.data[4] & 0x07)
+        }
+    
+    }
+    pub struct PowerControlAWrite<'l> {
+        d: &'l mut PowerControlA,
+    }
+    impl<'l> PowerControlAWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PowerControlA) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// reg_vd
+        #[inline(always)]
+        pub fn reg_vd(self, w: RegVd) -> Self {
+            let w = w as u8;
+            self.d.data[3] &= !(0x07);
+            self.d.data[3] |= w & 0x07;
+            self
+        }
+        /// vbc
+        #[inline(always)]
+        pub fn vbc(self, w: Vbc) -> Self {
+            let w = w as u8;
+            self.d.data[4] &= !(0x07);
+            self.d.data[4] |= w & 0x07;
+            self
+        }
+    
+    }
+    impl Default for PowerControlA {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PowerControlA {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PowerControlA")
+                .field("reg_vd", &super::Field(r.reg_vd()))
+                .field("vbc", &super::Field(r.vbc()))
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod power_control_b {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct PowerControlB {
+        pub(super) data: [u8; 3],
+    }
+    impl PowerControlB {
+        /// The reset default
+        pub const fn new() -> Self {
+            PowerControlB {
+                data: [ 0x00, 0x81, 0x30 ],
             }
         }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            PowerControlB { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> PowerControlBRead<'_> {
+            PowerControlBRead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(PowerControlBWrite) -> PowerControlBWrite,
+        {
+            f(PowerControlBWrite { d: self }).d
+        }
+    }
+    pub struct PowerControlBRead<'l> {
+        d: &'l PowerControlB,
+    }
+    impl<'l> PowerControlBRead<'l> {
+        /// pceq
+        #[inline(always)]
+        pub fn pceq(&self) -> bool {
+            ((self.d.data[1] >> 6) & 0x01) != 0
+        }
+        /// power_control
+        #[inline(always)]
+        pub fn power_control(&self) -> u8 {
+            (self.d.data[1] >> 3) & 0x03
+        }
+        /// dc_ena
+        #[inline(always)]
+        pub fn dc_ena(&self) -> bool {
+            ((self.d.data[2] >> 4) & 0x01) != 0
+        }
+    
+    }
+    pub struct PowerControlBWrite<'l> {
+        d: &'l mut PowerControlB,
+    }
+    impl<'l> PowerControlBWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PowerControlB) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// pceq
+        #[inline(always)]
+        pub fn pceq(self, w: bool) -> Self {
+            self.d.data[1] &= !(0x01 << 6);
+            self.d.data[1] |= ((w) as u8) << 6;
+            self
+        }
+        /// power_control
+        #[inline(always)]
+        pub fn power_control(self, w: u8) -> Self {
+            self.d.data[1] &= !(0x03 << 3);
+            self.d.data[1] |= (w & 0x03) << 3;
+            self
+        }
+        /// dc_ena
+        #[inline(always)]
+        pub fn dc_ena(self, w: bool) -> Self {
+            self.d.data[2] &= !(0x01 << 4);
+            self.d.data[2] |= ((w) as u8) << 4;
+            self
+        }
+    
+    }
+    impl Default for PowerControlB {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PowerControlB {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PowerControlB")
+                .field("pceq", &r.pceq())
+                .field("power_control", &r.power_control())
+                .field("dc_ena", &r.dc_ena())
+                .finish()
+        }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
@@ -4761,12 +5752,30 @@ pub mod nv_memory_write {
     enum_with_from! {
         ProgrammedNvMemorySelection(u8) => { Id1Programming = 0x00, Id2Programming = 0x01, Id3Programming = 0x02, Vmf60Programming = 0x04 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct NvMemory {
         pub(super) data: [u8; 2],
     }
     impl NvMemory {
-        pub fn read(&self) -> NvMemoryRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            NvMemory {
+                data: [ 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            NvMemory { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> NvMemoryRead<'_> {
             NvMemoryRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4796,6 +5805,12 @@ pub mod nv_memory_write {
         d: &'l mut NvMemory,
     }
     impl<'l> NvMemoryWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: NvMemory) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// programmed_nv_memory_selection
         #[inline(always)]
         pub fn programmed_nv_memory_selection(self, w: ProgrammedNvMemorySelection) -> Self {
@@ -4814,20 +5829,45 @@ pub mod nv_memory_write {
     }
     impl Default for NvMemory {
         fn default() -> Self {
-            NvMemory {
-                data: [ 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for NvMemory {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("NvMemory")
+                .field("programmed_nv_memory_selection", &super::Field(r.programmed_nv_memory_selection()))
+                .field("the_programmed_data", &r.the_programmed_data())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod nv_memory_protection_key {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct NvMemoryProtectionKey {
         pub(super) data: [u8; 3],
     }
     impl NvMemoryProtectionKey {
-        pub fn read(&self) -> NvMemoryProtectionKeyRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            NvMemoryProtectionKey {
+                data: [ 0x55, 0xAA, 0x66 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            NvMemoryProtectionKey { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> NvMemoryProtectionKeyRead<'_> {
             NvMemoryProtectionKeyRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4852,6 +5892,12 @@ pub mod nv_memory_protection_key {
         d: &'l mut NvMemoryProtectionKey,
     }
     impl<'l> NvMemoryProtectionKeyWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: NvMemoryProtectionKey) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// nv_memory_programming_protection_key
         #[inline(always)]
         pub fn nv_memory_programming_protection_key(self, w: u32) -> Self {
@@ -4864,9 +5910,15 @@ pub mod nv_memory_protection_key {
     }
     impl Default for NvMemoryProtectionKey {
         fn default() -> Self {
-            NvMemoryProtectionKey {
-                data: [ 0x55, 0xAA, 0x66 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for NvMemoryProtectionKey {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("NvMemoryProtectionKey")
+                .field("nv_memory_programming_protection_key", &r.nv_memory_programming_protection_key())
+                .finish()
         }
     }
 }
@@ -4879,12 +5931,30 @@ pub mod nv_memory_status_read {
         VmfWriteCount(u8) => { NoProgrammed = 0x00, Programmed1Time = 0x01, Programmed2Times = 0x03, Programmed3Times = 0x07 },
         Id3WriteCount(u8) => { NoProgrammed = 0x00, Programmed1Time = 0x01, Programmed2Times = 0x03, Programmed3Times = 0x07 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct NvMemoryStatus {
         pub(super) data: [u8; 2],
     }
     impl NvMemoryStatus {
-        pub fn read(&self) -> NvMemoryStatusRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            NvMemoryStatus {
+                data: [ 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            NvMemoryStatus { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> NvMemoryStatusRead<'_> {
             NvMemoryStatusRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -4929,6 +5999,12 @@ pub mod nv_memory_status_read {
         d: &'l mut NvMemoryStatus,
     }
     impl<'l> NvMemoryStatusWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: NvMemoryStatus) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// id2_write_count
         #[inline(always)]
         pub fn id2_write_count(self, w: Id2WriteCount) -> Self {
@@ -4973,20 +6049,48 @@ pub mod nv_memory_status_read {
     }
     impl Default for NvMemoryStatus {
         fn default() -> Self {
-            NvMemoryStatus {
-                data: [ 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for NvMemoryStatus {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("NvMemoryStatus")
+                .field("id2_write_count", &super::Field(r.id2_write_count()))
+                .field("id1_write_count", &super::Field(r.id1_write_count()))
+                .field("the_status_of_nv_memory", &super::Field(r.the_status_of_nv_memory()))
+                .field("vmf_write_count", &super::Field(r.vmf_write_count()))
+                .field("id3_write_count", &super::Field(r.id3_write_count()))
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod read_id4 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct Id4 {
         pub(super) data: [u8; 3],
     }
     impl Id4 {
-        pub fn read(&self) -> Id4Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            Id4 {
+                data: [ 0x00, 0x93, 0x41 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            Id4 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> Id4Read<'_> {
             Id4Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -5000,30 +6104,71 @@ pub mod read_id4 {
         d: &'l Id4,
     }
     impl<'l> Id4Read<'l> {
+        /// ic_version
+        #[inline(always)]
+        pub fn ic_version(&self) -> u8 {
+            self.d.data[0]
+        }
+        /// ic_model_name
+        #[inline(always)]
+        pub fn ic_model_name(&self) -> u16 {
+            ((self.d.data[1] as u16) << 8) | (self.d.data[2] as u16)
+        }
     
     }
     pub struct Id4Write<'l> {
         d: &'l mut Id4,
     }
     impl<'l> Id4Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: Id4) -> Self {
+            self.d.data = r.data;
+            self
+        }
     
     }
     impl Default for Id4 {
         fn default() -> Self {
-            Id4 {
-                data: [ 0x00, 0x93, 0x41 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for Id4 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("Id4")
+                .field("ic_version", &r.ic_version())
+                .field("ic_model_name", &r.ic_model_name())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod positive_gamma_correction {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct PositiveGammaCorrection {
         pub(super) data: [u8; 15],
     }
     impl PositiveGammaCorrection {
-        pub fn read(&self) -> PositiveGammaCorrectionRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            PositiveGammaCorrection {
+                data: [ 0x08, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 15]) -> Self {
+            PositiveGammaCorrection { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 15] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 15] {
+            self.data
+        }
+        pub fn read(&self) -> PositiveGammaCorrectionRead<'_> {
             PositiveGammaCorrectionRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -5123,6 +6268,12 @@ pub mod positive_gamma_correction {
         d: &'l mut PositiveGammaCorrection,
     }
     impl<'l> PositiveGammaCorrectionWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PositiveGammaCorrection) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// vp63
         #[inline(always)]
         pub fn vp63(self, w: u8) -> Self {
@@ -5239,20 +6390,59 @@ pub mod positive_gamma_correction {
     }
     impl Default for PositiveGammaCorrection {
         fn default() -> Self {
-            PositiveGammaCorrection {
-                data: [ 0x08, 0x00, 0x00, 0x05, 0x00, 0x09, 0x00, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x00, 0x00 ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PositiveGammaCorrection {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PositiveGammaCorrection")
+                .field("vp63", &r.vp63())
+                .field("vp62", &r.vp62())
+                .field("vp61", &r.vp61())
+                .field("vp59", &r.vp59())
+                .field("vp57", &r.vp57())
+                .field("vp50", &r.vp50())
+                .field("vp43", &r.vp43())
+                .field("vp27", &r.vp27())
+                .field("vp36", &r.vp36())
+                .field("vp20", &r.vp20())
+                .field("vp13", &r.vp13())
+                .field("vp6", &r.vp6())
+                .field("vp4", &r.vp4())
+                .field("vp2", &r.vp2())
+                .field("vp1", &r.vp1())
+                .field("vp0", &r.vp0())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod negative_gamma_correction {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct NegativeGammaCorrection {
         pub(super) data: [u8; 15],
     }
     impl NegativeGammaCorrection {
-        pub fn read(&self) -> NegativeGammaCorrectionRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            NegativeGammaCorrection {
+                data: [ 0x08, 0x00, 0x00, 0x07, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0x00, 0x0F, 0x00, 0x00, 0x0F ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 15]) -> Self {
+            NegativeGammaCorrection { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 15] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 15] {
+            self.data
+        }
+        pub fn read(&self) -> NegativeGammaCorrectionRead<'_> {
             NegativeGammaCorrectionRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -5352,6 +6542,12 @@ pub mod negative_gamma_correction {
         d: &'l mut NegativeGammaCorrection,
     }
     impl<'l> NegativeGammaCorrectionWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: NegativeGammaCorrection) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// vn63
         #[inline(always)]
         pub fn vn63(self, w: u8) -> Self {
@@ -5468,20 +6664,59 @@ pub mod negative_gamma_correction {
     }
     impl Default for NegativeGammaCorrection {
         fn default() -> Self {
-            NegativeGammaCorrection {
-                data: [ 0x08, 0x00, 0x00, 0x07, 0x00, 0x05, 0x00, 0x00, 0x00, 0x04, 0x00, 0x0F, 0x00, 0x00, 0x0F ],
-            }
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for NegativeGammaCorrection {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("NegativeGammaCorrection")
+                .field("vn63", &r.vn63())
+                .field("vn62", &r.vn62())
+                .field("vn61", &r.vn61())
+                .field("vn59", &r.vn59())
+                .field("vn57", &r.vn57())
+                .field("vn50", &r.vn50())
+                .field("vn43", &r.vn43())
+                .field("vn36", &r.vn36())
+                .field("vn27", &r.vn27())
+                .field("vn20", &r.vn20())
+                .field("vn13", &r.vn13())
+                .field("vn6", &r.vn6())
+                .field("vn4", &r.vn4())
+                .field("vn2", &r.vn2())
+                .field("vn1", &r.vn1())
+                .field("vn0", &r.vn0())
+                .finish()
         }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod digital_gamma_control1 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DigitalGammaControl1 {
         pub(super) data: [u8; 16],
     }
     impl DigitalGammaControl1 {
-        pub fn read(&self) -> DigitalGammaControl1Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            DigitalGammaControl1 {
+                data: [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 16]) -> Self {
+            DigitalGammaControl1 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 16] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 16] {
+            self.data
+        }
+        pub fn read(&self) -> DigitalGammaControl1Read<'_> {
             DigitalGammaControl1Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -5513,6 +6748,12 @@ pub mod digital_gamma_control1 {
         d: &'l mut DigitalGammaControl1,
     }
     impl<'l> DigitalGammaControl1Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DigitalGammaControl1) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// rca
         #[inline(always)]
         pub fn rca(self, w: &'l[u8]) -> Self {
@@ -5529,20 +6770,45 @@ pub mod digital_gamma_control1 {
     }
     impl Default for DigitalGammaControl1 {
         fn default() -> Self {
-            DigitalGammaControl1 {
-                data: [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ],
-            }
+            Self::new()
         }
     }
-}
+    impl core::fmt::Display for DigitalGammaControl1 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DigitalGammaControl1")
+                .field("rca", &r.rca())
+                .field("bca", &r.bca())
+                .finish()
+        }
+    }
+}
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
 pub mod digital_gamma_control2 {
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct DigitalGammaControl2 {
         pub(super) data: [u8; 64],
     }
     impl DigitalGammaControl2 {
-        pub fn read(&self) -> DigitalGammaControl2Read {
+        /// The reset default
+        pub const fn new() -> Self {
+            DigitalGammaControl2 {
+                data: [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 64]) -> Self {
+            DigitalGammaControl2 { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 64] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 64] {
+            self.data
+        }
+        pub fn read(&self) -> DigitalGammaControl2Read<'_> {
             DigitalGammaControl2Read { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -5574,6 +6840,12 @@ pub mod digital_gamma_control2 {
         d: &'l mut DigitalGammaControl2,
     }
     impl<'l> DigitalGammaControl2Write<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DigitalGammaControl2) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// rfa
         #[inline(always)]
         pub fn rfa(self, w: &'l[u8]) -> Self {
@@ -5590,10 +6862,601 @@ pub mod digital_gamma_control2 {
     }
     impl Default for DigitalGammaControl2 {
         fn default() -> Self {
-            DigitalGammaControl2 {
-                data: [ 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00 ],
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DigitalGammaControl2 {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DigitalGammaControl2")
+                .field("rfa", &r.rfa())
+                .field("bfa", &r.bfa())
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod driver_timing_control_a {
+    enum_with_from! {
+        Now(u8) => { Default = 0x00, DefaultPlus1Unit = 0x01 },
+        Eq(u8) => { DefaultMinus1Unit = 0x00, Default = 0x01 },
+        Cr(u8) => { DefaultMinus1Unit = 0x00, Default = 0x01 },
+        Pc(u8) => { DefaultMinus2Unit = 0x00, DefaultMinus1Unit = 0x01, Default = 0x02 },
+    }
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct DriverTimingControlA {
+        pub(super) data: [u8; 3],
+    }
+    impl DriverTimingControlA {
+        /// The reset default
+        pub const fn new() -> Self {
+            DriverTimingControlA {
+                data: [ 0x84, 0x11, 0x7A ],
             }
         }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            DriverTimingControlA { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> DriverTimingControlARead<'_> {
+            DriverTimingControlARead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(DriverTimingControlAWrite) -> DriverTimingControlAWrite,
+        {
+            f(DriverTimingControlAWrite { d: self }).d
+        }
+    }
+    pub struct DriverTimingControlARead<'l> {
+        d: &'l DriverTimingControlA,
+    }
+    impl<'l> DriverTimingControlARead<'l> {
+        /// now
+        #[inline(always)]
+        pub fn now(&self) -> Result<Now, u8> {
+            Now::try_from(self.d.data[0] & 0x01)
+        }
+        /// eq
+        #[inline(always)]
+        pub fn eq(&self) -> Result<Eq, u8> {
+            Eq::try_from((self.d.data[1] >> 4) & 0x01)
+        }
+        /// cr
+        #[inline(always)]
+        pub fn cr(&self) -> Result<Cr, u8> {
+            Cr::try_from(self.d.data[1] & 0x01)
+        }
+        /// pc
+        #[inline(always)]
+        pub fn pc(&self) -> Result<Pc, u8> {
+            Pc::try_from(self.d.data[2] & 0x03)
+        }
+    
+    }
+    pub struct DriverTimingControlAWrite<'l> {
+        d: &'l mut DriverTimingControlA,
+    }
+    impl<'l> DriverTimingControlAWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DriverTimingControlA) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// now
+        #[inline(always)]
+        pub fn now(self, w: Now) -> Self {
+            let w = w as u8;
+            self.d.data[0] &= !(0x01);
+            self.d.data[0] |= w & 0x01;
+            self
+        }
+        /// eq
+        #[inline(always)]
+        pub fn eq(self, w: Eq) -> Self {
+            let w = w as u8;
+            self.d.data[1] &= !(0x01 << 4);
+            self.d.data[1] |= (w & 0x01) << 4;
+            self
+        }
+        /// cr
+        #[inline(always)]
+        pub fn cr(self, w: Cr) -> Self {
+            let w = w as u8;
+            self.d.data[1] &= !(0x01);
+            self.d.data[1] |= w & 0x01;
+            self
+        }
+        /// pc
+        #[inline(always)]
+        pub fn pc(self, w: Pc) -> Self {
+            let w = w as u8;
+            self.d.data[2] &= !(0x03);
+            self.d.data[2] |= w & 0x03;
+            self
+        }
+    
+    }
+    impl Default for DriverTimingControlA {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DriverTimingControlA {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DriverTimingControlA")
+                .field("now", &super::Field(r.now()))
+                .field("eq", &super::Field(r.eq()))
+                .field("cr", &super::Field(r.cr()))
+                .field("pc", &super::Field(r.pc()))
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod driver_timing_control_b {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct DriverTimingControlB {
+        pub(super) data: [u8; 2],
+    }
+    impl DriverTimingControlB {
+        /// The reset default
+        pub const fn new() -> Self {
+            DriverTimingControlB {
+                data: [ 0x66, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 2]) -> Self {
+            DriverTimingControlB { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 2] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 2] {
+            self.data
+        }
+        pub fn read(&self) -> DriverTimingControlBRead<'_> {
+            DriverTimingControlBRead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(DriverTimingControlBWrite) -> DriverTimingControlBWrite,
+        {
+            f(DriverTimingControlBWrite { d: self }).d
+        }
+    }
+    pub struct DriverTimingControlBRead<'l> {
+        d: &'l DriverTimingControlB,
+    }
+    impl<'l> DriverTimingControlBRead<'l> {
+        /// vg_sw_t4
+        #[inline(always)]
+        pub fn vg_sw_t4(&self) -> u8 {
+            (self.d.data[0] >> 6) & 0x03
+        }
+        /// vg_sw_t3
+        #[inline(always)]
+        pub fn vg_sw_t3(&self) -> u8 {
+            (self.d.data[0] >> 4) & 0x03
+        }
+        /// vg_sw_t2
+        #[inline(always)]
+        pub fn vg_sw_t2(&self) -> u8 {
+            (self.d.data[0] >> 2) & 0x03
+        }
+        /// vg_sw_t1
+        #[inline(always)]
+        pub fn vg_sw_t1(&self) -> u8 {
+            self.d.data[0] & 0x03
+        }
+    
+    }
+    pub struct DriverTimingControlBWrite<'l> {
+        d: &'l mut DriverTimingControlB,
+    }
+    impl<'l> DriverTimingControlBWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: DriverTimingControlB) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// vg_sw_t4
+        #[inline(always)]
+        pub fn vg_sw_t4(self, w: u8) -> Self {
+            self.d.data[0] &= !(0x03 << 6);
+            self.d.data[0] |= (w & 0x03) << 6;
+            self
+        }
+        /// vg_sw_t3
+        #[inline(always)]
+        pub fn vg_sw_t3(self, w: u8) -> Self {
+            self.d.data[0] &= !(0x03 << 4);
+            self.d.data[0] |= (w & 0x03) << 4;
+            self
+        }
+        /// vg_sw_t2
+        #[inline(always)]
+        pub fn vg_sw_t2(self, w: u8) -> Self {
+            self.d.data[0] &= !(0x03 << 2);
+            self.d.data[0] |= (w & 0x03) << 2;
+            self
+        }
+        /// vg_sw_t1
+        #[inline(always)]
+        pub fn vg_sw_t1(self, w: u8) -> Self {
+            self.d.data[0] &= !(0x03);
+            self.d.data[0] |= w & 0x03;
+            self
+        }
+    
+    }
+    impl Default for DriverTimingControlB {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for DriverTimingControlB {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("DriverTimingControlB")
+                .field("vg_sw_t4", &r.vg_sw_t4())
+                .field("vg_sw_t3", &r.vg_sw_t3())
+                .field("vg_sw_t2", &r.vg_sw_t2())
+                .field("vg_sw_t1", &r.vg_sw_t1())
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod power_on_sequence_control {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct PowerOnSequenceControl {
+        pub(super) data: [u8; 4],
+    }
+    impl PowerOnSequenceControl {
+        /// The reset default
+        pub const fn new() -> Self {
+            PowerOnSequenceControl {
+                data: [ 0x55, 0x01, 0x23, 0x01 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 4]) -> Self {
+            PowerOnSequenceControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 4] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 4] {
+            self.data
+        }
+        pub fn read(&self) -> PowerOnSequenceControlRead<'_> {
+            PowerOnSequenceControlRead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(PowerOnSequenceControlWrite) -> PowerOnSequenceControlWrite,
+        {
+            f(PowerOnSequenceControlWrite { d: self }).d
+        }
+    }
+    pub struct PowerOnSequenceControlRead<'l> {
+        d: &'l PowerOnSequenceControl,
+    }
+    impl<'l> PowerOnSequenceControlRead<'l> {
+        /// cp1
+        #[inline(always)]
+        pub fn cp1(&self) -> u8 {
+            (self.d.data[0] >> 4) & 0x03
+        }
+        /// cp23
+        #[inline(always)]
+        pub fn cp23(&self) -> u8 {
+            self.d.data[0] & 0x03
+        }
+        /// en_vcl
+        #[inline(always)]
+        pub fn en_vcl(&self) -> u8 {
+            (self.d.data[1] >> 4) & 0x03
+        }
+        /// en_ddvdh
+        #[inline(always)]
+        pub fn en_ddvdh(&self) -> u8 {
+            self.d.data[1] & 0x03
+        }
+        /// en_vgh
+        #[inline(always)]
+        pub fn en_vgh(&self) -> u8 {
+            (self.d.data[2] >> 4) & 0x03
+        }
+        /// en_vgl
+        #[inline(always)]
+        pub fn en_vgl(&self) -> u8 {
+            self.d.data[2] & 0x03
+        }
+        /// ddvdh_enh
+        #[inline(always)]
+        pub fn ddvdh_enh(&self) -> bool {
+            ((self.d.data[3] >> 7) & 0x01) != 0
+        }
+    
+    }
+    pub struct PowerOnSequenceControlWrite<'l> {
+        d: &'l mut PowerOnSequenceControl,
+    }
+    impl<'l> PowerOnSequenceControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PowerOnSequenceControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// cp1
+        #[inline(always)]
+        pub fn cp1(self, w: u8) -> Self {
+            self.d.data[0] &= !(0x03 << 4);
+            self.d.data[0] |= (w & 0x03) << 4;
+            self
+        }
+        /// cp23
+        #[inline(always)]
+        pub fn cp23(self, w: u8) -> Self {
+            self.d.data[0] &= !(0x03);
+            self.d.data[0] |= w & 0x03;
+            self
+        }
+        /// en_vcl
+        #[inline(always)]
+        pub fn en_vcl(self, w: u8) -> Self {
+            self.d.data[1] &= !(0x03 << 4);
+            self.d.data[1] |= (w & 0x03) << 4;
+            self
+        }
+        /// en_ddvdh
+        #[inline(always)]
+        pub fn en_ddvdh(self, w: u8) -> Self {
+            self.d.data[1] &= !(0x03);
+            self.d.data[1] |= w & 0x03;
+            self
+        }
+        /// en_vgh
+        #[inline(always)]
+        pub fn en_vgh(self, w: u8) -> Self {
+            self.d.data[2] &= !(0x03 << 4);
+            self.d.data[2] |= (w & 0x03) << 4;
+            self
+        }
+        /// en_vgl
+        #[inline(always)]
+        pub fn en_vgl(self, w: u8) -> Self {
+            self.d.data[2] &= !(0x03);
+            self.d.data[2] |= w & 0x03;
+            self
+        }
+        /// ddvdh_enh
+        #[inline(always)]
+        pub fn ddvdh_enh(self, w: bool) -> Self {
+            self.d.data[3] &= !(0x01 << 7);
+            self.d.data[3] |= ((w) as u8) << 7;
+            self
+        }
+    
+    }
+    impl Default for PowerOnSequenceControl {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PowerOnSequenceControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PowerOnSequenceControl")
+                .field("cp1", &r.cp1())
+                .field("cp23", &r.cp23())
+                .field("en_vcl", &r.en_vcl())
+                .field("en_ddvdh", &r.en_ddvdh())
+                .field("en_vgh", &r.en_vgh())
+                .field("en_vgl", &r.en_vgl())
+                .field("ddvdh_enh", &r.ddvdh_enh())
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod undocumented_ef {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct UndocumentedEf {
+        pub(super) data: [u8; 3],
+    }
+    impl UndocumentedEf {
+        /// The reset default
+        pub const fn new() -> Self {
+            UndocumentedEf {
+                data: [ 0x03, 0x80, 0x02 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            UndocumentedEf { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> UndocumentedEfRead<'_> {
+            UndocumentedEfRead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(UndocumentedEfWrite) -> UndocumentedEfWrite,
+        {
+            f(UndocumentedEfWrite { d: self }).d
+        }
+    }
+    pub struct UndocumentedEfRead<'l> {
+        d: &'l UndocumentedEf,
+    }
+    impl<'l> UndocumentedEfRead<'l> {
+        /// p1
+        #[inline(always)]
+        pub fn p1(&self) -> u8 {
+            self.d.data[0]
+        }
+        /// p2
+        #[inline(always)]
+        pub fn p2(&self) -> u8 {
+            self.d.data[1]
+        }
+        /// p3
+        #[inline(always)]
+        pub fn p3(&self) -> u8 {
+            self.d.data[2]
+        }
+    
+    }
+    pub struct UndocumentedEfWrite<'l> {
+        d: &'l mut UndocumentedEf,
+    }
+    impl<'l> UndocumentedEfWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: UndocumentedEf) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// p1
+        #[inline(always)]
+        pub fn p1(self, w: u8) -> Self {
+            self.d.data[0] = w;
+            self
+        }
+        /// p2
+        #[inline(always)]
+        pub fn p2(self, w: u8) -> Self {
+            self.d.data[1] = w;
+            self
+        }
+        /// p3
+        #[inline(always)]
+        pub fn p3(self, w: u8) -> Self {
+            self.d.data[2] = w;
+            self
+        }
+    
+    }
+    impl Default for UndocumentedEf {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for UndocumentedEf {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("UndocumentedEf")
+                .field("p1", &r.p1())
+                .field("p2", &r.p2())
+                .field("p3", &r.p3())
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod enable_3g {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct Enable3G {
+        pub(super) data: [u8; 1],
+    }
+    impl Enable3G {
+        /// The reset default
+        pub const fn new() -> Self {
+            Enable3G {
+                data: [ 0x02 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            Enable3G { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> Enable3GRead<'_> {
+            Enable3GRead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(Enable3GWrite) -> Enable3GWrite,
+        {
+            f(Enable3GWrite { d: self }).d
+        }
+    }
+    pub struct Enable3GRead<'l> {
+        d: &'l Enable3G,
+    }
+    impl<'l> Enable3GRead<'l> {
+        /// enable_3g
+        #[inline(always)]
+        pub fn enable_3g(&self) -> bool {
+            (self.d.data[0] & 0x01) != 0
+        }
+    
+    }
+    pub struct Enable3GWrite<'l> {
+        d: &'l mut Enable3G,
+    }
+    impl<'l> Enable3GWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: Enable3G) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// enable_3g
+        #[inline(always)]
+        pub fn enable_3g(self, w: bool) -> Self {
+            self.d.data[0] &= !(0x01);
+            self.d.data[0] |= (w) as u8;
+            self
+        }
+    
+    }
+    impl Default for Enable3G {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for Enable3G {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("Enable3G")
+                .field("enable_3g", &r.enable_3g())
+                .finish()
+        }
     }
 }
 #[cfg(feature = "Ili9341ExtendedCommandSet")]
@@ -5605,12 +7468,30 @@ pub mod interface_control {
         InterfaceForRamAccess(u8) => { SystemInterfaceOrVsyncInterface = 0x00, RgbInterface = 0x01 },
         RgbInterfaceMode(u8) => { N16Or18BitRgbInterface1TransferPerPixel = 0x00, N6BitRgbInterface3TransferPerPixel = 0x01 },
     }
-    #[derive(Copy, Clone, Debug)]
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
     pub struct InterfaceControl {
         pub(super) data: [u8; 3],
     }
     impl InterfaceControl {
-        pub fn read(&self) -> InterfaceControlRead {
+        /// The reset default
+        pub const fn new() -> Self {
+            InterfaceControl {
+                data: [ 0x01, 0x00, 0x00 ],
+            }
+        }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 3]) -> Self {
+            InterfaceControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 3] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 3] {
+            self.data
+        }
+        pub fn read(&self) -> InterfaceControlRead<'_> {
             InterfaceControlRead { d: self }
         }
         pub fn write<F>(&mut self, f: F) -> &mut Self
@@ -5685,6 +7566,12 @@ pub mod interface_control {
         d: &'l mut InterfaceControl,
     }
     impl<'l> InterfaceControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: InterfaceControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
         /// my_eor
         #[inline(always)]
         pub fn my_eor(self, w: bool) -> Self {
@@ -5771,10 +7658,222 @@ pub mod interface_control {
     }
     impl Default for InterfaceControl {
         fn default() -> Self {
-            InterfaceControl {
-                data: [ 0x01, 0x00, 0x00 ],
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for InterfaceControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("InterfaceControl")
+                .field("my_eor", &r.my_eor())
+                .field("mx_eor", &r.mx_eor())
+                .field("mv_eor", &r.mv_eor())
+                .field("bgr_eor", &r.bgr_eor())
+                .field("memory_write_control", &r.memory_write_control())
+                .field("expand16_bbp_rgbt_o18_bbp_rgb", &super::Field(r.expand16_bbp_rgbt_o18_bbp_rgb()))
+                .field("select_the_method_of_display_data_transferring", &r.select_the_method_of_display_data_transferring())
+                .field("data_transfer_mode", &super::Field(r.data_transfer_mode()))
+                .field("display_operation_mode", &super::Field(r.display_operation_mode()))
+                .field("interface_for_ram_access", &super::Field(r.interface_for_ram_access()))
+                .field("rgb_interface_mode", &super::Field(r.rgb_interface_mode()))
+                .finish()
+        }
+    }
+}
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+pub mod pump_ratio_control {
+    enum_with_from! {
+        Ratio(u8) => { N2xVci = 0x02, N3xVci = 0x03 },
+    }
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct PumpRatioControl {
+        pub(super) data: [u8; 1],
+    }
+    impl PumpRatioControl {
+        /// The reset default
+        pub const fn new() -> Self {
+            PumpRatioControl {
+                data: [ 0x10 ],
             }
         }
+        /// The register from its parameter bytes
+        pub const fn from_bytes(data: [u8; 1]) -> Self {
+            PumpRatioControl { data }
+        }
+        /// The parameter bytes
+        pub const fn as_bytes(&self) -> &[u8; 1] {
+            &self.data
+        }
+        /// The parameter bytes
+        pub const fn into_bytes(self) -> [u8; 1] {
+            self.data
+        }
+        pub fn read(&self) -> PumpRatioControlRead<'_> {
+            PumpRatioControlRead { d: self }
+        }
+        pub fn write<F>(&mut self, f: F) -> &mut Self
+        where
+            F: FnOnce(PumpRatioControlWrite) -> PumpRatioControlWrite,
+        {
+            f(PumpRatioControlWrite { d: self }).d
+        }
+    }
+    pub struct PumpRatioControlRead<'l> {
+        d: &'l PumpRatioControl,
+    }
+    impl<'l> PumpRatioControlRead<'l> {
+        /// ratio
+        #[inline(always)]
+        pub fn ratio(&self) -> Result<Ratio, u8> {
+            Ratio::try_from((self.d.data[0] >> 4) & 0x03)
+        }
+    
+    }
+    pub struct PumpRatioControlWrite<'l> {
+        d: &'l mut PumpRatioControl,
+    }
+    impl<'l> PumpRatioControlWrite<'l> {
+        /// All the fields of `r`
+        #[inline(always)]
+        pub fn copy_from(self, r: PumpRatioControl) -> Self {
+            self.d.data = r.data;
+            self
+        }
+        /// ratio
+        #[inline(always)]
+        pub fn ratio(self, w: Ratio) -> Self {
+            let w = w as u8;
+            self.d.data[0] &= !(0x03 << 4);
+            self.d.data[0] |= (w & 0x03) << 4;
+            self
+        }
+    
+    }
+    impl Default for PumpRatioControl {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl core::fmt::Display for PumpRatioControl {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let r = self.read();
+            f.debug_struct("PumpRatioControl")
+                .field("ratio", &super::Field(r.ratio()))
+                .finish()
+        }
     }
 }
 // autogenerated types end
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::convert::Infallible;
+    use std::vec::Vec;
+
+    use super::*;
+
+    /// Interface answering every read with a constant, or with a counter if `fill` is `None`
+    struct Pattern {
+        fill: Option<u8>,
+        next: u8,
+        /// The reads: command and number of bytes
+        reads: Vec<(u8, usize)>,
+    }
+
+    impl Pattern {
+        fn new(fill: Option<u8>) -> Pattern {
+            Pattern { fill, next: 0, reads: Vec::new() }
+        }
+    }
+
+    impl Interface for Pattern {
+        type Error = Infallible;
+
+        fn command(&mut self, _command: u8) -> Result<(), Infallible> {
+            Ok(())
+        }
+        fn send_parameters(&mut self, _command: u8, _data: &[u8]) -> Result<(), Infallible> {
+            Ok(())
+        }
+        fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Infallible> {
+            self.reads.push((command, data.len()));
+            for d in data {
+                *d = self.fill.unwrap_or(self.next);
+                self.next = self.next.wrapping_add(1);
+            }
+            Ok(())
+        }
+        fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, _command: u8, _data: T) -> Result<(), Infallible> {
+            Ok(())
+        }
+        fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, _command: u8, _data: T) -> Result<(), Infallible> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn read_u16_odd_length() {
+        let mut iface = Pattern::new(None);
+        let mut data = [0u16; 3];
+        iface.read_parameters_u16(0x2E, &mut data).unwrap();
+        assert_eq!(data, [0x0001, 0x0203, 0x0405]);
+        assert_eq!(iface.reads, [(0x2E, 6)]);
+    }
+
+    #[test]
+    fn read_u32_odd_length() {
+        let mut iface = Pattern::new(None);
+        let mut data = [0u32; 5];
+        iface.read_parameters_u32(0x2E, &mut data).unwrap();
+        let expected: Vec<u32> = (0..5u8).map(|i| bytes_to_word18([3 * i, 3 * i + 1, 3 * i + 2])).collect();
+        assert_eq!(data[..], expected[..]);
+        assert_eq!(iface.reads, [(0x2E, 15)]);
+    }
+
+    #[test]
+    fn read_u16_all_ff_across_chunks() {
+        let mut iface = Pattern::new(Some(0xFF));
+        let mut data = [0u16; 25];
+        iface.read_parameters_u16(0x2E, &mut data).unwrap();
+        assert_eq!(data, [0xFFFF; 25]);
+        assert_eq!(iface.reads, [(0x2E, 48), (0x3E, 2)]);
+    }
+
+    #[test]
+    fn read_u32_all_ff_across_chunks() {
+        let mut iface = Pattern::new(Some(0xFF));
+        let mut data = [0u32; 33];
+        iface.read_parameters_u32(0x3E, &mut data).unwrap();
+        assert_eq!(data, [0x3FFFF; 33]);
+        assert_eq!(iface.reads, [(0x3E, 48), (0x3E, 48), (0x3E, 3)]);
+    }
+
+    #[test]
+    fn read_u32_keeps_the_order_across_chunks() {
+        let mut iface = Pattern::new(None);
+        let mut data = [0u32; 17];
+        iface.read_parameters_u32(0x2E, &mut data).unwrap();
+        assert_eq!(data[16], bytes_to_word18([48, 49, 50]));
+    }
+
+    #[test]
+    fn register_read_u16_longer_than_a_chunk() {
+        let mut iface = Pattern::new(None);
+        let mut data = [0u16; 25];
+        iface.read_parameters_u16(0xE0, &mut data).unwrap();
+        // Clamped to one chunk
+        assert_eq!(iface.reads, [(0xE0, 48)]);
+        assert_eq!(data[23], u16::from_be_bytes([46, 47]));
+        assert_eq!(data[24], 0);
+    }
+
+    #[test]
+    fn word18_round_trip() {
+        for w in [0, 0x3FFFF, 0x2A555, 0x00FC0] {
+            assert_eq!(bytes_to_word18(word18_to_bytes(w)), w);
+        }
+        assert_eq!(word18_to_bytes(0x3F03F), [0xFC, 0x00, 0xFC]);
+    }
+}
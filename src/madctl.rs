@@ -0,0 +1,95 @@
+//! Memory access control value
+//!
+//! The MADCTL register is written with Memory Access Control (36h) and read back with Read
+//! Display MADCTL (0Bh), whose generated types differ. [`Madctl`] converts from and into both,
+//! so a written value can be compared with the one read back:
+//!
+//! ```
+//! # use ili9341_cmd::madctl::Madctl;
+//! let written = Madctl { mx: true, bgr: true, ..Madctl::default() };
+//! assert_eq!(u8::from(written), 0x48);
+//! assert_eq!(Madctl::from(0x48), written);
+//! ```
+
+use embedded_hal::delay::DelayNs;
+
+use crate::memory_access_control::MemoryAccessControl;
+use crate::read_display_madctl::DisplayMadctl;
+use crate::shadow::RegisterCache;
+use crate::{Controller, Interface};
+
+/// Content of the MADCTL register
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Madctl {
+    /// Row address order (MY, B7), bottom to top
+    pub my: bool,
+    /// Column address order (MX, B6), right to left
+    pub mx: bool,
+    /// Row/column exchange (MV, B5)
+    pub mv: bool,
+    /// Vertical refresh order (ML, B4), LCD refresh bottom to top
+    pub ml: bool,
+    /// RGB-BGR order (BGR, B3), BGR color filter panel
+    pub bgr: bool,
+    /// Horizontal refresh order (MH, B2), LCD refresh right to left
+    pub mh: bool,
+}
+
+impl From<u8> for Madctl {
+    fn from(b: u8) -> Self {
+        Madctl {
+            my: b & 0x80 != 0,
+            mx: b & 0x40 != 0,
+            mv: b & 0x20 != 0,
+            ml: b & 0x10 != 0,
+            bgr: b & 0x08 != 0,
+            mh: b & 0x04 != 0,
+        }
+    }
+}
+
+impl From<Madctl> for u8 {
+    fn from(m: Madctl) -> Self {
+        (m.my as u8) << 7 | (m.mx as u8) << 6 | (m.mv as u8) << 5 | (m.ml as u8) << 4 | (m.bgr as u8) << 3 | (m.mh as u8) << 2
+    }
+}
+
+impl From<MemoryAccessControl> for Madctl {
+    fn from(r: MemoryAccessControl) -> Self {
+        Madctl::from(r.data[0])
+    }
+}
+
+impl From<Madctl> for MemoryAccessControl {
+    fn from(m: Madctl) -> Self {
+        MemoryAccessControl { data: [m.into()] }
+    }
+}
+
+impl From<DisplayMadctl> for Madctl {
+    fn from(r: DisplayMadctl) -> Self {
+        Madctl::from(r.data[0])
+    }
+}
+
+impl From<Madctl> for DisplayMadctl {
+    fn from(m: Madctl) -> Self {
+        DisplayMadctl { data: [m.into()] }
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Memory Access Control (36h)
+    pub fn write_madctl(&mut self, madctl: Madctl) -> Result<(), Iface::Error> {
+        self.send_parameters(0x36, &[madctl.into()])
+    }
+    /// Read Display MADCTL (0Bh)
+    pub fn read_madctl(&mut self) -> Result<Madctl, Iface::Error> {
+        self.read_display_madctl().map(Madctl::from)
+    }
+}
@@ -0,0 +1,271 @@
+//! Recording mock of the Interface trait for host-side tests
+//!
+//! The MockInterface records every command as a [`Transaction`] and serves reads from a queue of
+//! scripted responses. Clones share the transcript and the responses, so a test can hand one
+//! clone to the Controller and check the transcript through another one.
+//!
+//! ```
+//! use ili9341_cmd::Controller;
+//! use ili9341_cmd::mock::MockInterface;
+//!
+//! let mock = MockInterface::new();
+//! mock.push_response(0x0B, &[0x48]);
+//!
+//! let mut lcd = Controller::new(mock.clone());
+//! lcd.sleep_out().unwrap();
+//! lcd.memory_access_control(|w| w.column_address_order(true).rgb_bgr_order(true)).unwrap();
+//! let madctl = lcd.read_display_madctl().unwrap();
+//! assert!(madctl.read().column_address_order().is_ok());
+//!
+//! mock.expect_command(0x11)
+//!     .expect_parameters(0x36, &[0x48])
+//!     .expect_read(0x0B, 1)
+//!     .done();
+//! ```
+
+extern crate std;
+
+use core::convert::Infallible;
+use core::task::{Context, Poll};
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use crate::dma::{DmaInterface, TransferError};
+use crate::Interface;
+
+/// A transaction recorded by the MockInterface
+#[derive(Debug, Clone, PartialEq)]
+pub enum Transaction {
+    /// Command without parameters
+    Command(u8),
+    /// Command with parameters
+    Write(u8, Vec<u8>),
+    /// Read command and the number of bytes read
+    Read(u8, usize),
+}
+
+#[derive(Default)]
+struct State {
+    transcript: Vec<Transaction>,
+    /// Index of the next transaction to be checked by the expect_* helpers
+    checked: usize,
+    responses: VecDeque<(u8, Vec<u8>)>,
+    /// Buffer of the background transfer
+    transfer: Option<&'static mut [u8]>,
+}
+
+/// Mock interface, see the [module documentation](self)
+#[derive(Clone, Default)]
+pub struct MockInterface {
+    state: Rc<RefCell<State>>,
+}
+
+impl MockInterface {
+    pub fn new() -> MockInterface {
+        MockInterface::default()
+    }
+
+    /// Queue the response to the next read, which has to be a read of `command`
+    pub fn push_response(&self, command: u8, data: &[u8]) -> &Self {
+        self.state.borrow_mut().responses.push_back((command, data.to_vec()));
+        self
+    }
+    /// All the recorded transactions
+    pub fn transcript(&self) -> Vec<Transaction> {
+        self.state.borrow().transcript.clone()
+    }
+    /// Forget the recorded transactions and the queued responses
+    pub fn clear(&self) {
+        *self.state.borrow_mut() = State::default();
+    }
+
+    /// Check the next recorded transaction
+    /// Panics if it does not match `expected`.
+    pub fn expect(&self, expected: Transaction) -> &Self {
+        let mut state = self.state.borrow_mut();
+        let i = state.checked;
+        match state.transcript.get(i) {
+            Some(t) if *t == expected => state.checked += 1,
+            Some(t) => panic!("transaction {}: expected {:02X?}, got {:02X?}", i, expected, t),
+            None => panic!("transaction {}: expected {:02X?}, got nothing", i, expected),
+        }
+        self
+    }
+    /// Expect a command without parameters, e.g. `expect_command(0x11)`
+    pub fn expect_command(&self, command: u8) -> &Self {
+        self.expect(Transaction::Command(command))
+    }
+    /// Expect a command with parameters, e.g. `expect_parameters(0x36, &[0x48])`
+    pub fn expect_parameters(&self, command: u8, data: &[u8]) -> &Self {
+        self.expect(Transaction::Write(command, data.to_vec()))
+    }
+    /// Expect a read of `len` bytes
+    pub fn expect_read(&self, command: u8, len: usize) -> &Self {
+        self.expect(Transaction::Read(command, len))
+    }
+    /// Check that every transaction was expected and every response was read
+    pub fn done(&self) {
+        let state = self.state.borrow();
+        if let Some(t) = state.transcript.get(state.checked) {
+            panic!("transaction {}: unexpected {:02X?}", state.checked, t);
+        }
+        if let Some((command, _)) = state.responses.front() {
+            panic!("response to {:02X} was never read", command);
+        }
+    }
+
+    fn record(&self, t: Transaction) {
+        self.state.borrow_mut().transcript.push(t);
+    }
+    fn respond(&self, command: u8, len: usize) -> Vec<u8> {
+        let mut state = self.state.borrow_mut();
+        state.transcript.push(Transaction::Read(command, len));
+        match state.responses.pop_front() {
+            Some((c, data)) if c == command && data.len() == len => data,
+            Some((c, data)) => panic!("read of {} bytes from {:02X}, but the response is {:02X?} from {:02X}", len, command, data, c),
+            None => panic!("read of {} bytes from {:02X}, but no response is queued", len, command),
+        }
+    }
+}
+
+impl Interface for MockInterface {
+    type Error = Infallible;
+
+    fn command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.record(Transaction::Command(command));
+        Ok(())
+    }
+    fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.record(Transaction::Write(command, data.to_vec()));
+        Ok(())
+    }
+    fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        data.copy_from_slice(&self.respond(command, data.len()));
+        Ok(())
+    }
+
+    fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        self.record(Transaction::Write(command, data.into_iter().collect()));
+        Ok(())
+    }
+    fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        let data: Vec<&mut u8> = data.into_iter().collect();
+        let response = self.respond(command, data.len());
+        for (d, r) in data.into_iter().zip(response) {
+            *d = r;
+        }
+        Ok(())
+    }
+}
+
+/// Background transfers are recorded when they are started and finish immediately
+impl DmaInterface for MockInterface {
+    fn start_send_parameters(&mut self, command: u8, buffer: &'static mut [u8]) -> Result<(), TransferError<Self::Error>> {
+        self.record(Transaction::Write(command, buffer.to_vec()));
+        self.state.borrow_mut().transfer = Some(buffer);
+        Ok(())
+    }
+    fn poll_transfer(&mut self, _cx: &mut Context<'_>) -> Poll<Result<&'static mut [u8], TransferError<Self::Error>>> {
+        match self.state.borrow_mut().transfer.take() {
+            Some(buffer) => Poll::Ready(Ok(buffer)),
+            None => Poll::Pending,
+        }
+    }
+    fn abort_transfer(&mut self) -> Option<&'static mut [u8]> {
+        self.state.borrow_mut().transfer.take()
+    }
+}
+
+#[cfg(feature = "async")]
+impl crate::asynch::AsyncInterface for MockInterface {
+    type Error = Infallible;
+
+    async fn command(&mut self, command: u8) -> Result<(), Self::Error> {
+        Interface::command(self, command)
+    }
+    async fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error> {
+        Interface::send_parameters(self, command, data)
+    }
+    async fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        Interface::read_parameters(self, command, data)
+    }
+
+    async fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        Interface::send_parameters_iter(self, command, data)
+    }
+    async fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        Interface::read_parameters_iter(self, command, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Controller;
+
+    #[test]
+    fn clones_share_the_transcript() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        mock.push_response(0x0C, &[0x66]);
+        lcd.display_on().unwrap();
+        lcd.memory_write_iter([0x01, 0x02]).unwrap();
+        lcd.read_display_pixel_format().unwrap();
+        assert_eq!(
+            mock.transcript(),
+            [Transaction::Command(0x29), Transaction::Write(0x2C, std::vec![0x01, 0x02]), Transaction::Read(0x0C, 1)]
+        );
+
+        mock.clear();
+        assert!(mock.transcript().is_empty());
+        lcd.idle_mode_on().unwrap();
+        mock.expect_command(0x39).done();
+    }
+
+    #[test]
+    fn iterator_reads_are_served() {
+        let mock = MockInterface::new();
+        mock.push_response(0x2E, &[0x10, 0x20, 0x30]);
+        let mut lcd = Controller::new(mock.clone());
+        let mut data = [0; 3];
+        lcd.memory_read_iter(data.iter_mut()).unwrap();
+        assert_eq!(data, [0x10, 0x20, 0x30]);
+        mock.expect_read(0x2E, 3).done();
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction 0: expected")]
+    fn mismatch_panics() {
+        let mock = MockInterface::new();
+        Controller::new(mock.clone()).display_off().unwrap();
+        mock.expect_command(0x29);
+    }
+
+    #[test]
+    #[should_panic(expected = "transaction 1: unexpected")]
+    fn unexpected_transaction_panics() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        lcd.display_on().unwrap();
+        lcd.display_on().unwrap();
+        mock.expect_command(0x29).done();
+    }
+
+    #[test]
+    #[should_panic(expected = "response to 0A was never read")]
+    fn unread_response_panics() {
+        let mock = MockInterface::new();
+        mock.push_response(0x0A, &[0x9C]);
+        mock.done();
+    }
+
+    #[test]
+    #[should_panic(expected = "but the response is")]
+    fn response_to_another_command_panics() {
+        let mock = MockInterface::new();
+        mock.push_response(0x0A, &[0x9C]);
+        Controller::new(mock.clone()).read_display_madctl().unwrap();
+    }
+}
@@ -0,0 +1,121 @@
+//! Display orientation
+//!
+//! [`Controller::set_orientation`] programs the scanning direction of the frame memory (MADCTL,
+//! 36h) for an [`Orientation`] as seen on the usual modules, instead of the raw MY/MX/MV bits:
+//!
+//! | Orientation      | MADCTL (RGB) | MADCTL (BGR) | Size    |
+//! |------------------|--------------|--------------|---------|
+//! | Portrait         | 40h          | 48h          | 240x320 |
+//! | Landscape        | 20h          | 28h          | 320x240 |
+//! | PortraitFlipped  | 80h          | 88h          | 240x320 |
+//! | LandscapeFlipped | E0h          | E8h          | 320x240 |
+//!
+//! The mirrored variants flip the image left to right by toggling MX.
+
+use embedded_hal::delay::DelayNs;
+
+use crate::madctl::Madctl;
+use crate::shadow::RegisterCache;
+use crate::{Controller, Interface};
+
+/// Width of the frame memory in portrait orientation
+const WIDTH: u16 = 240;
+/// Height of the frame memory in portrait orientation
+const HEIGHT: u16 = 320;
+
+/// Scanning direction of the frame memory, as seen on the usual modules
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Orientation {
+    /// 240x320, MADCTL MX
+    Portrait,
+    /// 320x240, MADCTL MV
+    Landscape,
+    /// 240x320 rotated by 180°, MADCTL MY
+    PortraitFlipped,
+    /// 320x240 rotated by 180°, MADCTL MY, MX and MV
+    LandscapeFlipped,
+    /// Portrait mirrored left to right
+    PortraitMirrored,
+    /// Landscape mirrored left to right
+    LandscapeMirrored,
+    /// PortraitFlipped mirrored left to right
+    PortraitFlippedMirrored,
+    /// LandscapeFlipped mirrored left to right
+    LandscapeFlippedMirrored,
+}
+
+impl Orientation {
+    /// MADCTL of the orientation, `bgr` if the panel has a BGR color filter
+    pub fn madctl(self, bgr: bool) -> Madctl {
+        let (my, mx, mv) = match self {
+            Orientation::Portrait => (false, true, false),
+            Orientation::Landscape => (false, false, true),
+            Orientation::PortraitFlipped => (true, false, false),
+            Orientation::LandscapeFlipped => (true, true, true),
+            Orientation::PortraitMirrored => (false, false, false),
+            Orientation::LandscapeMirrored => (false, true, true),
+            Orientation::PortraitFlippedMirrored => (true, true, false),
+            Orientation::LandscapeFlippedMirrored => (true, false, true),
+        };
+        Madctl { my, mx, mv, bgr, ..Madctl::default() }
+    }
+    /// Logical width and height in pixels
+    pub fn size(self) -> (u16, u16) {
+        if self.madctl(false).mv {
+            (HEIGHT, WIDTH)
+        } else {
+            (WIDTH, HEIGHT)
+        }
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Memory Access Control (36h) for `orientation`, `bgr` if the panel has a BGR color filter
+    /// Returns the logical width and height. The vertical refresh order (ML) and horizontal
+    /// refresh order (MH) are reset.
+    pub fn set_orientation(&mut self, orientation: Orientation, bgr: bool) -> Result<(u16, u16), Iface::Error> {
+        self.write_madctl(orientation.madctl(bgr))?;
+        Ok(orientation.size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInterface;
+
+    #[test]
+    fn madctl_of_every_orientation() {
+        let table = [
+            (Orientation::Portrait, 0x40, (240, 320)),
+            (Orientation::Landscape, 0x20, (320, 240)),
+            (Orientation::PortraitFlipped, 0x80, (240, 320)),
+            (Orientation::LandscapeFlipped, 0xE0, (320, 240)),
+            (Orientation::PortraitMirrored, 0x00, (240, 320)),
+            (Orientation::LandscapeMirrored, 0x60, (320, 240)),
+            (Orientation::PortraitFlippedMirrored, 0xC0, (240, 320)),
+            (Orientation::LandscapeFlippedMirrored, 0xA0, (320, 240)),
+        ];
+        for (orientation, madctl, size) in table {
+            let mock = MockInterface::new();
+            let mut lcd = Controller::new(mock.clone());
+            assert_eq!(lcd.set_orientation(orientation, false).unwrap(), size);
+            assert_eq!(lcd.set_orientation(orientation, true).unwrap(), size);
+            mock.expect_parameters(0x36, &[madctl]).expect_parameters(0x36, &[madctl | 0x08]).done();
+        }
+    }
+
+    #[test]
+    fn read_back() {
+        let mock = MockInterface::new();
+        mock.push_response(0x0B, &[0xE8]);
+        let mut lcd = Controller::new(mock.clone());
+        assert_eq!(lcd.read_madctl().unwrap(), Orientation::LandscapeFlipped.madctl(true));
+        mock.expect_read(0x0B, 1).done();
+    }
+}
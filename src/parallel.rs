@@ -0,0 +1,549 @@
+//! MCU 8080-I parallel bus implementations of the Interface trait
+
+use embedded_hal::digital::{ErrorType, InputPin, OutputPin, PinState};
+
+use crate::{bytes_to_word18, word18_to_bytes, words16_to_words18, words18_to_words16, Interface};
+
+/// A data pin which can be switched between output and input
+///
+/// embedded-hal 1.0 has no trait for changing the pin direction at runtime, implement it on the
+/// flexible/dynamic pin type of the HAL.
+pub trait IoPin: OutputPin + InputPin {
+    /// Configure the pin as input
+    fn set_as_input(&mut self) -> Result<(), Self::Error>;
+    /// Configure the pin as output
+    fn set_as_output(&mut self) -> Result<(), Self::Error>;
+}
+
+/// 8080-I parallel interface over `N` GPIO data pins (8, 16 or 18)
+///
+/// Data is written on the rising edge of WRX and read while RDX is low, D/CX selects between
+/// command (low) and parameter (high). CSX is held low for the whole command.
+/// All the pins have to share the same error type.
+///
+/// Commands and parameters use D[7:0]. The word transfers (`send_parameters_u16`, ...) move one
+/// word per bus cycle if the bus is wide enough, so on a 16-bit (18-bit) bus the frame memory has
+/// to be accessed with the u16 (u32) variants. On a 16-bit bus `send_parameters_u32` and
+/// `read_parameters_u32` move two 18-bit words in three cycles, 6 bits each on D[15:10] and
+/// D[7:2] (R1 G1, B1 R2, G2 B2); a last odd word takes two cycles.
+///
+/// Note: The GPIO timing is not checked. On fast MCUs the frame memory read access time
+/// (tRDLFM = 340 ns) may require slowing down the pin accesses.
+pub struct ParallelInterface<D, WR, RD, DC, CS, const N: usize> {
+    /// D[N-1:0]
+    data: [D; N],
+    wr: WR,
+    rd: RD,
+    dc: DC,
+    cs: CS,
+    /// Current direction of the data pins
+    output: bool,
+}
+
+/// 8-bit 8080-I parallel interface (D[7:0])
+pub type Parallel8Interface<D, WR, RD, DC, CS> = ParallelInterface<D, WR, RD, DC, CS, 8>;
+/// 16-bit 8080-I parallel interface (D[15:0])
+pub type Parallel16Interface<D, WR, RD, DC, CS> = ParallelInterface<D, WR, RD, DC, CS, 16>;
+/// 18-bit 8080-I parallel interface (D[17:0])
+pub type Parallel18Interface<D, WR, RD, DC, CS> = ParallelInterface<D, WR, RD, DC, CS, 18>;
+
+impl<D, WR, RD, DC, CS, E, const N: usize> ParallelInterface<D, WR, RD, DC, CS, N>
+where
+    D: IoPin + ErrorType<Error = E>,
+    WR: OutputPin + ErrorType<Error = E>,
+    RD: OutputPin + ErrorType<Error = E>,
+    DC: OutputPin + ErrorType<Error = E>,
+    CS: OutputPin + ErrorType<Error = E>,
+{
+    /// Create the interface, the data pins are switched to output and the control pins to idle (high)
+    pub fn new(data: [D; N], wr: WR, rd: RD, dc: DC, cs: CS) -> Result<ParallelInterface<D, WR, RD, DC, CS, N>, E> {
+        let mut iface = ParallelInterface { data, wr, rd, dc, cs, output: false };
+        iface.cs.set_high()?;
+        iface.wr.set_high()?;
+        iface.rd.set_high()?;
+        iface.dc.set_high()?;
+        iface.set_output()?;
+        Ok(iface)
+    }
+
+    /// Release the pins
+    pub fn release(self) -> ([D; N], WR, RD, DC, CS) {
+        (self.data, self.wr, self.rd, self.dc, self.cs)
+    }
+
+    fn set_output(&mut self) -> Result<(), E> {
+        if !self.output {
+            for pin in self.data.iter_mut() {
+                pin.set_as_output()?;
+            }
+            self.output = true;
+        }
+        Ok(())
+    }
+    fn set_input(&mut self) -> Result<(), E> {
+        if self.output {
+            for pin in self.data.iter_mut() {
+                pin.set_as_input()?;
+            }
+            self.output = false;
+        }
+        Ok(())
+    }
+
+    /// One write cycle, the data is latched on the rising edge of WRX
+    fn write_cycle(&mut self, word: u32) -> Result<(), E> {
+        for (i, pin) in self.data.iter_mut().enumerate() {
+            pin.set_state(PinState::from((word >> i) & 0x01 != 0))?;
+        }
+        self.wr.set_low()?;
+        self.wr.set_high()
+    }
+    /// One read cycle, the data is sampled while RDX is low
+    fn read_cycle(&mut self) -> Result<u32, E> {
+        self.rd.set_low()?;
+        let mut word = 0;
+        for (i, pin) in self.data.iter_mut().enumerate() {
+            word |= (pin.is_high()? as u32) << i;
+        }
+        self.rd.set_high()?;
+        Ok(word)
+    }
+
+    /// Run a command, `body` transfers its parameters
+    /// A read switches the data pins to input and skips the dummy cycle before `body`. CSX is
+    /// released and the data pins are switched back to output even if a step fails, the first
+    /// error is returned.
+    fn transaction<F>(&mut self, command: u8, read: bool, body: F) -> Result<(), E>
+    where
+        F: FnOnce(&mut Self) -> Result<(), E>,
+    {
+        let result = self.start(command, read).and_then(|()| body(self));
+        if result.is_err() {
+            // A failed cycle may have left a strobe low, the first error is the one returned
+            let _ = self.rd.set_high();
+            let _ = self.wr.set_high();
+        }
+        let end = self.end();
+        result.and(end)
+    }
+    /// Select the chip and send the command, then read the dummy cycle of a read
+    fn start(&mut self, command: u8, read: bool) -> Result<(), E> {
+        self.set_output()?;
+        self.cs.set_low()?;
+        self.dc.set_low()?;
+        self.write_cycle(command as u32)?;
+        self.dc.set_high()?;
+        if read {
+            self.set_input()?;
+            self.read_cycle()?;
+        }
+        Ok(())
+    }
+    /// Release the chip and drive the data pins again
+    fn end(&mut self) -> Result<(), E> {
+        let cs = self.cs.set_high();
+        self.set_output().and(cs)
+    }
+}
+
+impl<D, WR, RD, DC, CS, E, const N: usize> Interface for ParallelInterface<D, WR, RD, DC, CS, N>
+where
+    D: IoPin + ErrorType<Error = E>,
+    WR: OutputPin + ErrorType<Error = E>,
+    RD: OutputPin + ErrorType<Error = E>,
+    DC: OutputPin + ErrorType<Error = E>,
+    CS: OutputPin + ErrorType<Error = E>,
+{
+    type Error = E;
+
+    fn command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.transaction(command, false, |_| Ok(()))
+    }
+    fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.send_parameters_iter(command, data.iter().copied())
+    }
+    /// Read parameters into a slice
+    ///
+    /// The data pins are switched to input after the command and the dummy read cycle is skipped.
+    fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        self.read_parameters_iter(command, data.iter_mut())
+    }
+
+    fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        self.transaction(command, false, |bus| {
+            for d in data {
+                bus.write_cycle(d as u32)?;
+            }
+            Ok(())
+        })
+    }
+    /// Read parameters into an iterator
+    ///
+    /// The data pins are switched to input after the command and the dummy read cycle is skipped.
+    fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        self.transaction(command, true, |bus| {
+            for d in data {
+                *d = bus.read_cycle()? as u8;
+            }
+            Ok(())
+        })
+    }
+
+    fn send_parameters_u16(&mut self, command: u8, data: &[u16]) -> Result<(), Self::Error> {
+        if N < 16 {
+            return self.send_parameters_iter(command, data.iter().flat_map(|w| w.to_be_bytes()));
+        }
+        self.transaction(command, false, |bus| {
+            for &w in data {
+                bus.write_cycle(w as u32)?;
+            }
+            Ok(())
+        })
+    }
+    fn send_parameters_u32(&mut self, command: u8, data: &[u32]) -> Result<(), Self::Error> {
+        if N < 16 {
+            return self.send_parameters_iter(command, data.iter().flat_map(|&w| word18_to_bytes(w)));
+        }
+        self.transaction(command, false, |bus| {
+            if N < 18 {
+                for pair in data.chunks(2) {
+                    let cycles = words18_to_words16(pair[0], pair.get(1).copied().unwrap_or(0));
+                    for &c in &cycles[..pair.len() + 1] {
+                        bus.write_cycle(c as u32)?;
+                    }
+                }
+            } else {
+                for &w in data {
+                    bus.write_cycle(w)?;
+                }
+            }
+            Ok(())
+        })
+    }
+    fn read_parameters_u16(&mut self, command: u8, data: &mut [u16]) -> Result<(), Self::Error> {
+        self.transaction(command, true, |bus| {
+            for w in data.iter_mut() {
+                *w = if N < 16 {
+                    let hi = bus.read_cycle()? as u8;
+                    let lo = bus.read_cycle()? as u8;
+                    u16::from_be_bytes([hi, lo])
+                } else {
+                    bus.read_cycle()? as u16
+                };
+            }
+            Ok(())
+        })
+    }
+    fn read_parameters_u32(&mut self, command: u8, data: &mut [u32]) -> Result<(), Self::Error> {
+        self.transaction(command, true, |bus| {
+            if N < 16 {
+                for w in data.iter_mut() {
+                    let mut b = [0; 3];
+                    for b in b.iter_mut() {
+                        *b = bus.read_cycle()? as u8;
+                    }
+                    *w = bytes_to_word18(b);
+                }
+            } else if N < 18 {
+                // Same packing as the writes, the odd last word takes two cycles
+                for pair in data.chunks_mut(2) {
+                    let mut c = [0; 3];
+                    for c in c[..pair.len() + 1].iter_mut() {
+                        *c = bus.read_cycle()? as u16;
+                    }
+                    let words = words16_to_words18(c);
+                    pair.copy_from_slice(&words[..pair.len()]);
+                }
+            } else {
+                for w in data.iter_mut() {
+                    *w = bus.read_cycle()? & 0x3FFFF;
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use std::vec::Vec;
+
+    use embedded_hal::digital::ErrorKind;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Fault;
+
+    impl embedded_hal::digital::Error for Fault {
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Cycle {
+        Command(u32),
+        Write(u32),
+        Read,
+    }
+
+    /// The panel side of the bus
+    struct Bus {
+        /// Number of data pins
+        width: usize,
+        /// D[17:0] as driven by the interface
+        levels: u32,
+        /// Data pins switched to input
+        inputs: u32,
+        dc: bool,
+        wr: bool,
+        rd: bool,
+        cs: bool,
+        cycles: Vec<Cycle>,
+        /// Data put on the bus by the read cycles, the dummy cycle included
+        responses: VecDeque<u32>,
+        response: u32,
+        /// Index of the read cycle whose RDX falling edge fails
+        fail_read: Option<usize>,
+        reads: usize,
+    }
+
+    #[derive(Clone, Copy)]
+    enum Role {
+        Data(usize),
+        Wr,
+        Rd,
+        Dc,
+        Cs,
+    }
+
+    /// Any pin of the bus
+    struct Pin {
+        bus: Rc<RefCell<Bus>>,
+        role: Role,
+    }
+
+    impl ErrorType for Pin {
+        type Error = Fault;
+    }
+    impl OutputPin for Pin {
+        fn set_low(&mut self) -> Result<(), Fault> {
+            self.set_state(PinState::Low)
+        }
+        fn set_high(&mut self) -> Result<(), Fault> {
+            self.set_state(PinState::High)
+        }
+        fn set_state(&mut self, state: PinState) -> Result<(), Fault> {
+            let high = state == PinState::High;
+            let mut bus = self.bus.borrow_mut();
+            let all = (1 << bus.width) - 1;
+            match self.role {
+                Role::Data(i) => bus.levels = (bus.levels & !(1 << i)) | ((high as u32) << i),
+                Role::Wr => {
+                    if high && !bus.wr && !bus.cs {
+                        assert_eq!(bus.inputs, 0, "write cycle with data pins in input mode");
+                        let cycle = if bus.dc { Cycle::Write(bus.levels) } else { Cycle::Command(bus.levels) };
+                        bus.cycles.push(cycle);
+                    }
+                    bus.wr = high;
+                }
+                Role::Rd => {
+                    if !high && bus.rd && !bus.cs {
+                        bus.reads += 1;
+                        if bus.fail_read == Some(bus.reads - 1) {
+                            return Err(Fault);
+                        }
+                        assert_eq!(bus.inputs, all, "read cycle with data pins in output mode");
+                        bus.response = bus.responses.pop_front().expect("no response left");
+                        bus.cycles.push(Cycle::Read);
+                    }
+                    bus.rd = high;
+                }
+                Role::Dc => bus.dc = high,
+                Role::Cs => bus.cs = high,
+            }
+            Ok(())
+        }
+    }
+    impl InputPin for Pin {
+        fn is_high(&mut self) -> Result<bool, Fault> {
+            match self.role {
+                Role::Data(i) => Ok((self.bus.borrow().response >> i) & 0x01 != 0),
+                _ => unreachable!(),
+            }
+        }
+        fn is_low(&mut self) -> Result<bool, Fault> {
+            self.is_high().map(|h| !h)
+        }
+    }
+    impl IoPin for Pin {
+        fn set_as_input(&mut self) -> Result<(), Fault> {
+            if let Role::Data(i) = self.role {
+                self.bus.borrow_mut().inputs |= 1 << i;
+            }
+            Ok(())
+        }
+        fn set_as_output(&mut self) -> Result<(), Fault> {
+            if let Role::Data(i) = self.role {
+                self.bus.borrow_mut().inputs &= !(1 << i);
+            }
+            Ok(())
+        }
+    }
+
+    type Fake<const N: usize> = ParallelInterface<Pin, Pin, Pin, Pin, Pin, N>;
+
+    fn interface<const N: usize>() -> (Fake<N>, Rc<RefCell<Bus>>) {
+        let bus = Rc::new(RefCell::new(Bus {
+            width: N,
+            levels: 0,
+            inputs: 0,
+            dc: true,
+            wr: true,
+            rd: true,
+            cs: true,
+            cycles: Vec::new(),
+            responses: VecDeque::new(),
+            response: 0,
+            fail_read: None,
+            reads: 0,
+        }));
+        let pin = |role| Pin { bus: bus.clone(), role };
+        let data = core::array::from_fn(|i| pin(Role::Data(i)));
+        let iface = ParallelInterface::new(data, pin(Role::Wr), pin(Role::Rd), pin(Role::Dc), pin(Role::Cs)).unwrap();
+        (iface, bus)
+    }
+
+    /// The parameter cycles of the single command sent
+    fn parameters(bus: &Rc<RefCell<Bus>>) -> Vec<u32> {
+        let bus = bus.borrow();
+        assert!(matches!(bus.cycles.first(), Some(Cycle::Command(_))));
+        bus.cycles[1..]
+            .iter()
+            .map(|&c| match c {
+                Cycle::Write(w) => w,
+                c => panic!("unexpected {:?}", c),
+            })
+            .collect()
+    }
+
+    /// Check that the bus is released: CSX and RDX high, the data pins driven
+    fn assert_idle(bus: &Rc<RefCell<Bus>>) {
+        let bus = bus.borrow();
+        assert!(bus.cs && bus.rd && bus.wr);
+        assert_eq!(bus.inputs, 0);
+    }
+
+    const PIXELS: [u32; 3] = [0x3F001, 0x00FC2, 0x2A815];
+
+    #[test]
+    fn command_and_parameters() {
+        let (mut iface, bus) = interface::<8>();
+        iface.command(0x29).unwrap();
+        iface.send_parameters(0x2A, &[0x00, 0x01, 0x00, 0xEF]).unwrap();
+        assert_eq!(
+            bus.borrow().cycles,
+            [
+                Cycle::Command(0x29),
+                Cycle::Command(0x2A),
+                Cycle::Write(0x00),
+                Cycle::Write(0x01),
+                Cycle::Write(0x00),
+                Cycle::Write(0xEF)
+            ]
+        );
+        assert_idle(&bus);
+    }
+
+    #[test]
+    fn read_skips_the_dummy_cycle() {
+        let (mut iface, bus) = interface::<8>();
+        bus.borrow_mut().responses.extend([0xAA, 0x93, 0x41]);
+        let mut data = [0; 2];
+        iface.read_parameters(0xD3, &mut data).unwrap();
+        assert_eq!(data, [0x93, 0x41]);
+        assert_eq!(bus.borrow().cycles, [Cycle::Command(0xD3), Cycle::Read, Cycle::Read, Cycle::Read]);
+        // The pins are driven again, the next write works
+        assert_idle(&bus);
+        iface.command(0x00).unwrap();
+    }
+
+    #[test]
+    fn failed_read_releases_the_bus() {
+        let (mut iface, bus) = interface::<8>();
+        bus.borrow_mut().responses.extend([0xAA, 0x93]);
+        bus.borrow_mut().fail_read = Some(2);
+        let mut data = [0; 2];
+        assert!(iface.read_parameters(0xD3, &mut data).is_err());
+        assert_idle(&bus);
+        iface.command(0x29).unwrap();
+        assert_eq!(bus.borrow().cycles.last(), Some(&Cycle::Command(0x29)));
+    }
+
+    #[test]
+    fn words18_on_an_8bit_bus() {
+        let (mut iface, bus) = interface::<8>();
+        iface.send_parameters_u32(0x2C, &PIXELS[..1]).unwrap();
+        assert_eq!(parameters(&bus), [0xFC, 0x00, 0x04]);
+    }
+
+    #[test]
+    fn words18_on_a_16bit_bus() {
+        let (mut iface, bus) = interface::<16>();
+        iface.send_parameters_u32(0x2C, &PIXELS).unwrap();
+        // R1 G1, B1 R2, G2 B2, then R3 G3, B3 of the odd pixel
+        assert_eq!(parameters(&bus), [0xFC00, 0x0400, 0xFC08, 0xA880, 0x5400]);
+        assert_eq!(bus.borrow().cycles[0], Cycle::Command(0x2C));
+    }
+
+    #[test]
+    fn read_words18_on_an_8bit_bus() {
+        let (mut iface, bus) = interface::<8>();
+        bus.borrow_mut().responses.extend([0x00, 0xFC, 0x00, 0x04]);
+        let mut data = [0; 1];
+        iface.read_parameters_u32(0x2E, &mut data).unwrap();
+        assert_eq!(data, PIXELS[..1]);
+        assert_eq!(bus.borrow().cycles.len(), 1 + 4);
+    }
+
+    #[test]
+    fn read_words18_on_a_16bit_bus() {
+        let (mut iface, bus) = interface::<16>();
+        bus.borrow_mut().responses.extend([0x0000, 0xFC00, 0x0400, 0xFC08, 0xA880, 0x5400]);
+        let mut data = [0; 3];
+        iface.read_parameters_u32(0x2E, &mut data).unwrap();
+        assert_eq!(data, PIXELS);
+        // The dummy cycle, three cycles for the first two words and two for the odd one
+        assert_eq!(bus.borrow().cycles.len(), 1 + 1 + 3 + 2);
+        assert_idle(&bus);
+    }
+
+    #[test]
+    fn read_words18_on_an_18bit_bus() {
+        let (mut iface, bus) = interface::<18>();
+        bus.borrow_mut().responses.extend([0x00000, 0x3F001, 0x00FC2, 0x2A815]);
+        let mut data = [0; 3];
+        iface.read_parameters_u32(0x2E, &mut data).unwrap();
+        assert_eq!(data, PIXELS);
+    }
+
+    #[test]
+    fn words18_on_an_18bit_bus() {
+        let (mut iface, bus) = interface::<18>();
+        iface.send_parameters_u32(0x2C, &PIXELS).unwrap();
+        assert_eq!(parameters(&bus), PIXELS);
+    }
+
+    #[test]
+    fn words16_on_a_16bit_bus() {
+        let (mut iface, bus) = interface::<16>();
+        iface.send_parameters_u16(0x2C, &[0xF800, 0x07E0]).unwrap();
+        assert_eq!(parameters(&bus), [0xF800, 0x07E0]);
+    }
+}
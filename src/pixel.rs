@@ -0,0 +1,583 @@
+//! Pixels typed by the pixel format
+//!
+//! [`PixelController`] sets the pixel format of the MCU interface (COLMOD, 3Ah) to the one of its
+//! pixel type and transfers the frame memory in that format, so the byte packing always matches:
+//! - [`Rgb565`] is sent as one 16-bit word per pixel
+//! - [`Rgb666`] is sent as one 18-bit word D[17:0] per pixel, two pixels take three cycles on a
+//!   16-bit bus
+//!
+//! The words are put on the bus by the `_u16`/`_u32` methods of the [`Interface`]. Reads return
+//! the 18-bit frame memory content in every format, it is converted to the pixel type.
+//!
+//! Without a `PixelController`, [`encode`] produces the byte stream of `memory_write` for a
+//! pixel format on an 8-bit bus or SPI, [`encode_u16`] and [`encode_u32`] the words of
+//! `memory_write_u16` on a 16-bit bus and `memory_write_u32` on an 18-bit bus, and [`decode`]
+//! turns the output of `memory_read` back into colors. [`Rgb888`] and the 8 colors of
+//! [`Rgb111`] convert into both pixel types:
+//!
+//! ```
+//! # use ili9341_cmd::pixel::{decode, encode, encode_u16, Rgb565, Rgb666, Rgb888};
+//! # use ili9341_cmd::pixel_format::McuInterfaceFormat;
+//! let orange = Rgb888 { r: 0xFF, g: 0x80, b: 0x00 };
+//! assert!(encode(McuInterfaceFormat::N16Bits, [orange]).eq([0xFC, 0x00]));
+//! assert!(encode(McuInterfaceFormat::N18Bits, [orange]).eq([0xFC, 0x80, 0x00]));
+//! assert!(encode_u16(McuInterfaceFormat::N18Bits, [orange, orange]).eq([0xFC80, 0x00FC, 0x8000]));
+//! assert!(decode(&[0xFC, 0x80, 0x00]).eq([Rgb666::from(orange)]));
+//! assert_eq!(Rgb565::from(orange), Rgb565::new(0x1F, 0x20, 0x00));
+//! ```
+
+use core::marker::PhantomData;
+
+use embedded_hal::delay::DelayNs;
+
+use crate::pixel_format::McuInterfaceFormat;
+use crate::power::TransitionError;
+use crate::registers::Registers;
+use crate::shadow::{NoCache, RegisterCache};
+use crate::timing::{NoDelay, NoResetPin};
+use crate::{words18_to_words16, Controller, Interface};
+
+/// 16-bit pixel, R[15:11] G[10:5] B[4:0]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Rgb565(pub u16);
+
+/// 18-bit pixel, R[17:12] G[11:6] B[5:0]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Rgb666(pub u32);
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Rgb565 {}
+    impl Sealed for super::Rgb666 {}
+}
+
+/// Pixels converted to words per transfer, even so two 18-bit pixels never straddle two transfers
+/// Longer transfers are continued with Write/Read Memory Continue (3Ch/3Eh).
+const PIXEL_CHUNK: usize = 32;
+
+/// Pixel types of the MCU interface pixel formats
+pub trait PixelFormat: Copy + Default + sealed::Sealed {
+    /// Pixel format of the MCU interface
+    const FORMAT: McuInterfaceFormat;
+    /// Send the pixels as parameters of `command`
+    fn send<Iface: Interface>(iface: &mut Iface, command: u8, pixels: &[Self]) -> Result<(), Iface::Error>;
+    /// Read the pixels as parameters of `command`
+    fn read<Iface: Interface>(iface: &mut Iface, command: u8, pixels: &mut [Self]) -> Result<(), Iface::Error>;
+}
+
+impl PixelFormat for Rgb565 {
+    const FORMAT: McuInterfaceFormat = McuInterfaceFormat::N16Bits;
+
+    fn send<Iface: Interface>(iface: &mut Iface, command: u8, pixels: &[Self]) -> Result<(), Iface::Error> {
+        let mut words = [0u16; PIXEL_CHUNK];
+        let mut command = command;
+        for chunk in pixels.chunks(PIXEL_CHUNK) {
+            let words = &mut words[..chunk.len()];
+            for (w, p) in words.iter_mut().zip(chunk) {
+                *w = p.0;
+            }
+            iface.send_parameters_u16(command, words)?;
+            // Write Memory Continue
+            command = 0x3C;
+        }
+        Ok(())
+    }
+    fn read<Iface: Interface>(iface: &mut Iface, command: u8, pixels: &mut [Self]) -> Result<(), Iface::Error> {
+        // The frame memory is read as 18-bit words
+        let mut words = [0u32; PIXEL_CHUNK];
+        let mut command = command;
+        for chunk in pixels.chunks_mut(PIXEL_CHUNK) {
+            let words = &mut words[..chunk.len()];
+            iface.read_parameters_u32(command, words)?;
+            for (p, &w) in chunk.iter_mut().zip(words.iter()) {
+                *p = Rgb565::from(Rgb666(w));
+            }
+            // Read Memory Continue
+            command = 0x3E;
+        }
+        Ok(())
+    }
+}
+
+impl PixelFormat for Rgb666 {
+    const FORMAT: McuInterfaceFormat = McuInterfaceFormat::N18Bits;
+
+    fn send<Iface: Interface>(iface: &mut Iface, command: u8, pixels: &[Self]) -> Result<(), Iface::Error> {
+        let mut words = [0u32; PIXEL_CHUNK];
+        let mut command = command;
+        for chunk in pixels.chunks(PIXEL_CHUNK) {
+            let words = &mut words[..chunk.len()];
+            for (w, p) in words.iter_mut().zip(chunk) {
+                *w = p.0;
+            }
+            iface.send_parameters_u32(command, words)?;
+            // Write Memory Continue
+            command = 0x3C;
+        }
+        Ok(())
+    }
+    fn read<Iface: Interface>(iface: &mut Iface, command: u8, pixels: &mut [Self]) -> Result<(), Iface::Error> {
+        let mut words = [0u32; PIXEL_CHUNK];
+        let mut command = command;
+        for chunk in pixels.chunks_mut(PIXEL_CHUNK) {
+            let words = &mut words[..chunk.len()];
+            iface.read_parameters_u32(command, words)?;
+            for (p, &w) in chunk.iter_mut().zip(words.iter()) {
+                *p = Rgb666(w);
+            }
+            // Read Memory Continue
+            command = 0x3E;
+        }
+        Ok(())
+    }
+}
+
+impl From<Rgb565> for Rgb666 {
+    /// Expand red and blue to 6 bits like the display does, by repeating their MSB
+    fn from(p: Rgb565) -> Self {
+        let w = p.0 as u32;
+        let r = (w >> 11) & 0x1F;
+        let g = (w >> 5) & 0x3F;
+        let b = w & 0x1F;
+        Rgb666((((r << 1) | (r >> 4)) << 12) | (g << 6) | ((b << 1) | (b >> 4)))
+    }
+}
+
+impl From<Rgb666> for Rgb565 {
+    /// Drop the LSB of red and blue
+    fn from(p: Rgb666) -> Self {
+        let w = p.0;
+        let r = (w >> 13) & 0x1F;
+        let g = (w >> 6) & 0x3F;
+        let b = (w >> 1) & 0x1F;
+        Rgb565(((r << 11) | (g << 5) | b) as u16)
+    }
+}
+
+impl Rgb565 {
+    /// Pixel of the 5-bit red, 6-bit green and 5-bit blue components
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Rgb565(((r as u16 & 0x1F) << 11) | ((g as u16 & 0x3F) << 5) | (b as u16 & 0x1F))
+    }
+    pub const fn r(self) -> u8 {
+        (self.0 >> 11) as u8 & 0x1F
+    }
+    pub const fn g(self) -> u8 {
+        (self.0 >> 5) as u8 & 0x3F
+    }
+    pub const fn b(self) -> u8 {
+        self.0 as u8 & 0x1F
+    }
+    /// The bytes on an 8-bit bus, MSB first
+    pub const fn to_bytes(self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+    /// The pixel from the bytes on an 8-bit bus, MSB first
+    pub const fn from_bytes(b: [u8; 2]) -> Self {
+        Rgb565(u16::from_be_bytes(b))
+    }
+}
+
+impl Rgb666 {
+    /// Pixel of the 6-bit red, green and blue components
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Rgb666(((r as u32 & 0x3F) << 12) | ((g as u32 & 0x3F) << 6) | (b as u32 & 0x3F))
+    }
+    pub const fn r(self) -> u8 {
+        (self.0 >> 12) as u8 & 0x3F
+    }
+    pub const fn g(self) -> u8 {
+        (self.0 >> 6) as u8 & 0x3F
+    }
+    pub const fn b(self) -> u8 {
+        self.0 as u8 & 0x3F
+    }
+    /// The bytes on an 8-bit bus, 6 bits each, left aligned
+    pub const fn to_bytes(self) -> [u8; 3] {
+        [self.r() << 2, self.g() << 2, self.b() << 2]
+    }
+    /// The pixel from the bytes on an 8-bit bus, 6 bits each, left aligned
+    pub const fn from_bytes(b: [u8; 3]) -> Self {
+        Rgb666::new(b[0] >> 2, b[1] >> 2, b[2] >> 2)
+    }
+}
+
+/// 24-bit color
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Rgb888 {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+/// One of the 8 colors of idle mode, R in bit 2, G in bit 1 and B in bit 0
+/// The display has no 3-bit pixel format, the colors are sent as [`Rgb565`] or [`Rgb666`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Rgb111(pub u8);
+
+impl Rgb111 {
+    pub const BLACK: Rgb111 = Rgb111(0b000);
+    pub const BLUE: Rgb111 = Rgb111(0b001);
+    pub const GREEN: Rgb111 = Rgb111(0b010);
+    pub const CYAN: Rgb111 = Rgb111(0b011);
+    pub const RED: Rgb111 = Rgb111(0b100);
+    pub const MAGENTA: Rgb111 = Rgb111(0b101);
+    pub const YELLOW: Rgb111 = Rgb111(0b110);
+    pub const WHITE: Rgb111 = Rgb111(0b111);
+}
+
+/// Extend a component of `bits` bits to 8 bits by repeating its MSBs
+const fn expand(c: u8, bits: u32) -> u8 {
+    (c << (8 - bits)) | (c >> (2 * bits - 8))
+}
+
+impl From<Rgb565> for Rgb888 {
+    fn from(p: Rgb565) -> Self {
+        Rgb888 { r: expand(p.r(), 5), g: expand(p.g(), 6), b: expand(p.b(), 5) }
+    }
+}
+
+impl From<Rgb666> for Rgb888 {
+    fn from(p: Rgb666) -> Self {
+        Rgb888 { r: expand(p.r(), 6), g: expand(p.g(), 6), b: expand(p.b(), 6) }
+    }
+}
+
+impl From<Rgb111> for Rgb888 {
+    fn from(p: Rgb111) -> Self {
+        let c = |bit: u8| if p.0 & bit != 0 { 0xFF } else { 0x00 };
+        Rgb888 { r: c(0b100), g: c(0b010), b: c(0b001) }
+    }
+}
+
+impl From<Rgb888> for Rgb565 {
+    fn from(c: Rgb888) -> Self {
+        Rgb565::new(c.r >> 3, c.g >> 2, c.b >> 3)
+    }
+}
+
+impl From<Rgb888> for Rgb666 {
+    fn from(c: Rgb888) -> Self {
+        Rgb666::new(c.r >> 2, c.g >> 2, c.b >> 2)
+    }
+}
+
+impl From<Rgb888> for Rgb111 {
+    /// The MSB of every component, like idle mode
+    fn from(c: Rgb888) -> Self {
+        Rgb111(((c.r >> 7) << 2) | ((c.g >> 7) << 1) | (c.b >> 7))
+    }
+}
+
+impl From<Rgb111> for Rgb565 {
+    fn from(p: Rgb111) -> Self {
+        Rgb888::from(p).into()
+    }
+}
+
+impl From<Rgb111> for Rgb666 {
+    fn from(p: Rgb111) -> Self {
+        Rgb888::from(p).into()
+    }
+}
+
+/// Byte stream of [`encode`]
+pub struct Encoder<I> {
+    colors: I,
+    format: McuInterfaceFormat,
+    bytes: [u8; 3],
+    pos: usize,
+    len: usize,
+}
+
+impl<I> Iterator for Encoder<I>
+where
+    I: Iterator,
+    I::Item: Into<Rgb888>,
+{
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == self.len {
+            let c: Rgb888 = self.colors.next()?.into();
+            match self.format {
+                McuInterfaceFormat::N16Bits => {
+                    let b = Rgb565::from(c).to_bytes();
+                    self.bytes = [b[0], b[1], 0];
+                    self.len = 2;
+                }
+                McuInterfaceFormat::N18Bits => {
+                    self.bytes = Rgb666::from(c).to_bytes();
+                    self.len = 3;
+                }
+            }
+            self.pos = 0;
+        }
+        self.pos += 1;
+        Some(self.bytes[self.pos - 1])
+    }
+}
+
+/// The bytes of `colors` for `memory_write` on an 8-bit bus or SPI, in the pixel format `format`
+pub fn encode<C>(format: McuInterfaceFormat, colors: C) -> Encoder<C::IntoIter>
+where
+    C: IntoIterator,
+    C::Item: Into<Rgb888>,
+{
+    Encoder { colors: colors.into_iter(), format, bytes: [0; 3], pos: 0, len: 0 }
+}
+
+/// Word stream of [`encode_u16`]
+pub struct Encoder16<I> {
+    colors: I,
+    format: McuInterfaceFormat,
+    words: [u16; 3],
+    pos: usize,
+    len: usize,
+}
+
+impl<I> Iterator for Encoder16<I>
+where
+    I: Iterator,
+    I::Item: Into<Rgb888>,
+{
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.pos == self.len {
+            let c: Rgb888 = self.colors.next()?.into();
+            match self.format {
+                McuInterfaceFormat::N16Bits => {
+                    self.words[0] = Rgb565::from(c).0;
+                    self.len = 1;
+                }
+                McuInterfaceFormat::N18Bits => {
+                    // Two pixels in three cycles, a last odd pixel in two
+                    let first = Rgb666::from(c).0;
+                    let second = self.colors.next().map(|c| Rgb666::from(c.into()).0);
+                    self.words = words18_to_words16(first, second.unwrap_or(0));
+                    self.len = if second.is_some() { 3 } else { 2 };
+                }
+            }
+            self.pos = 0;
+        }
+        self.pos += 1;
+        Some(self.words[self.pos - 1])
+    }
+}
+
+/// The words of `colors` for `memory_write_u16` on a 16-bit bus, in the pixel format `format`
+/// 16 bpp is one word per pixel, 18 bpp packs two pixels into three words with 6 bits each on
+/// D[15:10] and D[7:2] (R1 G1, B1 R2, G2 B2).
+pub fn encode_u16<C>(format: McuInterfaceFormat, colors: C) -> Encoder16<C::IntoIter>
+where
+    C: IntoIterator,
+    C::Item: Into<Rgb888>,
+{
+    Encoder16 { colors: colors.into_iter(), format, words: [0; 3], pos: 0, len: 0 }
+}
+
+/// The words of `colors` for `memory_write_u32` on an 18-bit bus, in the pixel format `format`
+/// One word per pixel, D[17:0] for 18 bpp and D[15:0] for 16 bpp.
+pub fn encode_u32<C>(format: McuInterfaceFormat, colors: C) -> impl Iterator<Item = u32>
+where
+    C: IntoIterator,
+    C::Item: Into<Rgb888>,
+{
+    colors.into_iter().map(move |c| {
+        let c: Rgb888 = c.into();
+        match format {
+            McuInterfaceFormat::N16Bits => Rgb565::from(c).0 as u32,
+            McuInterfaceFormat::N18Bits => Rgb666::from(c).0,
+        }
+    })
+}
+
+/// The colors of the bytes read by `memory_read`, 3 bytes per pixel in every pixel format
+/// Trailing bytes of an incomplete pixel are ignored.
+pub fn decode(bytes: &[u8]) -> impl Iterator<Item = Rgb666> + '_ {
+    bytes.chunks_exact(3).map(|b| Rgb666::from_bytes([b[0], b[1], b[2]]))
+}
+
+/// Result of a pixel format change of `Src` to the one of `Q`
+type Conversion<Iface, Src, Q, Delay, Reset, Cache> =
+    Result<PixelController<Iface, Q, Delay, Reset, Cache>, TransitionError<Src, <Iface as Interface>::Error>>;
+
+/// Controller transferring the frame memory as pixels of type `P`, see the [module documentation](self)
+pub struct PixelController<Iface, P, Delay = NoDelay, Reset = NoResetPin, Cache = NoCache>
+where
+    Iface: Interface,
+    P: PixelFormat,
+{
+    lcd: Controller<Iface, Delay, Reset, Cache>,
+    format: PhantomData<P>,
+}
+
+impl<Iface, P, Delay, Reset, Cache> PixelController<Iface, P, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    P: PixelFormat,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Set the pixel format to the one of `P`
+    pub fn new(mut lcd: Controller<Iface, Delay, Reset, Cache>) -> Result<Self, Iface::Error> {
+        lcd.set_pixel_format(P::FORMAT)?;
+        Ok(PixelController { lcd, format: PhantomData })
+    }
+    /// Change the pixel format to the one of `Q`
+    pub fn into_pixel_format<Q: PixelFormat>(mut self) -> Conversion<Iface, Self, Q, Delay, Reset, Cache> {
+        match self.lcd.set_pixel_format(Q::FORMAT) {
+            Ok(()) => Ok(PixelController { lcd: self.lcd, format: PhantomData }),
+            Err(error) => Err(TransitionError { display: self, error }),
+        }
+    }
+    /// Release the Controller, its pixel format is the one of `P`
+    pub fn release(self) -> Controller<Iface, Delay, Reset, Cache> {
+        self.lcd
+    }
+    /// The register commands, without the pixel format and the frame memory transfers
+    pub fn registers(&mut self) -> Registers<'_, Iface, Delay, Reset, Cache> {
+        Registers::new(&mut self.lcd)
+    }
+
+    /// Memory Write (2Ch)
+    pub fn memory_write(&mut self, pixels: &[P]) -> Result<(), Iface::Error> {
+        P::send(&mut self.lcd.iface, 0x2C, pixels)
+    }
+    /// Write Memory Continue (3Ch)
+    pub fn write_memory_continue(&mut self, pixels: &[P]) -> Result<(), Iface::Error> {
+        P::send(&mut self.lcd.iface, 0x3C, pixels)
+    }
+    /// Memory Read (2Eh)
+    pub fn memory_read<'l>(&mut self, pixels: &'l mut [P]) -> Result<&'l mut [P], Iface::Error> {
+        P::read(&mut self.lcd.iface, 0x2E, pixels)?;
+        Ok(pixels)
+    }
+    /// Read Memory Continue (3Eh)
+    pub fn read_memory_continue<'l>(&mut self, pixels: &'l mut [P]) -> Result<&'l mut [P], Iface::Error> {
+        P::read(&mut self.lcd.iface, 0x3E, pixels)?;
+        Ok(pixels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::MockInterface;
+
+    const ORANGE: Rgb888 = Rgb888 { r: 0xFF, g: 0x80, b: 0x00 };
+    const TEAL: Rgb888 = Rgb888 { r: 0x00, g: 0x80, b: 0x80 };
+
+    #[test]
+    fn expand_repeats_the_msbs() {
+        assert_eq!(expand(0x1F, 5), 0xFF);
+        assert_eq!(expand(0x10, 5), 0x84);
+        assert_eq!(expand(0x01, 5), 0x08);
+        assert_eq!(expand(0x3F, 6), 0xFF);
+        assert_eq!(expand(0x20, 6), 0x82);
+        assert_eq!(expand(0x00, 6), 0x00);
+    }
+
+    #[test]
+    fn rgb565_and_rgb666() {
+        let p = Rgb565::new(0x1F, 0x2A, 0x10);
+        assert_eq!((p.r(), p.g(), p.b()), (0x1F, 0x2A, 0x10));
+        assert_eq!(Rgb565::from_bytes(p.to_bytes()), p);
+        // 5-bit red and blue gain their MSB as LSB, and lose it again
+        assert_eq!(Rgb666::from(p), Rgb666::new(0x3F, 0x2A, 0x21));
+        assert_eq!(Rgb565::from(Rgb666::from(p)), p);
+
+        let q = Rgb666::new(0x3F, 0x01, 0x20);
+        assert_eq!(q.to_bytes(), [0xFC, 0x04, 0x80]);
+        assert_eq!(Rgb666::from_bytes(q.to_bytes()), q);
+        assert_eq!(Rgb565::from(q), Rgb565::new(0x1F, 0x01, 0x10));
+    }
+
+    #[test]
+    fn rgb888_conversions() {
+        assert_eq!(Rgb565::from(ORANGE), Rgb565::new(0x1F, 0x20, 0x00));
+        assert_eq!(Rgb666::from(ORANGE), Rgb666::new(0x3F, 0x20, 0x00));
+        assert_eq!(Rgb888::from(Rgb565::new(0x1F, 0x20, 0x00)), Rgb888 { r: 0xFF, g: 0x82, b: 0x00 });
+        assert_eq!(Rgb888::from(Rgb666::new(0x3F, 0x20, 0x01)), Rgb888 { r: 0xFF, g: 0x82, b: 0x04 });
+        assert_eq!(Rgb111::from(ORANGE), Rgb111::YELLOW);
+        assert_eq!(Rgb111::from(TEAL), Rgb111::CYAN);
+    }
+
+    #[test]
+    fn rgb111_conversions() {
+        for c in 0..8 {
+            let p = Rgb111(c);
+            assert_eq!(Rgb111::from(Rgb888::from(p)), p);
+        }
+        assert_eq!(Rgb888::from(Rgb111::MAGENTA), Rgb888 { r: 0xFF, g: 0x00, b: 0xFF });
+        assert_eq!(Rgb565::from(Rgb111::RED), Rgb565(0xF800));
+        assert_eq!(Rgb565::from(Rgb111::WHITE), Rgb565(0xFFFF));
+        assert_eq!(Rgb666::from(Rgb111::GREEN), Rgb666(0x00FC0));
+        assert_eq!(Rgb666::from(Rgb111::BLACK), Rgb666(0));
+    }
+
+    #[test]
+    fn encode_bytes_and_decode() {
+        assert!(encode(McuInterfaceFormat::N16Bits, [ORANGE, TEAL]).eq([0xFC, 0x00, 0x04, 0x10]));
+        let bytes: [u8; 6] = [0xFC, 0x80, 0x00, 0x00, 0x80, 0x80];
+        assert!(encode(McuInterfaceFormat::N18Bits, [ORANGE, TEAL]).eq(bytes));
+        assert!(decode(&bytes).eq([Rgb666::from(ORANGE), Rgb666::from(TEAL)]));
+        // The incomplete last pixel is dropped
+        assert!(decode(&bytes[..5]).eq([Rgb666::from(ORANGE)]));
+        assert_eq!(encode(McuInterfaceFormat::N16Bits, [Rgb111::RED; 0]).count(), 0);
+    }
+
+    #[test]
+    fn encode_words() {
+        assert!(encode_u16(McuInterfaceFormat::N16Bits, [ORANGE, TEAL]).eq([0xFC00, 0x0410]));
+        // Two pixels in three words, the odd third one in two
+        assert!(encode_u16(McuInterfaceFormat::N18Bits, [ORANGE, TEAL, Rgb888::from(Rgb111::WHITE)]).eq([
+            0xFC80, 0x0000, 0x8080, 0xFCFC, 0xFC00
+        ]));
+        assert!(encode_u32(McuInterfaceFormat::N18Bits, [ORANGE, TEAL]).eq([0x3F800, 0x00820]));
+        assert!(encode_u32(McuInterfaceFormat::N16Bits, [ORANGE]).eq([0xFC00]));
+    }
+
+    #[test]
+    fn pixel_controller_transcript() {
+        let mock = MockInterface::new();
+        let mut lcd = PixelController::<_, Rgb565>::new(Controller::new(mock.clone())).unwrap();
+        lcd.memory_write(&[Rgb565::new(0x1F, 0, 0), Rgb565(0x07E0)]).unwrap();
+        lcd.registers().gamma_set(|w| w).unwrap();
+        let mut lcd = lcd.into_pixel_format::<Rgb666>().ok().unwrap();
+        lcd.write_memory_continue(&[Rgb666::new(0x3F, 0x01, 0x20)]).unwrap();
+
+        mock.expect_parameters(0x3A, &[0x55])
+            .expect_parameters(0x2C, &[0xF8, 0x00, 0x07, 0xE0])
+            .expect_parameters(0x26, &[0x01])
+            .expect_parameters(0x3A, &[0x66])
+            .expect_parameters(0x3C, &[0xFC, 0x04, 0x80])
+            .done();
+    }
+
+    #[test]
+    fn long_transfers_continue() {
+        let mock = MockInterface::new();
+        let mut lcd = PixelController::<_, Rgb666>::new(Controller::new(mock.clone())).unwrap();
+        let pixels = [Rgb666::new(0x3F, 0x00, 0x01); PIXEL_CHUNK + 1];
+        lcd.memory_write(&pixels).unwrap();
+        mock.push_response(0x2E, &[0xFC, 0x00, 0x04].repeat(16))
+            .push_response(0x3E, &[0xFC, 0x00, 0x04].repeat(16))
+            .push_response(0x3E, &[0x00, 0xFC, 0x00]);
+        let mut read = [Rgb666::default(); PIXEL_CHUNK + 1];
+        lcd.memory_read(&mut read).unwrap();
+        assert_eq!(read[..PIXEL_CHUNK], pixels[..PIXEL_CHUNK]);
+        assert_eq!(read[PIXEL_CHUNK], Rgb666::new(0x00, 0x3F, 0x00));
+
+        mock.expect_parameters(0x3A, &[0x66])
+            .expect_parameters(0x2C, &[0xFC, 0x00, 0x04].repeat(PIXEL_CHUNK))
+            .expect_parameters(0x3C, &[0xFC, 0x00, 0x04])
+            .expect_read(0x2E, 48)
+            .expect_read(0x3E, 48)
+            .expect_read(0x3E, 3)
+            .done();
+    }
+}
@@ -0,0 +1,371 @@
+//! Power and display state in the type system
+//!
+//! [`Display`] wraps a Controller and tracks the power state of the display in its type, so an
+//! invalid transition or a frame memory access while sleeping is a compile error:
+//!
+//! ```text
+//! Sleep <-> SleepOut <-> DisplayOn <-> Partial
+//!                            ^
+//!                            +-------> Idle
+//! ```
+//!
+//! The transitions use the Controller commands, so the mandated waits are done if the
+//! Controller has a delay source (see [`timing`](crate::timing)). Partial and idle mode are left
+//! to normal display mode before anything else, the display is turned off before entering sleep.
+//!
+//! The other commands are sent through [`registers`](Display::registers), which leaves out the
+//! power state commands and the frame memory transfers, and
+//! [`pixel_format_set`](Display::pixel_format_set):
+//!
+//! ```compile_fail
+//! # use ili9341_cmd::{Interface, power::{Display, Sleep}};
+//! fn clear<Iface: Interface>(display: &mut Display<Iface, Sleep>) {
+//!     display.memory_write(&[0x00; 2]); // still sleeping
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use ili9341_cmd::{Interface, power::{Display, DisplayOn}};
+//! fn blank<Iface: Interface>(display: &mut Display<Iface, DisplayOn>) {
+//!     display.registers().display_off(); // the type stays DisplayOn
+//! }
+//! ```
+
+use core::marker::PhantomData;
+
+use embedded_hal::delay::DelayNs;
+
+use crate::registers::Registers;
+use crate::shadow::{NoCache, RegisterCache};
+use crate::timing::{NoDelay, NoResetPin};
+use crate::{pixel_format, Controller, Interface};
+
+/// Sleep in, the state after a reset
+pub struct Sleep;
+/// Sleep out, display off
+pub struct SleepOut;
+/// Display on in normal display mode
+pub struct DisplayOn;
+/// Display on in partial mode
+pub struct Partial;
+/// Display on in idle mode
+pub struct Idle;
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for super::Sleep {}
+    impl Sealed for super::SleepOut {}
+    impl Sealed for super::DisplayOn {}
+    impl Sealed for super::Partial {}
+    impl Sealed for super::Idle {}
+}
+
+/// Power states of the display
+pub trait PowerState: sealed::Sealed {}
+impl PowerState for Sleep {}
+impl PowerState for SleepOut {}
+impl PowerState for DisplayOn {}
+impl PowerState for Partial {}
+impl PowerState for Idle {}
+
+/// Power states which allow frame memory access
+pub trait Awake: PowerState {}
+impl Awake for SleepOut {}
+impl Awake for DisplayOn {}
+impl Awake for Partial {}
+impl Awake for Idle {}
+
+/// A failed transition, the display is handed back in its previous state
+///
+/// The actual state of the display is unknown after a failed command.
+pub struct TransitionError<D, E> {
+    pub display: D,
+    pub error: E,
+}
+
+/// Result of a transition of `Src` to the state `To`
+type Transition<Iface, Src, To, Delay, Reset, Cache> =
+    Result<Display<Iface, To, Delay, Reset, Cache>, TransitionError<Src, <Iface as Interface>::Error>>;
+
+/// Controller with the power state `State` tracked in its type, see the [module documentation](self)
+pub struct Display<Iface, State, Delay = NoDelay, Reset = NoResetPin, Cache = NoCache>
+where
+    Iface: Interface,
+    State: PowerState,
+{
+    lcd: Controller<Iface, Delay, Reset, Cache>,
+    state: PhantomData<State>,
+}
+
+impl<Iface, Delay, Reset, Cache> Display<Iface, Sleep, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Software reset the display to get into a known state
+    /// Use [`assume`](Display::assume) to keep a display which is already set up.
+    pub fn new(mut lcd: Controller<Iface, Delay, Reset, Cache>) -> Result<Self, Iface::Error> {
+        lcd.software_reset()?;
+        Ok(Display { lcd, state: PhantomData })
+    }
+
+    /// Sleep Out (11h)
+    pub fn sleep_out(self) -> Transition<Iface, Self, SleepOut, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.sleep_out())
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Display<Iface, SleepOut, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Enter Sleep Mode (10h)
+    pub fn enter_sleep_mode(self) -> Transition<Iface, Self, Sleep, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.enter_sleep_mode())
+    }
+    /// Display ON (29h)
+    pub fn display_on(self) -> Transition<Iface, Self, DisplayOn, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.display_on())
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Display<Iface, DisplayOn, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Display OFF (28h)
+    pub fn display_off(self) -> Transition<Iface, Self, SleepOut, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.display_off())
+    }
+    /// Partial Mode ON (12h), the area is set with `partial_area`
+    pub fn partial_mode_on(self) -> Transition<Iface, Self, Partial, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.partial_mode_on())
+    }
+    /// Idle Mode ON (39h)
+    pub fn idle_mode_on(self) -> Transition<Iface, Self, Idle, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.idle_mode_on())
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Display<Iface, Partial, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Normal Display Mode ON (13h)
+    pub fn normal_display_mode_on(self) -> Transition<Iface, Self, DisplayOn, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.normal_display_mode_on())
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Display<Iface, Idle, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Idle Mode OFF (38h)
+    pub fn idle_mode_off(self) -> Transition<Iface, Self, DisplayOn, Delay, Reset, Cache> {
+        self.transition(|lcd| lcd.idle_mode_off())
+    }
+}
+
+impl<Iface, State, Delay, Reset, Cache> Display<Iface, State, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    State: PowerState,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Wrap a Controller whose display is known to be in the power state `State`, without
+    /// sending a command
+    ///
+    /// [`Controller::init`] leaves the display on in normal display mode:
+    ///
+    /// ```
+    /// # use ili9341_cmd::{Controller, Interface, init::{Config, Error}, power::{Display, DisplayOn}};
+    /// fn start<Iface: Interface>(iface: Iface) -> Result<Display<Iface, DisplayOn>, Error<Iface::Error, core::convert::Infallible>> {
+    ///     let mut lcd = Controller::new(iface);
+    ///     lcd.init(&Config::default())?;
+    ///     Ok(Display::assume(lcd))
+    /// }
+    /// ```
+    pub fn assume(lcd: Controller<Iface, Delay, Reset, Cache>) -> Self {
+        Display { lcd, state: PhantomData }
+    }
+    /// Release the Controller, its power state is `State`
+    pub fn release(self) -> Controller<Iface, Delay, Reset, Cache> {
+        self.lcd
+    }
+    /// The register commands, without the power state commands and the frame memory transfers
+    pub fn registers(&mut self) -> Registers<'_, Iface, Delay, Reset, Cache> {
+        Registers::new(&mut self.lcd)
+    }
+    /// Pixel Format Set (3Ah), see [`Controller::pixel_format_set`]
+    pub fn pixel_format_set<F>(&mut self, f: F) -> Result<(), Iface::Error>
+    where
+        F: FnOnce(pixel_format::PixelFormatSetWrite) -> pixel_format::PixelFormatSetWrite,
+    {
+        self.lcd.pixel_format_set(f)
+    }
+
+    fn transition<S, F>(mut self, f: F) -> Transition<Iface, Self, S, Delay, Reset, Cache>
+    where
+        S: PowerState,
+        F: FnOnce(&mut Controller<Iface, Delay, Reset, Cache>) -> Result<(), Iface::Error>,
+    {
+        match f(&mut self.lcd) {
+            Ok(()) => Ok(Display { lcd: self.lcd, state: PhantomData }),
+            Err(error) => Err(TransitionError { display: self, error }),
+        }
+    }
+}
+
+impl<Iface, State, Delay, Reset, Cache> Display<Iface, State, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    State: Awake,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Memory Write (2Ch), see [`Controller::memory_write`]
+    pub fn memory_write(&mut self, d: &[u8]) -> Result<(), Iface::Error> {
+        self.lcd.memory_write(d)
+    }
+    /// Memory Write (2Ch), see [`Controller::memory_write_iter`]
+    pub fn memory_write_iter<T: IntoIterator<Item = u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
+        self.lcd.memory_write_iter(d)
+    }
+    /// Memory Write (2Ch), see [`Controller::memory_write_u16`]
+    pub fn memory_write_u16(&mut self, d: &[u16]) -> Result<(), Iface::Error> {
+        self.lcd.memory_write_u16(d)
+    }
+    /// Memory Write (2Ch), see [`Controller::memory_write_u32`]
+    pub fn memory_write_u32(&mut self, d: &[u32]) -> Result<(), Iface::Error> {
+        self.lcd.memory_write_u32(d)
+    }
+    /// Write Memory Continue (3Ch), see [`Controller::write_memory_continue`]
+    pub fn write_memory_continue(&mut self, d: &[u8]) -> Result<(), Iface::Error> {
+        self.lcd.write_memory_continue(d)
+    }
+    /// Write Memory Continue (3Ch), see [`Controller::write_memory_continue_iter`]
+    pub fn write_memory_continue_iter<T: IntoIterator<Item = u8>>(&mut self, d: T) -> Result<(), Iface::Error> {
+        self.lcd.write_memory_continue_iter(d)
+    }
+    /// Write Memory Continue (3Ch), see [`Controller::write_memory_continue_u16`]
+    pub fn write_memory_continue_u16(&mut self, d: &[u16]) -> Result<(), Iface::Error> {
+        self.lcd.write_memory_continue_u16(d)
+    }
+    /// Write Memory Continue (3Ch), see [`Controller::write_memory_continue_u32`]
+    pub fn write_memory_continue_u32(&mut self, d: &[u32]) -> Result<(), Iface::Error> {
+        self.lcd.write_memory_continue_u32(d)
+    }
+    /// Memory Read (2Eh), see [`Controller::memory_read`]
+    pub fn memory_read<'l>(&mut self, d: &'l mut [u8]) -> Result<&'l mut [u8], Iface::Error> {
+        self.lcd.memory_read(d)
+    }
+    /// Read Memory Continue (3Eh), see [`Controller::read_memory_continue`]
+    pub fn read_memory_continue<'l>(&mut self, d: &'l mut [u8]) -> Result<&'l mut [u8], Iface::Error> {
+        self.lcd.read_memory_continue(d)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::init::Config;
+    use crate::mock::MockInterface;
+    use crate::orientation::Orientation;
+
+    /// Delay source recording the waits in ms
+    #[derive(Default)]
+    struct Waits(Vec<u32>);
+
+    impl DelayNs for Waits {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0.push(ns / 1_000_000);
+        }
+    }
+
+    #[test]
+    fn transitions_and_registers() {
+        let mock = MockInterface::new();
+        let display = Display::new(Controller::new(mock.clone())).ok().unwrap();
+        let mut display = display.sleep_out().ok().unwrap().display_on().ok().unwrap();
+        assert_eq!(display.registers().set_orientation(Orientation::Landscape, true).unwrap(), (320, 240));
+        display.memory_write(&[0x12, 0x34]).unwrap();
+        let display = display.idle_mode_on().ok().unwrap().idle_mode_off().ok().unwrap();
+        display.display_off().ok().unwrap().enter_sleep_mode().ok().unwrap();
+
+        mock.expect_command(0x01)
+            .expect_command(0x11)
+            .expect_command(0x29)
+            .expect_parameters(0x36, &[0x28])
+            .expect_parameters(0x2C, &[0x12, 0x34])
+            .expect_command(0x39)
+            .expect_command(0x38)
+            .expect_command(0x28)
+            .expect_command(0x10)
+            .done();
+    }
+
+    #[test]
+    fn sleep_transitions_wait() {
+        let mock = MockInterface::new();
+        let display = Display::new(Controller::new(mock.clone()).with_delay(Waits::default())).ok().unwrap();
+        let display = display.sleep_out().ok().unwrap();
+        let display = display.display_on().ok().unwrap().display_off().ok().unwrap();
+        let display = display.enter_sleep_mode().ok().unwrap();
+        let lcd = display.sleep_out().ok().unwrap().release();
+
+        // The 120 ms guard between the reset and the sleep in/out commands, 5 ms after each
+        assert_eq!(lcd.delay.0, [115, 5, 115, 5, 115, 5, 115, 5]);
+        mock.expect_command(0x01)
+            .expect_command(0x11)
+            .expect_command(0x29)
+            .expect_command(0x28)
+            .expect_command(0x10)
+            .expect_command(0x11)
+            .done();
+    }
+
+    #[test]
+    fn partial_mode() {
+        let mock = MockInterface::new();
+        let display = Display::new(Controller::new(mock.clone())).ok().unwrap();
+        let mut display = display.sleep_out().ok().unwrap().display_on().ok().unwrap().partial_mode_on().ok().unwrap();
+        display.registers().partial_area(|w| w.sr(0).er(99)).unwrap();
+        display.normal_display_mode_on().ok().unwrap();
+
+        mock.expect_command(0x01)
+            .expect_command(0x11)
+            .expect_command(0x29)
+            .expect_command(0x12)
+            .expect_parameters(0x30, &[0x00, 0x00, 0x00, 0x63])
+            .expect_command(0x13)
+            .done();
+    }
+
+    #[test]
+    fn assume_keeps_the_initialized_display() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        lcd.init(&Config::default()).unwrap();
+        mock.clear();
+
+        let display = Display::<_, DisplayOn>::assume(lcd);
+        display.display_off().ok().unwrap();
+        // No reset
+        mock.expect_command(0x28).done();
+    }
+}
@@ -0,0 +1,134 @@
+//! Register access of the typed Controller wrappers
+//!
+//! [`power::Display`](crate::power::Display) and [`pixel::PixelController`](crate::pixel::PixelController)
+//! keep state of the display in their type. Instead of the Controller they hand out
+//! [`Registers`], which has the register commands of the Controller except the ones that would
+//! put either type out of sync:
+//! - the reset and sleep in/out commands
+//! - the display on/off, partial, normal and idle mode commands
+//! - Pixel Format Set (3Ah)
+//! - the frame memory transfers, the wrappers have their own
+//!
+//! ```compile_fail
+//! # use ili9341_cmd::{Interface, power::{Display, Sleep}};
+//! fn clear<Iface: Interface>(display: &mut Display<Iface, Sleep>) {
+//!     display.registers().memory_write(&[0x00; 2]); // still sleeping
+//! }
+//! ```
+//!
+//! ```compile_fail
+//! # use ili9341_cmd::{Interface, pixel::{PixelController, Rgb565}};
+//! fn switch<Iface: Interface>(pixels: &mut PixelController<Iface, Rgb565>) {
+//!     pixels.registers().pixel_format_set(|w| w); // the type stays Rgb565
+//! }
+//! ```
+
+use embedded_hal::delay::DelayNs;
+
+use crate::checked;
+use crate::madctl::Madctl;
+use crate::orientation::Orientation;
+use crate::shadow::RegisterCache;
+// The command table refers to the register modules relative to the crate root
+use crate::*;
+
+/// Generates the Registers methods from the command table, forwarding to the Controller
+///
+/// The entries which change the power state or the pixel format and the frame memory transfers
+/// are left out.
+macro_rules! register_commands {
+    (@fn $(#[$attr:meta])* timed $name:ident $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* command partial_mode_on $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* command normal_display_mode_on $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* command display_off $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* command display_on $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* command idle_mode_off $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* command idle_mode_on $cmd:literal) => {};
+    (@fn $(#[$attr:meta])* write pixel_format_set $cmd:literal $reg:ty, $w:ty) => {};
+    (@fn $(#[$attr:meta])* command $name:ident $cmd:literal) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<(), Iface::Error> {
+            self.lcd.$name()
+        }
+    };
+    (@fn $(#[$attr:meta])* read $name:ident $cmd:literal $reg:ty) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<$reg, Iface::Error> {
+            self.lcd.$name()
+        }
+    };
+    (@fn $(#[$attr:meta])* read_ext $name:ident $cmd:literal $reg:ty) => {
+        $(#[$attr])*
+        pub fn $name(&mut self) -> Result<$reg, Iface::Error> {
+            self.lcd.$name()
+        }
+    };
+    (@fn $(#[$attr:meta])* write $name:ident $cmd:literal $reg:ty, $w:ty) => {
+        $(#[$attr])*
+        pub fn $name<F>(&mut self, f: F) -> Result<(), Iface::Error>
+        where
+            F: FnOnce($w) -> $w
+        {
+            self.lcd.$name(f)
+        }
+    };
+    // Frame memory transfers
+    (@fn $(#[$attr:meta])* $kind:ident $name:ident $cmd:literal) => {};
+    ($($(#[$attr:meta])* $kind:ident $name:ident = $cmd:literal $(=> $($ty:ty),+)?;)*) => {
+        $(register_commands! { @fn $(#[$attr])* $kind $name $cmd $($($ty),+)? })*
+    };
+}
+
+/// The register commands of a wrapped Controller, see the [module documentation](self)
+pub struct Registers<'c, Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+{
+    lcd: &'c mut Controller<Iface, Delay, Reset, Cache>,
+}
+
+impl<'c, Iface, Delay, Reset, Cache> Registers<'c, Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    pub(crate) fn new(lcd: &'c mut Controller<Iface, Delay, Reset, Cache>) -> Self {
+        Registers { lcd }
+    }
+
+    /// See [`Controller::set_orientation`]
+    pub fn set_orientation(&mut self, orientation: Orientation, bgr: bool) -> Result<(u16, u16), Iface::Error> {
+        self.lcd.set_orientation(orientation, bgr)
+    }
+    /// See [`Controller::write_madctl`]
+    pub fn write_madctl(&mut self, madctl: Madctl) -> Result<(), Iface::Error> {
+        self.lcd.write_madctl(madctl)
+    }
+    /// See [`Controller::read_madctl`]
+    pub fn read_madctl(&mut self) -> Result<Madctl, Iface::Error> {
+        self.lcd.read_madctl()
+    }
+    /// See [`Controller::column_address_set_checked`]
+    pub fn column_address_set_checked(&mut self, sc: u16, ec: u16) -> Result<(), checked::Error<Iface::Error>> {
+        self.lcd.column_address_set_checked(sc, ec)
+    }
+    /// See [`Controller::page_address_set_checked`]
+    pub fn page_address_set_checked(&mut self, sp: u16, ep: u16) -> Result<(), checked::Error<Iface::Error>> {
+        self.lcd.page_address_set_checked(sp, ep)
+    }
+    /// See [`Controller::partial_area_checked`]
+    pub fn partial_area_checked(&mut self, sr: u16, er: u16) -> Result<(), checked::Error<Iface::Error>> {
+        self.lcd.partial_area_checked(sr, er)
+    }
+    /// See [`Controller::vertical_scrolling_definition_checked`]
+    pub fn vertical_scrolling_definition_checked(&mut self, tfa: u16, vsa: u16, bfa: u16) -> Result<(), checked::Error<Iface::Error>> {
+        self.lcd.vertical_scrolling_definition_checked(tfa, vsa, bfa)
+    }
+    /// See [`Controller::set_tear_scanline_checked`]
+    pub fn set_tear_scanline_checked(&mut self, sts: u16) -> Result<(), checked::Error<Iface::Error>> {
+        self.lcd.set_tear_scanline_checked(sts)
+    }
+
+    command_table!(register_commands);
+}
@@ -0,0 +1,213 @@
+//! Shadow copies of the written registers
+//!
+//! A Controller created with `with_shadow_registers` keeps a copy of every register it writes,
+//! so the current settings can be queried without bus traffic, e.g. on boards without MISO line.
+//! [`ShadowRegisters`] has one accessor per register write method of the Controller, returning
+//! `None` until the register is written. After a reset the accessors return the reset defaults.
+//!
+//! Only the writes through the Controller are seen, changes made behind its back (e.g. a raw
+//! command on the released interface) are not.
+
+// The command table refers to the register modules relative to the crate root
+use crate::*;
+
+/// Cache of the register writes, hooked into the Controller
+pub trait RegisterCache {
+    /// Called for every command sent with parameters
+    fn update(&mut self, command: u8, data: &[u8]);
+    /// Called after a hardware or software reset
+    fn reset(&mut self);
+}
+
+/// Cache of a Controller without shadow registers
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoCache;
+
+impl RegisterCache for NoCache {
+    #[inline(always)]
+    fn update(&mut self, _command: u8, _data: &[u8]) {}
+    #[inline(always)]
+    fn reset(&mut self) {}
+}
+
+/// Generates ShadowRegisters from the command table
+///
+/// Every table entry gets a field, entries other than `write` have a zero sized one.
+macro_rules! shadow_registers {
+    (@type write $reg:ty, $w:ty) => { Option<$reg> };
+    (@type $kind:ident $($ty:ty),*) => { () };
+    (@get $(#[$attr:meta])* write $name:ident $reg:ty, $w:ty) => {
+        $(#[$attr])*
+        pub fn $name(&self) -> Option<$reg> {
+            self.$name
+        }
+    };
+    (@get $(#[$attr:meta])* $kind:ident $name:ident $($ty:ty),*) => {};
+    (@update $self:ident, $command:ident, $data:ident, write $name:ident $cmd:literal $reg:ty, $w:ty) => {
+        if $command == $cmd {
+            let mut r = <$reg>::default();
+            let n = r.data.len().min($data.len());
+            r.data[..n].copy_from_slice(&$data[..n]);
+            $self.$name = Some(r);
+        }
+    };
+    (@update $self:ident, $command:ident, $data:ident, $kind:ident $name:ident $cmd:literal $($ty:ty),*) => {};
+    (@reset $self:ident, write $name:ident $reg:ty, $w:ty) => {
+        $self.$name = Some(<$reg>::default());
+    };
+    (@reset $self:ident, $kind:ident $name:ident $($ty:ty),*) => {};
+    ($($(#[$attr:meta])* $kind:ident $name:ident = $cmd:literal $(=> $($ty:ty),+)?;)*) => {
+        /// Shadow copies of the written registers, see the [module documentation](self)
+        #[derive(Clone, Debug, Default)]
+        // The fields of the entries other than `write` are never read
+        #[allow(dead_code)]
+        pub struct ShadowRegisters {
+            $($(#[$attr])* $name: shadow_registers!(@type $kind $($($ty),+)?),)*
+        }
+
+        impl ShadowRegisters {
+            $(shadow_registers! { @get $(#[$attr])* $kind $name $($($ty),+)? })*
+        }
+
+        impl RegisterCache for ShadowRegisters {
+            // The cfg attributes of the table come with its doc comments
+            #[allow(unused_doc_comments)]
+            fn update(&mut self, command: u8, data: &[u8]) {
+                $($(#[$attr])* shadow_registers! { @update self, command, data, $kind $name $cmd $($($ty),+)? })*
+            }
+            #[allow(unused_doc_comments)]
+            fn reset(&mut self) {
+                $($(#[$attr])* shadow_registers! { @reset self, $kind $name $($($ty),+)? })*
+            }
+        }
+    };
+}
+
+command_table!(shadow_registers);
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Keep shadow copies of the written registers, see [`shadow`](crate::shadow)
+    pub fn with_shadow_registers(self) -> Controller<Iface, Delay, Reset, ShadowRegisters> {
+        Controller {
+            iface: self.iface,
+            delay: self.delay,
+            reset: self.reset,
+            sleep_guard: self.sleep_guard,
+            row_column_exchange: self.row_column_exchange,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended_read: self.extended_read,
+            cache: ShadowRegisters::default(),
+        }
+    }
+}
+
+impl<Iface, Delay, Reset> Controller<Iface, Delay, Reset, ShadowRegisters>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+{
+    /// The shadow copies of the written registers
+    pub fn shadow_registers(&self) -> &ShadowRegisters {
+        &self.cache
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::gamma::CurveSelected;
+    use crate::mock::{MockInterface, Transaction};
+    use crate::pixel_format::McuInterfaceFormat;
+
+    /// The parameters of the last write of `command`
+    fn written(mock: &MockInterface, command: u8) -> Vec<u8> {
+        mock.transcript()
+            .into_iter()
+            .rev()
+            .find_map(|t| match t {
+                Transaction::Write(c, data) if c == command => Some(data),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn writes_are_cached() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone()).with_shadow_registers();
+        assert_eq!(lcd.shadow_registers().memory_access_control(), None);
+
+        lcd.memory_access_control(|w| w.row_column_exchange(true)).unwrap();
+        lcd.pixel_format_set(|w| w.mcu_interface_format(McuInterfaceFormat::N16Bits)).unwrap();
+        lcd.column_address_set(|w| w.sc(10).ec(309)).unwrap();
+        lcd.page_address_set(|w| w.sp(20).ep(219)).unwrap();
+        lcd.vertical_scrolling_definition(|w| w.tfa(16).vsa(288).bfa(16)).unwrap();
+        lcd.gamma_set(|w| w.curve_selected(CurveSelected::GammaCurve1G2o2)).unwrap();
+
+        let shadow = lcd.shadow_registers();
+        assert_eq!(shadow.memory_access_control().unwrap().as_bytes()[..], written(&mock, 0x36));
+        assert_eq!(shadow.pixel_format_set().unwrap().as_bytes()[..], written(&mock, 0x3A));
+        assert_eq!(shadow.column_address_set().unwrap().as_bytes()[..], [0x00, 0x0A, 0x01, 0x35]);
+        assert_eq!(shadow.page_address_set().unwrap().as_bytes()[..], written(&mock, 0x2B));
+        assert_eq!(shadow.vertical_scrolling_definition().unwrap().as_bytes()[..], [0x00, 0x10, 0x01, 0x20, 0x00, 0x10]);
+        assert_eq!(shadow.gamma_set().unwrap().as_bytes()[..], written(&mock, 0x26));
+        // Not written yet
+        assert_eq!(shadow.partial_area(), None);
+    }
+
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    #[test]
+    fn extended_writes_are_cached() {
+        use crate::power_control1::Gvdd;
+
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone()).with_shadow_registers();
+        lcd.power_control1(|w| w.gvdd(Gvdd::N3o00V)).unwrap();
+        lcd.positive_gamma_correction(|w| w.vp63(0x0F).vp62(0x31)).unwrap();
+
+        let shadow = lcd.shadow_registers();
+        assert_eq!(shadow.power_control1().unwrap().as_bytes()[..], written(&mock, 0xC0));
+        assert_eq!(shadow.positive_gamma_correction().unwrap().as_bytes()[..], written(&mock, 0xE0));
+    }
+
+    #[test]
+    fn raw_writes_are_cached() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone()).with_shadow_registers();
+        lcd.send_parameters(0x36, &[0xE8]).unwrap();
+        assert_eq!(lcd.shadow_registers().memory_access_control().unwrap().into_bytes(), [0xE8]);
+    }
+
+    #[test]
+    fn queries_stay_off_the_bus() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone()).with_shadow_registers();
+        lcd.memory_access_control(|w| w.row_column_exchange(true)).unwrap();
+        mock.clear();
+
+        let shadow = lcd.shadow_registers();
+        shadow.memory_access_control();
+        shadow.pixel_format_set();
+        shadow.column_address_set();
+        assert!(mock.transcript().is_empty());
+    }
+
+    #[test]
+    fn reset_restores_the_defaults() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone()).with_shadow_registers();
+        lcd.column_address_set(|w| w.sc(10).ec(309)).unwrap();
+        lcd.software_reset().unwrap();
+        assert_eq!(lcd.shadow_registers().column_address_set(), Some(Default::default()));
+        assert_eq!(lcd.shadow_registers().partial_area(), Some(Default::default()));
+    }
+}
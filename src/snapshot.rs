@@ -0,0 +1,407 @@
+//! Dump and restore of the register state
+//!
+//! [`Controller::snapshot`] reads every readable register into a [`RegisterSnapshot`], which can
+//! be compared with the one of a known-good unit or printed field by field with `{:#}` on its
+//! members. [`Controller::restore`] writes the registers with parameters back through their typed
+//! writers; the power mode (sleep, display on/off, partial, idle) and inversion are left to the
+//! application.
+//!
+//! The extended registers are read with the mode set by `with_extended_read`, see
+//! [`extended`](crate::extended).
+
+use embedded_hal::delay::DelayNs;
+
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+use crate::*;
+use crate::madctl::Madctl;
+use crate::read_cabc_minimum_brightness::CabcMinimumBrightness;
+use crate::read_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl;
+use crate::read_ctrl_display::CtrlDisplay;
+use crate::read_display_brightness::DisplayBrightness;
+use crate::read_display_identification_information::DisplayIdentificationInformation;
+use crate::read_display_image_format::DisplayImageFormat;
+use crate::read_display_madctl::DisplayMadctl;
+use crate::read_display_pixel_format::DisplayPixelFormat;
+use crate::read_display_power_mode::DisplayPowerMode;
+use crate::read_display_self_diagnostic_result::DisplaySelfDiagnosticResult;
+use crate::read_display_signal_mode::DisplaySignalMode;
+use crate::read_display_status::DisplayStatus;
+use crate::read_id1::Id1;
+use crate::read_id2::Id2;
+use crate::read_id3::Id3;
+use crate::shadow::RegisterCache;
+use crate::{gamma, pixel_format, write_cabc_minimum_brightness, write_content_adaptive_brightness_control};
+use crate::{write_ctrl_display, write_display_brightness, Controller, Interface};
+
+/// Content of the readable registers, see the [module documentation](self)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RegisterSnapshot {
+    /// 04h
+    pub display_identification_information: DisplayIdentificationInformation,
+    /// 09h
+    pub display_status: DisplayStatus,
+    /// 0Ah
+    pub display_power_mode: DisplayPowerMode,
+    /// 0Bh
+    pub display_madctl: DisplayMadctl,
+    /// 0Ch
+    pub display_pixel_format: DisplayPixelFormat,
+    /// 0Dh
+    pub display_image_format: DisplayImageFormat,
+    /// 0Eh
+    pub display_signal_mode: DisplaySignalMode,
+    /// 0Fh
+    pub display_self_diagnostic_result: DisplaySelfDiagnosticResult,
+    /// 52h
+    pub display_brightness: DisplayBrightness,
+    /// 54h
+    pub ctrl_display: CtrlDisplay,
+    /// 56h
+    pub content_adaptive_brightness_control: ContentAdaptiveBrightnessControl,
+    /// 5Fh
+    pub cabc_minimum_brightness: CabcMinimumBrightness,
+    /// DAh
+    pub id1: Id1,
+    /// DBh
+    pub id2: Id2,
+    /// DCh
+    pub id3: Id3,
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    pub extended: ExtendedSnapshot,
+}
+
+/// Content of the readable extended registers
+#[cfg(feature = "Ili9341ExtendedCommandSet")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedSnapshot {
+    /// B0h
+    pub rgb_interface_signal_control: rgb_interface_signal_control::RgbInterfaceSignalControl,
+    /// B1h
+    pub frame_control_in_normal_mode: frame_control_in_normal_mode::FrameControlInNormalMode,
+    /// B2h
+    pub frame_control_in_idle_mode: frame_control_in_idle_mode::FrameControlInIdleMode,
+    /// B3h
+    pub frame_control_in_partial_mode: frame_control_in_partial_mode::FrameControlInPartialMode,
+    /// B4h
+    pub display_inversion_control: display_inversion_control::DisplayInversionControl,
+    /// B5h
+    pub blanking_porch_control: blanking_porch_control::BlankingPorchControl,
+    /// B6h
+    pub display_function_control: display_function_control::DisplayFunctionControl,
+    /// B7h
+    pub entry_mode: entry_mode::EntryModeSet,
+    /// B8h
+    pub backlight_control1: backlight_control1::BacklightControl1,
+    /// B9h
+    pub backlight_control2: backlight_control2::BacklightControl2,
+    /// BAh
+    pub backlight_control3: backlight_control3::BacklightControl3,
+    /// BBh
+    pub backlight_control4: backlight_control4::BacklightControl4,
+    /// BCh
+    pub backlight_control5: backlight_control5::BacklightControl5,
+    /// BEh
+    pub backlight_control7: backlight_control7::BacklightControl7,
+    /// BFh
+    pub backlight_control8: backlight_control8::BacklightControl8,
+    /// C0h
+    pub power_control1: power_control1::PowerControl1,
+    /// C1h
+    pub power_control2: power_control2::PowerControl2,
+    /// C5h
+    pub vcom_control1: vcom_control1::VcomControl1,
+    /// C7h
+    pub vcom_control2: vcom_control2::VcomControl2,
+    /// D2h
+    pub nv_memory_status: nv_memory_status_read::NvMemoryStatus,
+    /// D3h
+    pub id4: read_id4::Id4,
+    /// E0h
+    pub positive_gamma_correction: positive_gamma_correction::PositiveGammaCorrection,
+    /// E1h
+    pub negative_gamma_correction: negative_gamma_correction::NegativeGammaCorrection,
+    /// F6h
+    pub interface_control: interface_control::InterfaceControl,
+}
+
+/// The register read as all 00h or FFh, what a read without MISO line returns
+fn unread(data: &[u8]) -> bool {
+    data.iter().all(|&b| b == 0x00) || data.iter().all(|&b| b == 0xFF)
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Read every readable register
+    pub fn snapshot(&mut self) -> Result<RegisterSnapshot, Iface::Error> {
+        Ok(RegisterSnapshot {
+            display_identification_information: self.read_display_identification_information()?,
+            display_status: self.read_display_status()?,
+            display_power_mode: self.read_display_power_mode()?,
+            display_madctl: self.read_display_madctl()?,
+            display_pixel_format: self.read_display_pixel_format()?,
+            display_image_format: self.read_display_image_format()?,
+            display_signal_mode: self.read_display_signal_mode()?,
+            display_self_diagnostic_result: self.read_display_self_diagnostic_result()?,
+            display_brightness: self.read_display_brightness()?,
+            ctrl_display: self.read_ctrl_display()?,
+            content_adaptive_brightness_control: self.read_content_adaptive_brightness_control()?,
+            cabc_minimum_brightness: self.read_cabc_minimum_brightness()?,
+            id1: self.read_id1()?,
+            id2: self.read_id2()?,
+            id3: self.read_id3()?,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended: ExtendedSnapshot {
+                rgb_interface_signal_control: self.read_rgb_interface_signal_control()?,
+                frame_control_in_normal_mode: self.read_frame_control_in_normal_mode()?,
+                frame_control_in_idle_mode: self.read_frame_control_in_idle_mode()?,
+                frame_control_in_partial_mode: self.read_frame_control_in_partial_mode()?,
+                display_inversion_control: self.read_display_inversion_control()?,
+                blanking_porch_control: self.read_blanking_porch_control()?,
+                display_function_control: self.read_display_function_control()?,
+                entry_mode: self.read_entry_mode()?,
+                backlight_control1: self.read_backlight_control1()?,
+                backlight_control2: self.read_backlight_control2()?,
+                backlight_control3: self.read_backlight_control3()?,
+                backlight_control4: self.read_backlight_control4()?,
+                backlight_control5: self.read_backlight_control5()?,
+                backlight_control7: self.read_backlight_control7()?,
+                backlight_control8: self.read_backlight_control8()?,
+                power_control1: self.read_power_control1()?,
+                power_control2: self.read_power_control2()?,
+                vcom_control1: self.read_vcom_control1()?,
+                vcom_control2: self.read_vcom_control2()?,
+                nv_memory_status: self.nv_memory_status_read()?,
+                id4: self.read_id4()?,
+                positive_gamma_correction: self.read_positive_gamma_correction()?,
+                negative_gamma_correction: self.read_negative_gamma_correction()?,
+                interface_control: self.read_interface_control()?,
+            },
+        })
+    }
+
+    /// Write the registers with parameters of `snapshot` back
+    /// The identification, status, power mode and NV memory registers are read only. The pixel
+    /// format and the extended registers are skipped if they read as all 00h or FFh, like on a
+    /// board without MISO line, writing those would misconfigure the panel. A register whose
+    /// actual value is all 00h (e.g. BFh after a reset) is left as it is then.
+    pub fn restore(&mut self, snapshot: &RegisterSnapshot) -> Result<(), Iface::Error> {
+        self.write_madctl(Madctl::from(snapshot.display_madctl))?;
+        if !unread(snapshot.display_pixel_format.as_bytes()) {
+            let r = pixel_format::PixelFormatSet::from_bytes(snapshot.display_pixel_format.into_bytes());
+            self.pixel_format_set(|w| w.copy_from(r))?;
+        }
+        // 0Dh reads the number of the gamma curve (D2-D0, 0 to 3), 26h selects it by bit
+        let r = gamma::GammaSet::from_bytes([1 << (snapshot.display_image_format.as_bytes()[0] & 0x03)]);
+        self.gamma_set(|w| w.copy_from(r))?;
+        let r = write_display_brightness::DisplayBrightness::from_bytes(snapshot.display_brightness.into_bytes());
+        self.write_display_brightness(|w| w.copy_from(r))?;
+        let r = write_ctrl_display::CtrlDisplay::from_bytes(snapshot.ctrl_display.into_bytes());
+        self.write_ctrl_display(|w| w.copy_from(r))?;
+        let r = write_content_adaptive_brightness_control::ContentAdaptiveBrightnessControl::from_bytes(
+            snapshot.content_adaptive_brightness_control.into_bytes(),
+        );
+        self.write_content_adaptive_brightness_control(|w| w.copy_from(r))?;
+        let r = write_cabc_minimum_brightness::CabcMinimumBrightness::from_bytes(snapshot.cabc_minimum_brightness.into_bytes());
+        self.write_cabc_minimum_brightness(|w| w.copy_from(r))?;
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        {
+            let e = &snapshot.extended;
+            macro_rules! restore_read {
+                ($($field:ident => $writer:ident),*) => {
+                    $(if !unread(e.$field.as_bytes()) {
+                        self.$writer(|w| w.copy_from(e.$field))?;
+                    })*
+                };
+            }
+            restore_read! {
+                rgb_interface_signal_control => rgb_interface_signal_control,
+                frame_control_in_normal_mode => frame_control_in_normal_mode,
+                frame_control_in_idle_mode => frame_control_in_idle_mode,
+                frame_control_in_partial_mode => frame_control_in_partial_mode,
+                display_inversion_control => display_inversion_control,
+                blanking_porch_control => blanking_porch_control,
+                display_function_control => display_function_control,
+                entry_mode => entry_mode_set,
+                backlight_control1 => backlight_control1,
+                backlight_control2 => backlight_control2,
+                backlight_control3 => backlight_control3,
+                backlight_control4 => backlight_control4,
+                backlight_control5 => backlight_control5,
+                backlight_control7 => backlight_control7,
+                backlight_control8 => backlight_control8,
+                power_control1 => power_control1,
+                power_control2 => power_control2,
+                vcom_control1 => vcom_control1,
+                vcom_control2 => vcom_control2,
+                positive_gamma_correction => positive_gamma_correction,
+                negative_gamma_correction => negative_gamma_correction,
+                interface_control => interface_control
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::mock::{MockInterface, Transaction};
+
+    /// The reset defaults of every register
+    fn defaults() -> RegisterSnapshot {
+        RegisterSnapshot {
+            display_identification_information: Default::default(),
+            display_status: Default::default(),
+            display_power_mode: Default::default(),
+            display_madctl: Default::default(),
+            display_pixel_format: Default::default(),
+            display_image_format: Default::default(),
+            display_signal_mode: Default::default(),
+            display_self_diagnostic_result: Default::default(),
+            display_brightness: Default::default(),
+            ctrl_display: Default::default(),
+            content_adaptive_brightness_control: Default::default(),
+            cabc_minimum_brightness: Default::default(),
+            id1: Default::default(),
+            id2: Default::default(),
+            id3: Default::default(),
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended: ExtendedSnapshot {
+                rgb_interface_signal_control: Default::default(),
+                frame_control_in_normal_mode: Default::default(),
+                frame_control_in_idle_mode: Default::default(),
+                frame_control_in_partial_mode: Default::default(),
+                display_inversion_control: Default::default(),
+                blanking_porch_control: Default::default(),
+                display_function_control: Default::default(),
+                entry_mode: Default::default(),
+                backlight_control1: Default::default(),
+                backlight_control2: Default::default(),
+                backlight_control3: Default::default(),
+                backlight_control4: Default::default(),
+                backlight_control5: Default::default(),
+                backlight_control7: Default::default(),
+                backlight_control8: Default::default(),
+                power_control1: Default::default(),
+                power_control2: Default::default(),
+                vcom_control1: Default::default(),
+                vcom_control2: Default::default(),
+                nv_memory_status: Default::default(),
+                id4: Default::default(),
+                positive_gamma_correction: Default::default(),
+                negative_gamma_correction: Default::default(),
+                interface_control: Default::default(),
+            },
+        }
+    }
+
+    #[cfg(not(feature = "Ili9341ExtendedCommandSet"))]
+    #[test]
+    fn snapshot_reads_every_register() {
+        let mock = MockInterface::new();
+        mock.push_response(0x04, &[0x00, 0x93, 0x41])
+            .push_response(0x09, &[0x00, 0x61, 0x00, 0x00])
+            .push_response(0x0A, &[0x9C])
+            .push_response(0x0B, &[0x48])
+            .push_response(0x0C, &[0x55])
+            .push_response(0x0D, &[0x00])
+            .push_response(0x0E, &[0x00])
+            .push_response(0x0F, &[0xC0])
+            .push_response(0x52, &[0x00])
+            .push_response(0x54, &[0x00])
+            .push_response(0x56, &[0x00])
+            .push_response(0x5F, &[0x00])
+            .push_response(0xDA, &[0x00])
+            .push_response(0xDB, &[0x93])
+            .push_response(0xDC, &[0x41]);
+        let mut lcd = Controller::new(mock.clone());
+        let snapshot = lcd.snapshot().unwrap();
+        assert_eq!(snapshot.display_madctl, DisplayMadctl::from_bytes([0x48]));
+        assert_eq!(snapshot.id3, Id3::from_bytes([0x41]));
+
+        mock.expect_read(0x04, 3).expect_read(0x09, 4);
+        for command in [0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x52, 0x54, 0x56, 0x5F, 0xDA, 0xDB, 0xDC] {
+            mock.expect_read(command, 1);
+        }
+        mock.done();
+    }
+
+    #[test]
+    fn restore_writes_the_user_registers() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let mut snapshot = defaults();
+        snapshot.display_madctl = DisplayMadctl::from_bytes([0x48]);
+        snapshot.display_pixel_format = DisplayPixelFormat::from_bytes([0x55]);
+        snapshot.display_image_format = DisplayImageFormat::from_bytes([0x07]);
+        snapshot.display_brightness = DisplayBrightness::from_bytes([0x80]);
+        snapshot.ctrl_display = CtrlDisplay::from_bytes([0x2C]);
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        {
+            snapshot.extended.frame_control_in_normal_mode =
+                frame_control_in_normal_mode::FrameControlInNormalMode::from_bytes([0x00, 0x1B]);
+        }
+        lcd.restore(&snapshot).unwrap();
+        #[cfg(feature = "Ili9341ExtendedCommandSet")]
+        assert!(mock.transcript().contains(&Transaction::Write(0xB1, std::vec![0x00, 0x1B])));
+
+        mock.expect_parameters(0x36, &[0x48])
+            .expect_parameters(0x3A, &[0x55])
+            // Curve 3 of 0Dh, D2 is masked off, inversion is left out
+            .expect_parameters(0x26, &[0x08])
+            .expect_parameters(0x51, &[0x80])
+            .expect_parameters(0x53, &[0x2C])
+            .expect_parameters(0x55, &[0x00])
+            .expect_parameters(0x5E, &[0x00]);
+        #[cfg(not(feature = "Ili9341ExtendedCommandSet"))]
+        mock.done();
+    }
+
+    #[test]
+    fn unread_pixel_format_is_skipped() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let mut snapshot = defaults();
+        snapshot.display_pixel_format = DisplayPixelFormat::from_bytes([0xFF]);
+        lcd.restore(&snapshot).unwrap();
+        assert!(!mock.transcript().iter().any(|t| matches!(t, Transaction::Write(0x3A, _))));
+    }
+
+    #[cfg(feature = "Ili9341ExtendedCommandSet")]
+    #[test]
+    fn unread_extended_registers_are_skipped() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone());
+        let mut snapshot = defaults();
+        let e = &mut snapshot.extended;
+        e.rgb_interface_signal_control = rgb_interface_signal_control::RgbInterfaceSignalControl::from_bytes([0x00]);
+        e.power_control1 = power_control1::PowerControl1::from_bytes([0xFF]);
+        e.power_control2 = power_control2::PowerControl2::from_bytes([0x00]);
+        e.vcom_control1 = vcom_control1::VcomControl1::from_bytes([0xFF, 0xFF]);
+        e.vcom_control2 = vcom_control2::VcomControl2::from_bytes([0x00]);
+        e.interface_control = interface_control::InterfaceControl::from_bytes([0xFF; 3]);
+        e.frame_control_in_normal_mode = frame_control_in_normal_mode::FrameControlInNormalMode::from_bytes([0x00, 0x00]);
+        e.positive_gamma_correction = positive_gamma_correction::PositiveGammaCorrection::from_bytes([0xFF; 15]);
+        lcd.restore(&snapshot).unwrap();
+
+        let written: std::vec::Vec<u8> = mock
+            .transcript()
+            .into_iter()
+            .map(|t| match t {
+                Transaction::Write(c, _) => c,
+                t => panic!("unexpected {:02X?}", t),
+            })
+            .collect();
+        // BFh reads 00h at its reset default
+        assert_eq!(
+            written,
+            [0x36, 0x3A, 0x26, 0x51, 0x53, 0x55, 0x5E, 0xB2, 0xB3, 0xB4, 0xB5, 0xB6, 0xB7, 0xB8, 0xB9, 0xBA, 0xBB, 0xBC, 0xBE, 0xE1]
+        );
+    }
+}
@@ -0,0 +1,754 @@
+//! SPI implementations of the Interface trait
+
+use core::task::{Context, Poll};
+
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::{Operation, SpiDevice};
+
+use crate::dma::{DmaInterface, TransferError};
+use crate::{read_continuation, Interface};
+
+/// Size of the stack buffer used by the chunked transfers
+const CHUNK_SIZE: usize = 48;
+
+/// An enumeration of SPI interface errors
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Error<SpiError, PinError> {
+    /// Error on the SPI bus
+    Spi(SpiError),
+    /// Error while setting the D/C pin
+    Pin(PinError),
+}
+
+/// Dummy cycles the display inserts between a read command and its data on the serial interface
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum Dummy {
+    /// Data follows the command immediately
+    None,
+    /// One dummy clock cycle (24/32-bit register reads)
+    Bit,
+    /// One dummy byte (frame memory and extended register reads)
+    Byte,
+}
+
+pub(crate) fn read_dummy(command: u8) -> Dummy {
+    match command {
+        0x04 | 0x09 | 0x45 => Dummy::Bit,
+        0x0A..=0x0F | 0x52 | 0x54 | 0x56 | 0x5F | 0xDA..=0xDC => Dummy::None,
+        _ => Dummy::Byte,
+    }
+}
+
+/// Shift a read which started one dummy clock early back into place
+pub(crate) fn drop_dummy_bit(data: &mut [u8], next: u8) {
+    let len = data.len();
+    for i in 0..len {
+        let low = if i + 1 < len { data[i + 1] } else { next };
+        data[i] = (data[i] << 1) | (low >> 7);
+    }
+}
+
+/// Feed an iterator of output bytes to `read` in chunks
+///
+/// `read` is called with the command for the first chunk and with its continuation for the others.
+/// The chunk size is a multiple of 3, so frame memory reads are always resumed at a pixel boundary.
+///
+/// A read which can't be resumed stops after the first chunk, the bytes past it are left unchanged.
+pub(crate) fn read_chunked<'l, T, E, F>(command: u8, data: T, mut read: F) -> Result<(), E>
+where
+    T: IntoIterator<Item = &'l mut u8>,
+    F: FnMut(u8, &mut [u8]) -> Result<(), E>,
+{
+    let mut data = data.into_iter();
+    let mut command = Some(command);
+    let mut buf = [0u8; CHUNK_SIZE];
+    while let Some(cmd) = command {
+        let mut refs: [Option<&'l mut u8>; CHUNK_SIZE] = core::array::from_fn(|_| None);
+        let mut n = 0;
+        for r in refs.iter_mut() {
+            match data.next() {
+                Some(d) => *r = Some(d),
+                None => break,
+            }
+            n += 1;
+        }
+        if n == 0 {
+            break;
+        }
+        read(cmd, &mut buf[..n])?;
+        for (r, b) in refs.iter_mut().zip(buf.iter()).take(n) {
+            if let Some(r) = r.take() {
+                *r = *b;
+            }
+        }
+        if n < CHUNK_SIZE {
+            break;
+        }
+        command = read_continuation(cmd);
+    }
+    Ok(())
+}
+
+/// Feed a slice to `read` in chunks, see [`read_chunked`]
+pub(crate) fn read_slice_chunked<E, F>(command: u8, data: &mut [u8], mut read: F) -> Result<(), E>
+where
+    F: FnMut(u8, &mut [u8]) -> Result<(), E>,
+{
+    let mut command = Some(command);
+    for chunk in data.chunks_mut(CHUNK_SIZE) {
+        let cmd = match command {
+            Some(cmd) => cmd,
+            None => break,
+        };
+        read(cmd, chunk)?;
+        command = read_continuation(cmd);
+    }
+    Ok(())
+}
+
+/// 4-wire SPI interface ("4-line serial interface I")
+///
+/// The chip select is handled by the [`SpiDevice`], the D/C line is driven by a separate
+/// [`OutputPin`]. Reads require the SDO line of the display to be connected to MISO.
+pub struct SpiInterface<SPI, DC> {
+    spi: SPI,
+    dc: DC,
+    /// Buffer of the last background transfer, see [`DmaInterface`]
+    transfer: Option<&'static mut [u8]>,
+}
+
+impl<SPI, DC> SpiInterface<SPI, DC>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    pub fn new(spi: SPI, dc: DC) -> SpiInterface<SPI, DC> {
+        SpiInterface { spi, dc, transfer: None }
+    }
+
+    /// Release the SPI device and the D/C pin
+    pub fn release(self) -> (SPI, DC) {
+        (self.spi, self.dc)
+    }
+
+    #[inline(always)]
+    fn write_data(&mut self, data: &[u8]) -> Result<(), Error<SPI::Error, DC::Error>> {
+        self.dc.set_high().map_err(Error::Pin)?;
+        self.spi.write(data).map_err(Error::Spi)
+    }
+}
+
+impl<SPI, DC> Interface for SpiInterface<SPI, DC>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    type Error = Error<SPI::Error, DC::Error>;
+
+    fn command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.dc.set_low().map_err(Error::Pin)?;
+        self.spi.write(&[command]).map_err(Error::Spi)
+    }
+    fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.command(command)?;
+        if data.is_empty() {
+            return Ok(());
+        }
+        self.write_data(data)
+    }
+    /// Read parameters into a slice
+    ///
+    /// The command and the read are done in a single SPI transaction, the dummy cycle is skipped
+    /// according to the read command.
+    fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        self.dc.set_low().map_err(Error::Pin)?;
+        match read_dummy(command) {
+            Dummy::None => self.spi.transaction(&mut [
+                Operation::Write(&[command]),
+                Operation::Read(data),
+            ]),
+            Dummy::Byte => self.spi.transaction(&mut [
+                Operation::Write(&[command]),
+                Operation::Read(&mut [0]),
+                Operation::Read(data),
+            ]),
+            Dummy::Bit => {
+                let mut next = [0];
+                self.spi.transaction(&mut [
+                    Operation::Write(&[command]),
+                    Operation::Read(data),
+                    Operation::Read(&mut next),
+                ]).map(|_| drop_dummy_bit(data, next[0]))
+            }
+        }.map_err(Error::Spi)
+    }
+
+    fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        self.command(command)?;
+        let mut buf = [0u8; CHUNK_SIZE];
+        let mut n = 0;
+        for d in data {
+            buf[n] = d;
+            n += 1;
+            if n == CHUNK_SIZE {
+                self.write_data(&buf)?;
+                n = 0;
+            }
+        }
+        if n > 0 {
+            self.write_data(&buf[..n])?;
+        }
+        Ok(())
+    }
+    /// Read parameters into an iterator
+    ///
+    /// The data is read in chunks, frame memory reads (2Eh) are resumed with Read Memory Continue (3Eh).
+    fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        read_chunked(command, data, |command, buf| self.read_parameters(command, buf))
+    }
+
+    fn read_indexed_parameter(&mut self, command: u8) -> Result<u8, Self::Error> {
+        let mut data = [0];
+        self.dc.set_low().map_err(Error::Pin)?;
+        self.spi.transaction(&mut [
+            Operation::Write(&[command]),
+            Operation::Read(&mut data),
+        ]).map_err(Error::Spi)?;
+        Ok(data[0])
+    }
+}
+
+/// Blocking background transfers
+///
+/// A [`SpiDevice`] has no background transfers, the data is sent before `start_send_parameters`
+/// returns and the [`Transfer`](crate::dma::Transfer) is done right away. Code written for
+/// interfaces with DMA runs unchanged on it, e.g. streaming strips of rows from two buffers:
+///
+/// ```no_run
+/// use embedded_hal::digital::OutputPin;
+/// use embedded_hal::spi::SpiDevice;
+/// use ili9341_cmd::Controller;
+/// use ili9341_cmd::dma::TransferError;
+/// use ili9341_cmd::spi::{Error, SpiInterface};
+///
+/// fn render(strip: usize, buffer: &mut [u8]) {
+///     buffer.fill(strip as u8);
+/// }
+///
+/// fn stream<SPI: SpiDevice, DC: OutputPin>(
+///     lcd: &mut Controller<SpiInterface<SPI, DC>>,
+///     mut front: &'static mut [u8],
+///     mut back: &'static mut [u8],
+/// ) -> Result<(), TransferError<Error<SPI::Error, DC::Error>>> {
+///     render(0, front);
+///     let mut transfer = lcd.start_memory_write(front)?;
+///     for strip in 1..16 {
+///         // Render the next strip while the previous one is sent
+///         render(strip, back);
+///         front = transfer.wait()?;
+///         transfer = lcd.start_write_memory_continue(back)?;
+///         back = front;
+///     }
+///     transfer.wait()?;
+///     Ok(())
+/// }
+/// ```
+impl<SPI, DC> DmaInterface for SpiInterface<SPI, DC>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+{
+    fn start_send_parameters(&mut self, command: u8, buffer: &'static mut [u8]) -> Result<(), TransferError<Self::Error>> {
+        match self.send_parameters(command, buffer) {
+            Ok(()) => {
+                self.transfer = Some(buffer);
+                Ok(())
+            }
+            Err(error) => Err(TransferError { buffer, error }),
+        }
+    }
+    fn poll_transfer(&mut self, _cx: &mut Context<'_>) -> Poll<Result<&'static mut [u8], TransferError<Self::Error>>> {
+        match self.transfer.take() {
+            Some(buffer) => Poll::Ready(Ok(buffer)),
+            None => Poll::Pending,
+        }
+    }
+    fn abort_transfer(&mut self) -> Option<&'static mut [u8]> {
+        self.transfer.take()
+    }
+}
+
+/// Number of 9-bit words sent per SPI transaction (a multiple of 8, so chunks end on a byte boundary)
+const WORDS_PER_CHUNK: usize = 64;
+
+/// Packs 9-bit words, D/C flag first, into a byte stream
+struct WordPacker {
+    buf: [u8; WORDS_PER_CHUNK * 9 / 8],
+    bits: usize,
+}
+
+impl WordPacker {
+    fn new() -> WordPacker {
+        WordPacker { buf: [0; WORDS_PER_CHUNK * 9 / 8], bits: 0 }
+    }
+    fn is_full(&self) -> bool {
+        self.bits == self.buf.len() * 8
+    }
+    fn push(&mut self, dc: bool, byte: u8) {
+        let word = ((dc as u16) << 8) | (byte as u16);
+        let (i, shift) = (self.bits / 8, self.bits % 8);
+        let w = word << (7 - shift);
+        self.buf[i] |= (w >> 8) as u8;
+        if i + 1 < self.buf.len() {
+            self.buf[i + 1] |= w as u8;
+        }
+        self.bits += 9;
+    }
+    /// Take the packed bytes, the trailing bits of the last byte are padded with zeros
+    fn take(&mut self) -> &[u8] {
+        let len = self.bits.div_ceil(8);
+        self.bits = 0;
+        &self.buf[..len]
+    }
+    fn clear(&mut self) {
+        self.buf = [0; WORDS_PER_CHUNK * 9 / 8];
+    }
+}
+
+/// 3-wire 9-bit SPI interface ("3-line serial interface I")
+///
+/// Every byte is sent as a 9-bit word with the D/C flag in front (0: command, 1: parameter),
+/// 8 words are packed into 9 bytes of a plain 8-bit SPI transfer. The padding bits at the end of
+/// a transaction form an incomplete word, which the display drops when CSX goes high.
+///
+/// Reads are full duplex transfers, the display output (SDO, or SDA through a series resistor)
+/// has to be connected to MISO.
+pub struct ThreeWireSpiInterface<SPI> {
+    spi: SPI,
+}
+
+impl<SPI> ThreeWireSpiInterface<SPI>
+where
+    SPI: SpiDevice,
+{
+    pub fn new(spi: SPI) -> ThreeWireSpiInterface<SPI> {
+        ThreeWireSpiInterface { spi }
+    }
+
+    /// Release the SPI device
+    pub fn release(self) -> SPI {
+        self.spi
+    }
+
+    fn send_words<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), SPI::Error> {
+        let mut packer = WordPacker::new();
+        packer.push(false, command);
+        for d in data {
+            packer.push(true, d);
+            if packer.is_full() {
+                self.spi.write(packer.take())?;
+                packer.clear();
+            }
+        }
+        if packer.bits > 0 {
+            self.spi.write(packer.take())?;
+        }
+        Ok(())
+    }
+
+    /// Read at most `CHUNK_SIZE` bytes in a single transfer
+    fn read_chunk(&mut self, command: u8, data: &mut [u8]) -> Result<(), SPI::Error> {
+        self.read_chunk_with(command, read_dummy(command), data)
+    }
+    fn read_chunk_with(&mut self, command: u8, dummy: Dummy, data: &mut [u8]) -> Result<(), SPI::Error> {
+        let start = 9 + match dummy {
+            Dummy::None => 0,
+            Dummy::Bit => 1,
+            Dummy::Byte => 8,
+        };
+        let mut buf = [0u8; CHUNK_SIZE + 3];
+        let len = (start + data.len() * 8).div_ceil(8);
+        buf[0] = command >> 1;
+        buf[1] = command << 7;
+        self.spi.transfer_in_place(&mut buf[..len])?;
+        for (i, d) in data.iter_mut().enumerate() {
+            let (j, shift) = ((start + i * 8) / 8, (start + i * 8) % 8);
+            *d = if shift == 0 {
+                buf[j]
+            } else {
+                (buf[j] << shift) | (buf[j + 1] >> (8 - shift))
+            };
+        }
+        Ok(())
+    }
+}
+
+impl<SPI> Interface for ThreeWireSpiInterface<SPI>
+where
+    SPI: SpiDevice,
+{
+    type Error = SPI::Error;
+
+    fn command(&mut self, command: u8) -> Result<(), Self::Error> {
+        self.send_words(command, None)
+    }
+    fn send_parameters(&mut self, command: u8, data: &[u8]) -> Result<(), Self::Error> {
+        self.send_words(command, data.iter().copied())
+    }
+    /// Read parameters into a slice
+    ///
+    /// The data is read in chunks, frame memory reads (2Eh) are resumed with Read Memory Continue (3Eh).
+    fn read_parameters(&mut self, command: u8, data: &mut [u8]) -> Result<(), Self::Error> {
+        read_slice_chunked(command, data, |command, buf| self.read_chunk(command, buf))
+    }
+
+    fn send_parameters_iter<T: IntoIterator<Item = u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        self.send_words(command, data)
+    }
+    /// Read parameters into an iterator
+    ///
+    /// The data is read in chunks, frame memory reads (2Eh) are resumed with Read Memory Continue (3Eh).
+    fn read_parameters_iter<'l, T: IntoIterator<Item = &'l mut u8>>(&mut self, command: u8, data: T) -> Result<(), Self::Error> {
+        read_chunked(command, data, |command, buf| self.read_chunk(command, buf))
+    }
+
+    fn read_indexed_parameter(&mut self, command: u8) -> Result<u8, Self::Error> {
+        let mut data = [0];
+        self.read_chunk_with(command, Dummy::None, &mut data)?;
+        Ok(data[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use core::convert::Infallible;
+    use std::cell::RefCell;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use std::vec::Vec;
+    use std::vec;
+
+    use embedded_hal::digital;
+    use embedded_hal::spi::{self, Operation, SpiDevice};
+
+    use super::*;
+
+    /// Bus event seen by the fake SPI device and D/C pin
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Dc(bool),
+        Write(Vec<u8>),
+        Read(usize),
+        /// Full duplex transfer, the bytes sent
+        Transfer(Vec<u8>),
+    }
+
+    /// SPI device and D/C pin recording the bus events, reads are served from `miso`
+    #[derive(Clone, Default)]
+    struct Bus {
+        events: Rc<RefCell<Vec<Vec<Event>>>>,
+        miso: Rc<RefCell<VecDeque<u8>>>,
+    }
+
+    impl Bus {
+        /// Queue the bytes the display outputs after `dummy_bits` dummy clocks
+        fn respond(&self, dummy_bits: usize, data: &[u8]) {
+            self.miso.borrow_mut().extend(shifted(dummy_bits, data));
+        }
+        /// The recorded events, one list per SPI transaction
+        fn transactions(&self) -> Vec<Vec<Event>> {
+            self.events.take()
+        }
+        fn read_miso(&self, buf: &mut [u8]) {
+            for b in buf.iter_mut() {
+                *b = self.miso.borrow_mut().pop_front().unwrap_or(0xFF);
+            }
+        }
+    }
+
+    /// `data` delayed by `bits` clocks, as the display outputs it after the dummy clocks
+    fn shifted(bits: usize, data: &[u8]) -> Vec<u8> {
+        let mut out = vec![0u8; bits / 8 + data.len() + 1];
+        let shift = bits % 8;
+        for (i, &d) in data.iter().enumerate() {
+            let word = (d as u16) << (8 - shift);
+            out[bits / 8 + i] |= (word >> 8) as u8;
+            out[bits / 8 + i + 1] |= word as u8;
+        }
+        out.truncate((bits + data.len() * 8).div_ceil(8));
+        out
+    }
+
+    impl spi::ErrorType for Bus {
+        type Error = Infallible;
+    }
+
+    impl SpiDevice for Bus {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Infallible> {
+            let mut events = Vec::new();
+            for op in operations {
+                match op {
+                    Operation::Write(data) => events.push(Event::Write(data.to_vec())),
+                    Operation::Read(buf) => {
+                        events.push(Event::Read(buf.len()));
+                        self.read_miso(buf);
+                    }
+                    Operation::Transfer(read, write) => {
+                        events.push(Event::Transfer(write.to_vec()));
+                        self.read_miso(read);
+                    }
+                    Operation::TransferInPlace(buf) => {
+                        events.push(Event::Transfer(buf.to_vec()));
+                        self.read_miso(buf);
+                    }
+                    Operation::DelayNs(_) => {}
+                }
+            }
+            self.events.borrow_mut().push(events);
+            Ok(())
+        }
+    }
+
+    impl digital::ErrorType for Bus {
+        type Error = Infallible;
+    }
+
+    impl digital::OutputPin for Bus {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.events.borrow_mut().push(vec![Event::Dc(false)]);
+            Ok(())
+        }
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.events.borrow_mut().push(vec![Event::Dc(true)]);
+            Ok(())
+        }
+    }
+
+    fn four_wire() -> (Bus, SpiInterface<Bus, Bus>) {
+        let bus = Bus::default();
+        (bus.clone(), SpiInterface::new(bus.clone(), bus))
+    }
+
+    /// The 9-bit words (D/C flag, byte) of a 3-wire transaction, without the padding bits
+    fn unpack(bytes: &[u8]) -> Vec<(bool, u8)> {
+        let bit = |i: usize| bytes[i / 8] & (0x80 >> (i % 8)) != 0;
+        (0..bytes.len() * 8 / 9)
+            .map(|w| (bit(9 * w), (1..9).fold(0u8, |b, i| (b << 1) | bit(9 * w + i) as u8)))
+            .collect()
+    }
+
+    /// The command of a 3-wire read transfer
+    fn read_command(transaction: &[Event]) -> u8 {
+        match transaction {
+            [Event::Transfer(bytes)] => {
+                assert_eq!(bytes[0] & 0x80, 0, "D/C of the command");
+                (bytes[0] << 1) | (bytes[1] >> 7)
+            }
+            t => panic!("expected a single transfer, got {:02X?}", t),
+        }
+    }
+
+    #[test]
+    fn dummy_cycles_per_command() {
+        for command in [0x04, 0x09, 0x45] {
+            assert_eq!(read_dummy(command), Dummy::Bit, "{:02X}h", command);
+        }
+        for command in [0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F, 0x52, 0x54, 0x56, 0x5F, 0xDA, 0xDB, 0xDC] {
+            assert_eq!(read_dummy(command), Dummy::None, "{:02X}h", command);
+        }
+        for command in [0x2E, 0x3E, 0xB6, 0xD3, 0xE0] {
+            assert_eq!(read_dummy(command), Dummy::Byte, "{:02X}h", command);
+        }
+    }
+
+    #[test]
+    fn drop_dummy_bit_shifts_across_bytes() {
+        let mut data = [0x49, 0xA0, 0x80];
+        drop_dummy_bit(&mut data, 0x80);
+        assert_eq!(data, [0x93, 0x41, 0x01]);
+    }
+
+    #[test]
+    fn read_without_dummy() {
+        let (bus, mut iface) = four_wire();
+        bus.respond(0, &[0x9C]);
+        let mut data = [0];
+        iface.read_parameters(0x0A, &mut data).unwrap();
+        assert_eq!(data, [0x9C]);
+        assert_eq!(bus.transactions(), [vec![Event::Dc(false)], vec![Event::Write(vec![0x0A]), Event::Read(1)]]);
+    }
+
+    #[test]
+    fn read_with_dummy_bit() {
+        let (bus, mut iface) = four_wire();
+        bus.respond(1, &[0x00, 0x93, 0x41]);
+        let mut data = [0; 3];
+        iface.read_parameters(0x04, &mut data).unwrap();
+        assert_eq!(data, [0x00, 0x93, 0x41]);
+        assert_eq!(bus.transactions()[1], [Event::Write(vec![0x04]), Event::Read(3), Event::Read(1)]);
+    }
+
+    #[test]
+    fn read_with_dummy_byte() {
+        let (bus, mut iface) = four_wire();
+        bus.respond(8, &[0x00, 0x93, 0x41]);
+        let mut data = [0; 3];
+        iface.read_parameters(0xD3, &mut data).unwrap();
+        assert_eq!(data, [0x00, 0x93, 0x41]);
+        assert_eq!(bus.transactions()[1], [Event::Write(vec![0xD3]), Event::Read(1), Event::Read(3)]);
+    }
+
+    #[test]
+    fn memory_read_resumes_with_continue() {
+        let (bus, mut iface) = four_wire();
+        let pixels: Vec<u8> = (0..100).collect();
+        for chunk in pixels.chunks(CHUNK_SIZE) {
+            bus.respond(8, chunk);
+        }
+        let mut data = [0u8; 100];
+        iface.read_parameters_iter(0x2E, data.iter_mut()).unwrap();
+        assert_eq!(data[..], pixels[..]);
+        let reads: Vec<_> = bus.transactions().into_iter().filter(|t| t.len() > 1).collect();
+        assert_eq!(reads, [
+            vec![Event::Write(vec![0x2E]), Event::Read(1), Event::Read(48)],
+            vec![Event::Write(vec![0x3E]), Event::Read(1), Event::Read(48)],
+            vec![Event::Write(vec![0x3E]), Event::Read(1), Event::Read(4)],
+        ]);
+    }
+
+    #[test]
+    fn register_read_of_one_chunk() {
+        let (bus, mut iface) = four_wire();
+        bus.respond(8, &[0x5A; CHUNK_SIZE]);
+        let mut data = [0u8; CHUNK_SIZE];
+        iface.read_parameters_iter(0xE0, data.iter_mut()).unwrap();
+        assert_eq!(data, [0x5A; CHUNK_SIZE]);
+    }
+
+    #[test]
+    fn register_read_longer_than_a_chunk() {
+        let (bus, mut iface) = four_wire();
+        bus.respond(8, &[0x5A; CHUNK_SIZE]);
+        let mut data = [0u8; CHUNK_SIZE + 1];
+        iface.read_parameters_iter(0xE0, data.iter_mut()).unwrap();
+        // Clamped to one chunk
+        assert_eq!(data[..CHUNK_SIZE], [0x5A; CHUNK_SIZE]);
+        assert_eq!(data[CHUNK_SIZE], 0);
+    }
+
+    #[test]
+    fn blocking_background_transfer() {
+        let (bus, iface) = four_wire();
+        let mut lcd = crate::Controller::new(iface);
+        let buffer: &'static mut [u8] = std::boxed::Box::leak(vec![0xA5; 4].into_boxed_slice());
+        let buffer = lcd.start_memory_write(buffer).unwrap().wait().unwrap();
+        assert_eq!(buffer, [0xA5; 4]);
+        assert_eq!(bus.transactions(), [
+            vec![Event::Dc(false)],
+            vec![Event::Write(vec![0x2C])],
+            vec![Event::Dc(true)],
+            vec![Event::Write(vec![0xA5; 4])],
+        ]);
+    }
+
+    #[test]
+    fn poll_without_background_transfer() {
+        let (_bus, mut iface) = four_wire();
+        let mut cx = Context::from_waker(core::task::Waker::noop());
+        assert!(iface.poll_transfer(&mut cx).is_pending());
+        assert!(iface.abort_transfer().is_none());
+    }
+
+    #[test]
+    fn three_wire_command() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        iface.command(0x29).unwrap();
+        assert_eq!(bus.transactions(), [vec![Event::Write(vec![0x14, 0x80])]]);
+    }
+
+    #[test]
+    fn three_wire_write_across_chunks() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        let data: Vec<u8> = (0..200).map(|i| (i * 7) as u8).collect();
+        iface.send_parameters(0x2C, &data).unwrap();
+
+        let transactions = bus.transactions();
+        let lengths: Vec<usize> = transactions.iter().map(|t| match &t[..] {
+            [Event::Write(bytes)] => bytes.len(),
+            t => panic!("expected a single write, got {:02X?}", t),
+        }).collect();
+        // 64 words per chunk: the command and 63 parameters, 2 x 64 parameters, the last 9
+        assert_eq!(lengths, [72, 72, 72, 11]);
+
+        let words: Vec<(bool, u8)> = transactions.iter().flat_map(|t| match &t[..] {
+            [Event::Write(bytes)] => unpack(bytes),
+            _ => unreachable!(),
+        }).collect();
+        let expected: Vec<(bool, u8)> = Some((false, 0x2C)).into_iter().chain(data.iter().map(|&d| (true, d))).collect();
+        assert_eq!(words, expected);
+    }
+
+    #[test]
+    fn three_wire_read_with_dummy_bit() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        bus.respond(9 + 1, &[0x00, 0x93, 0x41]);
+        let mut data = [0; 3];
+        iface.read_parameters(0x04, &mut data).unwrap();
+        assert_eq!(data, [0x00, 0x93, 0x41]);
+        let transactions = bus.transactions();
+        assert_eq!(read_command(&transactions[0]), 0x04);
+        // command, dummy clock and 24 bits
+        assert!(matches!(&transactions[0][..], [Event::Transfer(b)] if b.len() == 5));
+    }
+
+    #[test]
+    fn three_wire_read_with_dummy_byte() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        bus.respond(9 + 8, &[0x12, 0x34]);
+        let mut data = [0; 2];
+        iface.read_parameters(0xB6, &mut data).unwrap();
+        assert_eq!(data, [0x12, 0x34]);
+        assert_eq!(read_command(&bus.transactions()[0]), 0xB6);
+    }
+
+    #[test]
+    fn three_wire_indexed_read_without_dummy() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        bus.respond(9, &[0x93]);
+        assert_eq!(iface.read_indexed_parameter(0xD3).unwrap(), 0x93);
+    }
+
+    #[test]
+    fn three_wire_memory_read_resumes_with_continue() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        let pixels: Vec<u8> = (0..100).map(|i| 255 - i).collect();
+        for chunk in pixels.chunks(CHUNK_SIZE) {
+            bus.respond(9 + 8, chunk);
+        }
+        let mut data = [0u8; 100];
+        iface.read_parameters(0x2E, &mut data).unwrap();
+        assert_eq!(data[..], pixels[..]);
+        let commands: Vec<u8> = bus.transactions().iter().map(|t| read_command(t)).collect();
+        assert_eq!(commands, [0x2E, 0x3E, 0x3E]);
+    }
+
+    #[test]
+    fn three_wire_register_read_longer_than_a_chunk() {
+        let bus = Bus::default();
+        let mut iface = ThreeWireSpiInterface::new(bus.clone());
+        bus.respond(9 + 8, &[0x5A; CHUNK_SIZE]);
+        let mut data = [0u8; CHUNK_SIZE + 1];
+        iface.read_parameters(0xE0, &mut data).unwrap();
+        assert_eq!(data[..CHUNK_SIZE], [0x5A; CHUNK_SIZE]);
+        assert_eq!(data[CHUNK_SIZE], 0);
+        assert_eq!(bus.transactions().len(), 1);
+    }
+}
@@ -0,0 +1,201 @@
+//! Reset pin and the waits mandated by the datasheet
+//!
+//! The Controller optionally owns a [`DelayNs`] source and the RESX pin. With a delay source,
+//! `software_reset`, `enter_sleep_mode` and `sleep_out` wait as required before returning:
+//! - 5 ms after a reset or a sleep in/out command before the next command
+//! - 120 ms between a reset or a sleep in/out command and the next one of them
+//!
+//! The Controller has no clock, so the 120 ms guard is waited in full (minus the 5 ms already
+//! waited) by the next reset or sleep in/out command, however long ago the previous one was. The
+//! power-on state is not known either, so the first one after creating the Controller waits too.
+//! Without a delay source ([`NoDelay`]) the application has to take care of the timing.
+//!
+//! The AsyncController waits the same way with an `embedded_hal_async` delay source.
+
+use core::convert::Infallible;
+
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::OutputPin;
+
+use crate::shadow::RegisterCache;
+use crate::{Controller, Interface};
+
+/// RESX low pulse (tRW >= 10 us)
+const RESET_PULSE_US: u32 = 10;
+/// Wait after a reset or a sleep in/out command before the next command
+pub(crate) const COMMAND_WAIT_MS: u32 = 5;
+/// Wait between a reset or a sleep in/out command and the next one of them
+pub(crate) const SLEEP_GUARD_MS: u32 = 120;
+
+/// Delay source of a Controller without delay, all the waits are skipped
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoDelay;
+
+impl DelayNs for NoDelay {
+    fn delay_ns(&mut self, _ns: u32) {}
+}
+
+/// Reset pin of a Controller without reset pin, `hard_reset` is not available
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoResetPin;
+
+/// RESX line of a Controller
+///
+/// Implemented for every [`OutputPin`] and for [`NoResetPin`], so [`init`](Controller::init)
+/// can reset by hardware whenever the Controller has the pin.
+pub trait ResetPin {
+    type Error;
+    /// Pulse the line low, `Ok(false)` if there is no line
+    fn pulse<D: DelayNs>(&mut self, delay: &mut D) -> Result<bool, Self::Error>;
+}
+
+impl<P: OutputPin> ResetPin for P {
+    type Error = P::Error;
+
+    fn pulse<D: DelayNs>(&mut self, delay: &mut D) -> Result<bool, Self::Error> {
+        self.set_low()?;
+        delay.delay_us(RESET_PULSE_US);
+        self.set_high()?;
+        Ok(true)
+    }
+}
+
+impl ResetPin for NoResetPin {
+    type Error = Infallible;
+
+    fn pulse<D: DelayNs>(&mut self, _delay: &mut D) -> Result<bool, Self::Error> {
+        Ok(false)
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Cache: RegisterCache,
+{
+    /// Use `delay` for the mandated waits
+    pub fn with_delay<D: DelayNs>(self, delay: D) -> Controller<Iface, D, Reset, Cache> {
+        Controller {
+            iface: self.iface,
+            delay,
+            reset: self.reset,
+            sleep_guard: self.sleep_guard,
+            row_column_exchange: self.row_column_exchange,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended_read: self.extended_read,
+            cache: self.cache,
+        }
+    }
+    /// Use `reset` as RESX pin, see [`hard_reset`](Controller::hard_reset)
+    pub fn with_reset_pin<R: OutputPin>(self, reset: R) -> Controller<Iface, Delay, R, Cache> {
+        Controller {
+            iface: self.iface,
+            delay: self.delay,
+            reset,
+            sleep_guard: self.sleep_guard,
+            row_column_exchange: self.row_column_exchange,
+            #[cfg(feature = "Ili9341ExtendedCommandSet")]
+            extended_read: self.extended_read,
+            cache: self.cache,
+        }
+    }
+
+    /// Send a reset or a sleep in/out command and wait as required
+    pub(crate) fn timed_command(&mut self, command: u8) -> Result<(), Iface::Error> {
+        self.wait_sleep_guard();
+        self.iface.command(command)?;
+        if command == 0x01 {
+            self.row_column_exchange = false;
+            self.cache.reset();
+        }
+        self.delay.delay_ms(COMMAND_WAIT_MS);
+        self.sleep_guard = true;
+        Ok(())
+    }
+
+    fn wait_sleep_guard(&mut self) {
+        if self.sleep_guard {
+            self.delay.delay_ms(SLEEP_GUARD_MS - COMMAND_WAIT_MS);
+            self.sleep_guard = false;
+        }
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Reset: ResetPin,
+    Cache: RegisterCache,
+{
+    /// Pulse RESX if there is a reset pin and wait until the display accepts commands
+    /// Returns whether the display was reset.
+    pub(crate) fn pulse_reset(&mut self) -> Result<bool, Reset::Error> {
+        if !self.reset.pulse(&mut self.delay)? {
+            return Ok(false);
+        }
+        self.row_column_exchange = false;
+        self.cache.reset();
+        self.delay.delay_ms(COMMAND_WAIT_MS);
+        self.sleep_guard = true;
+        Ok(true)
+    }
+}
+
+impl<Iface, Delay, Reset, Cache> Controller<Iface, Delay, Reset, Cache>
+where
+    Iface: Interface,
+    Delay: DelayNs,
+    Reset: OutputPin,
+    Cache: RegisterCache,
+{
+    /// Pulse RESX low and wait until the display accepts commands
+    /// The registers are reset to their H/W reset defaults, the frame memory is kept.
+    pub fn hard_reset(&mut self) -> Result<(), Reset::Error> {
+        self.pulse_reset().map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::checked;
+    use crate::mock::MockInterface;
+
+    /// Delay source recording the waits in ms
+    #[derive(Default)]
+    struct Waits(Vec<u32>);
+
+    impl DelayNs for Waits {
+        fn delay_ns(&mut self, ns: u32) {
+            self.0.push(ns / 1_000_000);
+        }
+    }
+
+    #[test]
+    fn raw_timed_commands_wait_and_reset_the_state() {
+        let mock = MockInterface::new();
+        let mut lcd = Controller::new(mock.clone()).with_delay(Waits::default()).with_shadow_registers();
+        lcd.memory_access_control(|w| w.row_column_exchange(true)).unwrap();
+        lcd.command(0x11).unwrap();
+        lcd.command(0x29).unwrap();
+        lcd.column_address_set_checked(0, 319).unwrap();
+        lcd.command(0x01).unwrap();
+
+        assert_eq!(lcd.delay.0, [115, 5, 115, 5]);
+        // MV and the shadow registers are back at their reset defaults
+        assert_eq!(lcd.column_address_set_checked(0, 319), Err(checked::Error::OutOfBounds { address: 319, last: 239 }));
+        assert_eq!(lcd.shadow_registers().memory_access_control(), Some(Default::default()));
+        mock.expect_parameters(0x36, &[0x20])
+            .expect_command(0x11)
+            .expect_command(0x29)
+            .expect_parameters(0x2A, &[0x00, 0x00, 0x01, 0x3F])
+            .expect_command(0x01)
+            .done();
+    }
+}